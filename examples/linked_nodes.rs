@@ -0,0 +1,55 @@
+use algods::data_structure::{BSearchTree, Stack, VecBSearchTree, VecStack};
+use rand::seq::SliceRandom;
+use rand::{rngs::StdRng, SeedableRng};
+use std::time::Instant;
+
+fn main() {
+    // compare boxed nodes with nodes stored contiguously in a `Vec`
+    let n = 1_000_000;
+
+    let start = Instant::now();
+    let mut stack = Stack::new();
+    for k in 0..n {
+        stack.push(k);
+    }
+    let mut sum = 0;
+    for _ in 0..n / 2 {
+        sum += stack.pop().unwrap();
+    }
+    drop(stack);
+    println!("Stack:          {:?} (sum {sum})", start.elapsed());
+
+    let start = Instant::now();
+    let mut stack = VecStack::new();
+    for k in 0..n {
+        stack.push(k);
+    }
+    let mut sum = 0;
+    for _ in 0..n / 2 {
+        sum += stack.pop().unwrap();
+    }
+    drop(stack);
+    println!("VecStack:       {:?} (sum {sum})", start.elapsed());
+
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut keys = (0..n).collect::<Vec<usize>>();
+    keys.shuffle(&mut rng);
+
+    let start = Instant::now();
+    let mut tree = BSearchTree::new();
+    for key in keys.iter() {
+        tree.insert(*key, *key);
+    }
+    let found = keys.iter().filter(|key| tree.contains(key)).count();
+    drop(tree);
+    println!("BSearchTree:    {:?} ({found} found)", start.elapsed());
+
+    let start = Instant::now();
+    let mut tree = VecBSearchTree::new();
+    for key in keys.iter() {
+        tree.insert(*key, *key);
+    }
+    let found = keys.iter().filter(|key| tree.contains(key)).count();
+    drop(tree);
+    println!("VecBSearchTree: {:?} ({found} found)", start.elapsed());
+}
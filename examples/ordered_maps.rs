@@ -0,0 +1,105 @@
+use algods::data_structure::{BTreeTable, OrdVecTable, SkipList, SplayTree, Treap};
+use rand::seq::SliceRandom;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::time::Instant;
+
+fn main() {
+    // compare the ordered symbol tables on the same random keys
+    let n = 50_000;
+    let mut rng = StdRng::seed_from_u64(0);
+    let mut keys = (0..n).collect::<Vec<usize>>();
+    keys.shuffle(&mut rng);
+
+    let start = Instant::now();
+    let mut btree = BTreeTable::new();
+    for key in keys.iter() {
+        btree.insert(*key, *key);
+    }
+    let found = keys.iter().filter(|key| btree.get(key).is_some()).count();
+    let count = btree.range_count(&(n / 4), &(3 * n / 4));
+    println!(
+        "BTreeTable:  {:?} ({found} found, {count} in range)",
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let mut skip_list = SkipList::with_seed(0);
+    for key in keys.iter() {
+        skip_list.insert(*key, *key);
+    }
+    let found = keys
+        .iter()
+        .filter(|key| skip_list.get(key).is_some())
+        .count();
+    let count = skip_list.range_count(&(n / 4), &(3 * n / 4));
+    println!(
+        "SkipList:    {:?} ({found} found, {count} in range)",
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let mut ord_vec = OrdVecTable::new();
+    for key in keys.iter() {
+        ord_vec.insert(*key, *key);
+    }
+    let found = keys.iter().filter(|key| ord_vec.get(key).is_some()).count();
+    println!("OrdVecTable: {:?} ({found} found)", start.elapsed());
+
+    let start = Instant::now();
+    let mut treap = Treap::with_seed(0);
+    for key in keys.iter() {
+        treap.insert(*key, *key);
+    }
+    let found = keys.iter().filter(|key| treap.get(key).is_some()).count();
+    let count = treap.range_count(&(n / 4), &(3 * n / 4));
+    println!(
+        "Treap:       {:?} ({found} found, {count} in range)",
+        start.elapsed()
+    );
+
+    let start = Instant::now();
+    let mut splay = SplayTree::new();
+    for key in keys.iter() {
+        splay.insert(*key, *key);
+    }
+    let found = keys.iter().filter(|key| splay.get(key).is_some()).count();
+    let count = splay.range_count(&(n / 4), &(3 * n / 4));
+    println!(
+        "SplayTree:   {:?} ({found} found, {count} in range)",
+        start.elapsed()
+    );
+
+    // skewed lookups: 90% of the accesses go to 1% of the keys
+    let hot = &keys[..n / 100];
+    let accesses = (0..20 * n)
+        .map(|_| {
+            if rng.gen_bool(0.9) {
+                hot[rng.gen_range(0..hot.len())]
+            } else {
+                keys[rng.gen_range(0..n)]
+            }
+        })
+        .collect::<Vec<usize>>();
+    println!("Skewed lookups ({} accesses):", accesses.len());
+
+    let start = Instant::now();
+    let found = accesses
+        .iter()
+        .filter(|key| btree.get(key).is_some())
+        .count();
+    println!("BTreeTable:  {:?} ({found} found)", start.elapsed());
+
+    let start = Instant::now();
+    let found = accesses
+        .iter()
+        .filter(|key| treap.get(key).is_some())
+        .count();
+    println!("Treap:       {:?} ({found} found)", start.elapsed());
+
+    let start = Instant::now();
+    let found = accesses
+        .iter()
+        .filter(|key| splay.get(key).is_some())
+        .count();
+    println!("SplayTree:   {:?} ({found} found)", start.elapsed());
+}
@@ -1,9 +1,10 @@
 use crate::collection::{Collection, Document};
 use crate::preprocessing::{character_ngram, preprocess};
+use algods::data_structure::MultiSet;
 use std::collections::{HashMap, HashSet};
 pub struct InvertedIndex {
     index: HashMap<String, Vec<usize>>, // stores the postings
-    raw_freq: HashMap<usize, HashMap<String, usize>>, // stores the number of occurrences of tokens in the documents they appear
+    raw_freq: HashMap<usize, MultiSet<String>>, // stores the number of occurrences of tokens in the documents they appear
     char_t_index: HashMap<String, HashSet<String>>,   // character to term index
     t_char_index: HashMap<String, HashSet<String>>,   // term to character index
     include_char_index: bool, // says whether or not to include the (term to) character (to term) index
@@ -33,7 +34,7 @@ impl InvertedIndex {
         &self.index
     }
 
-    pub fn raw_freq(&self) -> &HashMap<usize, HashMap<String, usize>> {
+    pub fn raw_freq(&self) -> &HashMap<usize, MultiSet<String>> {
         &self.raw_freq
     }
 
@@ -50,7 +51,7 @@ impl InvertedIndex {
         let terms = preprocess(collection.document(&doc_id));
         // Character indexing the document
         if self.include_char_index {
-            for term in terms.keys() {
+            for (term, _) in &terms {
                 let chars = character_ngram(term, self.ngram); // String, usize -> HashSet
                 for _char in &chars {
                     if let Some(h) = self.char_t_index.get_mut(_char) {
@@ -63,7 +64,7 @@ impl InvertedIndex {
             }
         }
         // Invert indexing the document
-        for token in terms.keys() {
+        for (token, _) in &terms {
             if let Some(posting) = self.index.get_mut(token) {
                 (*posting).push(doc_id); // works if the documents are indexed iteratively with increasing IDs.
             } else {
@@ -1,6 +1,7 @@
 use crate::collection::Document;
 use crate::constant::PUNCTUATION;
-use std::collections::{HashMap, HashSet};
+use algods::data_structure::MultiSet;
+use std::collections::HashSet;
 
 pub fn character_ngram(word: &str, size: usize) -> HashSet<String> {
     let _word = word.trim();
@@ -29,7 +30,7 @@ pub fn is_not_punct(character: char) -> bool {
     !PUNCTUATION.contains(character)
 }
 
-pub fn preprocess(doc: &Document) -> HashMap<String, usize> {
+pub fn preprocess(doc: &Document) -> MultiSet<String> {
     let mut content = doc.content().to_lowercase();
     content.retain(is_not_punct);
     let content = content.split_whitespace().collect();
@@ -43,16 +44,8 @@ impl Counter {
     pub fn new() -> Self {
         Self {}
     }
-    pub fn count(&mut self, content: Vec<&str>) -> HashMap<String, usize> {
-        let mut counter = HashMap::with_capacity(content.len());
-        for word in content {
-            if let Some(count) = counter.get_mut(word) {
-                *count += 1;
-            } else {
-                counter.insert(word.to_string(), 1);
-            }
-        }
-        counter
+    pub fn count(&mut self, content: Vec<&str>) -> MultiSet<String> {
+        content.into_iter().map(str::to_string).collect()
     }
 }
 
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::preprocessing::*;
-    use std::collections::{HashMap, HashSet};
+    use algods::data_structure::MultiSet;
+    use std::collections::HashSet;
 
     #[test]
     fn test_character_ngram() {
@@ -31,7 +32,10 @@ mod tests {
         let b = "b".to_string();
         let c = "c".to_string();
         let d = "d".to_string();
-        let expected_result = HashMap::from([(a, 3), (b, 2), (c, 1), (d, 1)]);
+        let mut expected_result = MultiSet::new();
+        for (word, count) in [(a, 3), (b, 2), (c, 1), (d, 1)] {
+            expected_result.add(word, count);
+        }
         assert_eq!(counter.count(vec), expected_result);
     }
 }
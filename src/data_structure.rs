@@ -1,13 +1,60 @@
+mod bloom_filter;
+mod btree;
+mod cache;
+mod dary_heap;
 mod deque;
+mod fenwick_tree;
 mod hash_table;
+mod interval_tree;
+mod kd_tree;
+mod mergeable_heap;
+mod min_max_heap;
+mod multiset;
+mod persistent;
 mod priority_queue;
 mod queue;
+mod randomized_queue;
+mod rank_select;
+mod ring_buffer;
+mod segment_tree;
+mod sketch;
+mod skip_list;
+mod sliding_window;
+mod sparse_table;
+mod splay_tree;
 mod stack;
+mod treap;
 mod tree_table;
+mod veb_set;
 
+pub use bloom_filter::{BloomFilter, CountingBloomFilter};
+pub use btree::{BTree, Codec, FilePageStore, Header, MemoryPageStore, Page, PageId, PageStore};
+pub use cache::{CacheStats, LfuCache, LruCache};
+pub use dary_heap::{DaryHeap, IndexDaryHeap};
 pub use deque::Deque;
+pub use fenwick_tree::FenwickTree;
 pub use hash_table::SepChainTable;
-pub use priority_queue::{BinaryHeapQueue, Orientation, PriorityQueue};
+pub use interval_tree::IntervalTree;
+pub use kd_tree::{KdTree, PointSet};
+pub use mergeable_heap::{FibonacciHeap, HeapHandle, PairingHeap};
+pub use min_max_heap::MinMaxHeap;
+pub use multiset::{Bag, MultiSet, MultiSetIter};
+pub use persistent::{PersistentQueue, PersistentQueueIter, PersistentStack, PersistentStackIter};
+pub use priority_queue::{BinaryHeapQueue, Orientation, PeekMut, PriorityQueue};
 pub use queue::Queue;
+pub use randomized_queue::{RandomizedIter, RandomizedQueue};
+pub use rank_select::RankSelectBitVec;
+pub use ring_buffer::{BoundedQueue, RingDeque};
+pub use segment_tree::{
+    Gcd, Idempotent, LazyMonoid, LazySegmentTree, Max, Min, Monoid, Number, SegmentTree, Selective,
+    Sum,
+};
+pub use sketch::{CountMinSketch, HyperLogLog, SpaceSaving};
+pub use skip_list::{SkipList, SkipListIter};
+pub use sliding_window::SlidingWindow;
+pub use sparse_table::SparseTable;
+pub use splay_tree::{SplayTree, SplayTreeIter};
 pub use stack::{ListStack, Stack, VecStack};
-pub use tree_table::{BSearchTree, BTreeTable, OrdVecTable, UnordVecTable};
+pub use treap::{Treap, TreapIter};
+pub use tree_table::{BSearchTree, BTreeTable, OrdVecTable, UnordVecTable, VecBSearchTree};
+pub use veb_set::{VebSet, VebSetIter};
@@ -0,0 +1,450 @@
+#[cfg(test)]
+mod unit_test;
+use crate::compression::{BitVec, Lsb0};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+fn optimal_size(expected_items: usize, false_positive_rate: f64) -> (usize, usize) {
+    // number of bits m = -n ln(p) / ln(2)^2 and number of hashes k = (m / n) ln(2)
+    if expected_items == 0 {
+        panic!("the expected number of items should be > 0");
+    }
+    if !(false_positive_rate > 0. && false_positive_rate < 1.) {
+        panic!("the false positive rate should be in ]0, 1[");
+    }
+    let ln2 = std::f64::consts::LN_2;
+    let n = expected_items as f64;
+    let nb_bits = (-n * false_positive_rate.ln() / (ln2 * ln2)).ceil().max(1.) as usize;
+    let nb_hashes = (nb_bits as f64 / n * ln2).round().max(1.) as usize;
+    (nb_bits, nb_hashes)
+}
+
+pub(super) fn positions<T: Hash + ?Sized>(
+    item: &T,
+    nb_bits: usize,
+    nb_hashes: usize,
+) -> Vec<usize> {
+    // double hashing: the i^th position is h1 + i * h2 (mod nb_bits)
+    let mut hasher = DefaultHasher::new();
+    item.hash(&mut hasher);
+    let h1 = hasher.finish();
+    // hashing more data after the item gives a second, independent enough, hash
+    hasher.write_u8(0xff);
+    let h2 = hasher.finish() | 1;
+    (0..nb_hashes as u64)
+        .map(|i| (h1.wrapping_add(i.wrapping_mul(h2)) % nb_bits as u64) as usize)
+        .collect()
+}
+
+/// Implementation of a Bloom filter: a probabilistic set answering membership queries
+/// with no false negative and a tunable rate of false positives, using a fixed number of bits.
+/// # Example
+/// ```
+/// use algods::data_structure::BloomFilter;
+/// let mut filter = BloomFilter::new(1000, 0.01);
+/// filter.insert("key");
+/// assert!(filter.contains("key"));
+/// // an absent key is reported absent with probability 0.99
+/// let _maybe = filter.contains("absent key");
+/// ```
+#[derive(Debug)]
+pub struct BloomFilter<T: ?Sized> {
+    bits: BitVec<usize, Lsb0>,
+    // number of positions set for each item
+    nb_hashes: usize,
+    item: PhantomData<T>,
+}
+// manual implementations, so that no bound is required on the type of the items
+impl<T: ?Sized> Clone for BloomFilter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            bits: self.bits.clone(),
+            nb_hashes: self.nb_hashes,
+            item: PhantomData,
+        }
+    }
+}
+impl<T: ?Sized> PartialEq for BloomFilter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.nb_hashes == other.nb_hashes && self.bits == other.bits
+    }
+}
+impl<T: Hash + ?Sized> BloomFilter<T> {
+    /// Creates an empty filter sized so that, once `expected_items` items are inserted,
+    /// the probability of a false positive is about `false_positive_rate`.
+    /// # Panics
+    /// If `expected_items = 0` or `false_positive_rate` is not in ]0, 1[, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let filter = BloomFilter::<str>::new(1000, 0.01);
+    /// assert_eq!(filter.nb_bits(), 9586);
+    /// assert_eq!(filter.nb_hashes(), 7);
+    /// ```
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let (nb_bits, nb_hashes) = optimal_size(expected_items, false_positive_rate);
+        Self::with_size(nb_bits, nb_hashes)
+    }
+    /// Creates an empty filter with a given number of bits and of hash functions.
+    /// # Panics
+    /// If `nb_bits = 0` or `nb_hashes = 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let filter = BloomFilter::<usize>::with_size(64, 3);
+    /// assert_eq!(filter.nb_bits(), 64);
+    /// ```
+    pub fn with_size(nb_bits: usize, nb_hashes: usize) -> Self {
+        if nb_bits == 0 || nb_hashes == 0 {
+            panic!("the number of bits and the number of hashes should be > 0");
+        }
+        Self {
+            bits: BitVec::repeat(false, nb_bits),
+            nb_hashes,
+            item: PhantomData,
+        }
+    }
+    /// Gives the number of bits of the filter.
+    pub fn nb_bits(&self) -> usize {
+        self.bits.len()
+    }
+    /// Gives the number of hash functions of the filter.
+    pub fn nb_hashes(&self) -> usize {
+        self.nb_hashes
+    }
+    /// Tests whether or not no item was inserted in the filter.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let filter = BloomFilter::<usize>::new(10, 0.1);
+    /// assert!(filter.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.bits.not_any()
+    }
+    /// Inserts an item in the filter.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let mut filter = BloomFilter::new(10, 0.1);
+    /// filter.insert(&1);
+    /// assert!(filter.contains(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(k) where k is the number of hash functions
+    pub fn insert(&mut self, item: &T) {
+        for position in positions(item, self.nb_bits(), self.nb_hashes) {
+            self.bits.set(position, true);
+        }
+    }
+    /// Tests whether or not an item may be in the filter: `false` means that the item was
+    /// never inserted, `true` that it was inserted or, with a small probability, not.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let mut filter = BloomFilter::new(10, 0.1);
+    /// filter.insert(&1);
+    /// assert!(filter.contains(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(k) where k is the number of hash functions
+    pub fn contains(&self, item: &T) -> bool {
+        positions(item, self.nb_bits(), self.nb_hashes)
+            .into_iter()
+            .all(|position| self.bits[position])
+    }
+    /// Estimates the number of distinct items inserted in the filter, from the number of bits set.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let mut filter = BloomFilter::new(1000, 0.01);
+    /// for k in 0..500 {
+    ///     filter.insert(&k);
+    /// }
+    /// assert!((filter.estimated_len() - 500.).abs() < 25.);
+    /// ```
+    pub fn estimated_len(&self) -> f64 {
+        // n = -(m / k) ln(1 - X / m) where X is the number of bits set
+        let m = self.nb_bits() as f64;
+        let ones = self.bits.count_ones() as f64;
+        -(m / self.nb_hashes as f64) * (1. - ones / m).ln()
+    }
+    /// Estimates the current probability of a false positive, from the number of bits set.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let mut filter = BloomFilter::new(1000, 0.01);
+    /// for k in 0..1000 {
+    ///     filter.insert(&k);
+    /// }
+    /// assert!(filter.false_positive_rate() < 0.02);
+    /// ```
+    pub fn false_positive_rate(&self) -> f64 {
+        let ones = self.bits.count_ones() as f64;
+        (ones / self.nb_bits() as f64).powi(self.nb_hashes as i32)
+    }
+    /// Tests whether or not two filters have the same number of bits and of hash functions,
+    /// so that they can be combined.
+    pub fn is_compatible(&self, other: &Self) -> bool {
+        self.nb_bits() == other.nb_bits() && self.nb_hashes == other.nb_hashes
+    }
+    fn check_compatible(&self, other: &Self) {
+        if !self.is_compatible(other) {
+            panic!("filters should have the same number of bits and of hashes");
+        }
+    }
+    /// Returns the filter of the union of the items of two compatible filters.
+    /// # Panics
+    /// If the filters are not compatible, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let mut f1 = BloomFilter::new(10, 0.1);
+    /// let mut f2 = BloomFilter::new(10, 0.1);
+    /// f1.insert(&1);
+    /// f2.insert(&2);
+    /// let union = f1.union(&f2);
+    /// assert!(union.contains(&1) && union.contains(&2));
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        self.check_compatible(other);
+        let mut result = self.clone();
+        for (word, other_word) in result
+            .bits
+            .as_raw_mut_slice()
+            .iter_mut()
+            .zip(other.bits.as_raw_slice())
+        {
+            *word |= *other_word;
+        }
+        result
+    }
+    /// Returns a filter of the intersection of the items of two compatible filters.
+    /// Its false positive rate can be higher than that of a filter built from the intersection.
+    /// # Panics
+    /// If the filters are not compatible, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BloomFilter;
+    /// let mut f1 = BloomFilter::new(10, 0.01);
+    /// let mut f2 = BloomFilter::new(10, 0.01);
+    /// f1.insert(&1);
+    /// f1.insert(&2);
+    /// f2.insert(&2);
+    /// let intersection = f1.intersection(&f2);
+    /// assert!(intersection.contains(&2));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.check_compatible(other);
+        let mut result = self.clone();
+        for (word, other_word) in result
+            .bits
+            .as_raw_mut_slice()
+            .iter_mut()
+            .zip(other.bits.as_raw_slice())
+        {
+            *word &= *other_word;
+        }
+        result
+    }
+}
+
+/// Implementation of a counting Bloom filter: a Bloom filter whose bits are replaced
+/// by small counters, so that items can also be removed.
+/// # Example
+/// ```
+/// use algods::data_structure::CountingBloomFilter;
+/// let mut filter = CountingBloomFilter::new(1000, 0.01);
+/// filter.insert("key");
+/// filter.insert("key");
+/// assert_eq!(filter.count("key"), 2);
+/// assert!(filter.remove("key"));
+/// assert!(filter.contains("key"));
+/// assert!(filter.remove("key"));
+/// assert!(!filter.contains("key"));
+/// ```
+#[derive(Debug)]
+pub struct CountingBloomFilter<T: ?Sized> {
+    // counters saturate at u8::MAX, saturated counters are never decremented
+    counters: Vec<u8>,
+    nb_hashes: usize,
+    item: PhantomData<T>,
+}
+impl<T: ?Sized> Clone for CountingBloomFilter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            counters: self.counters.clone(),
+            nb_hashes: self.nb_hashes,
+            item: PhantomData,
+        }
+    }
+}
+impl<T: ?Sized> PartialEq for CountingBloomFilter<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.nb_hashes == other.nb_hashes && self.counters == other.counters
+    }
+}
+impl<T: Hash + ?Sized> CountingBloomFilter<T> {
+    /// Creates an empty filter sized so that, once `expected_items` items are inserted,
+    /// the probability of a false positive is about `false_positive_rate`.
+    /// # Panics
+    /// If `expected_items = 0` or `false_positive_rate` is not in ]0, 1[, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountingBloomFilter;
+    /// let filter = CountingBloomFilter::<str>::new(1000, 0.01);
+    /// assert_eq!(filter.nb_counters(), 9586);
+    /// ```
+    pub fn new(expected_items: usize, false_positive_rate: f64) -> Self {
+        let (nb_counters, nb_hashes) = optimal_size(expected_items, false_positive_rate);
+        Self::with_size(nb_counters, nb_hashes)
+    }
+    /// Creates an empty filter with a given number of counters and of hash functions.
+    /// # Panics
+    /// If `nb_counters = 0` or `nb_hashes = 0`, then it panics.
+    pub fn with_size(nb_counters: usize, nb_hashes: usize) -> Self {
+        if nb_counters == 0 || nb_hashes == 0 {
+            panic!("the number of counters and the number of hashes should be > 0");
+        }
+        Self {
+            counters: vec![0; nb_counters],
+            nb_hashes,
+            item: PhantomData,
+        }
+    }
+    /// Gives the number of counters of the filter.
+    pub fn nb_counters(&self) -> usize {
+        self.counters.len()
+    }
+    /// Gives the number of hash functions of the filter.
+    pub fn nb_hashes(&self) -> usize {
+        self.nb_hashes
+    }
+    /// Tests whether or not the filter is empty.
+    pub fn is_empty(&self) -> bool {
+        self.counters.iter().all(|c| *c == 0)
+    }
+    /// Inserts an item in the filter.
+    /// # Time complexity
+    /// This is expected to run in O(k) where k is the number of hash functions
+    pub fn insert(&mut self, item: &T) {
+        for position in positions(item, self.nb_counters(), self.nb_hashes) {
+            self.counters[position] = self.counters[position].saturating_add(1);
+        }
+    }
+    /// Tests whether or not an item may be in the filter, with the same guarantees as `BloomFilter`.
+    /// # Time complexity
+    /// This is expected to run in O(k) where k is the number of hash functions
+    pub fn contains(&self, item: &T) -> bool {
+        self.count(item) > 0
+    }
+    /// Returns an upper bound of the number of times an item was inserted (and not removed),
+    /// i.e. the smallest of its counters.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountingBloomFilter;
+    /// let mut filter = CountingBloomFilter::new(100, 0.01);
+    /// filter.insert(&1);
+    /// filter.insert(&1);
+    /// assert_eq!(filter.count(&1), 2);
+    /// ```
+    pub fn count(&self, item: &T) -> u8 {
+        positions(item, self.nb_counters(), self.nb_hashes)
+            .into_iter()
+            .map(|position| self.counters[position])
+            .min()
+            .unwrap_or(0)
+    }
+    /// Removes one occurrence of an item from the filter if it may be in the filter,
+    /// returning whether or not the filter was modified.
+    /// Removing an item that was never inserted may create false negatives.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountingBloomFilter;
+    /// let mut filter = CountingBloomFilter::new(100, 0.01);
+    /// filter.insert(&1);
+    /// assert!(filter.remove(&1));
+    /// assert!(!filter.remove(&1));
+    /// ```
+    pub fn remove(&mut self, item: &T) -> bool {
+        let positions = positions(item, self.nb_counters(), self.nb_hashes);
+        if positions
+            .iter()
+            .any(|position| self.counters[*position] == 0)
+        {
+            return false;
+        }
+        for position in positions {
+            if self.counters[position] < u8::MAX {
+                self.counters[position] -= 1;
+            }
+        }
+        true
+    }
+    /// Tests whether or not two filters have the same number of counters and of hash functions,
+    /// so that they can be combined.
+    pub fn is_compatible(&self, other: &Self) -> bool {
+        self.nb_counters() == other.nb_counters() && self.nb_hashes == other.nb_hashes
+    }
+    fn combine(&self, other: &Self, op: fn(u8, u8) -> u8) -> Self {
+        if !self.is_compatible(other) {
+            panic!("filters should have the same number of counters and of hashes");
+        }
+        let mut result = self.clone();
+        for (counter, other_counter) in result.counters.iter_mut().zip(other.counters.iter()) {
+            *counter = op(*counter, *other_counter);
+        }
+        result
+    }
+    /// Returns the filter of the union (sum) of the items of two compatible filters.
+    /// # Panics
+    /// If the filters are not compatible, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountingBloomFilter;
+    /// let mut f1 = CountingBloomFilter::new(10, 0.1);
+    /// let mut f2 = CountingBloomFilter::new(10, 0.1);
+    /// f1.insert(&1);
+    /// f2.insert(&1);
+    /// assert_eq!(f1.union(&f2).count(&1), 2);
+    /// ```
+    pub fn union(&self, other: &Self) -> Self {
+        self.combine(other, u8::saturating_add)
+    }
+    /// Returns a filter of the intersection of the items of two compatible filters,
+    /// keeping the smallest of the counters.
+    /// # Panics
+    /// If the filters are not compatible, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountingBloomFilter;
+    /// let mut f1 = CountingBloomFilter::new(10, 0.01);
+    /// let mut f2 = CountingBloomFilter::new(10, 0.01);
+    /// f1.insert(&1);
+    /// f1.insert(&2);
+    /// f2.insert(&2);
+    /// let intersection = f1.intersection(&f2);
+    /// assert!(intersection.contains(&2));
+    /// ```
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, u8::min)
+    }
+    /// Returns the equivalent `BloomFilter`, where bits are set for non zero counters.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountingBloomFilter;
+    /// let mut filter = CountingBloomFilter::new(10, 0.1);
+    /// filter.insert(&1);
+    /// assert!(filter.to_bloom_filter().contains(&1));
+    /// ```
+    pub fn to_bloom_filter(&self) -> BloomFilter<T> {
+        let mut filter = BloomFilter::with_size(self.nb_counters(), self.nb_hashes);
+        for (position, counter) in self.counters.iter().enumerate() {
+            if *counter > 0 {
+                filter.bits.set(position, true);
+            }
+        }
+        filter
+    }
+}
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_bloom_filter_size() {
+        let filter = BloomFilter::<usize>::new(1000, 0.01);
+        assert_eq!(filter.nb_bits(), 9586);
+        assert_eq!(filter.nb_hashes(), 7);
+        assert!(filter.is_empty());
+        let filter = BloomFilter::<usize>::new(1, 0.5);
+        assert!(filter.nb_bits() >= 1);
+        assert!(filter.nb_hashes() >= 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bloom_filter_invalid_rate() {
+        BloomFilter::<usize>::new(10, 1.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bloom_filter_no_item() {
+        BloomFilter::<usize>::new(0, 0.1);
+    }
+
+    #[test]
+    fn test_bloom_filter_no_false_negative() {
+        let mut filter = BloomFilter::new(1000, 0.01);
+        for k in 0..1000usize {
+            filter.insert(&k);
+        }
+        assert!((0..1000usize).all(|k| filter.contains(&k)));
+        assert!(!filter.is_empty());
+    }
+
+    #[test]
+    fn test_bloom_filter_false_positive_rate() {
+        let mut filter = BloomFilter::new(1000, 0.01);
+        for k in 0..1000usize {
+            filter.insert(&k);
+        }
+        let false_positives = (1000..101_000usize).filter(|k| filter.contains(k)).count();
+        // expected around 1000 false positives
+        assert!(false_positives < 2000, "{false_positives}");
+        assert!(filter.false_positive_rate() < 0.02);
+        assert!((filter.estimated_len() - 1000.).abs() < 50.);
+    }
+
+    #[test]
+    fn test_bloom_filter_union_intersection() {
+        let mut f1 = BloomFilter::new(100, 0.001);
+        let mut f2 = BloomFilter::new(100, 0.001);
+        for k in 0..50usize {
+            f1.insert(&k);
+        }
+        for k in 25..75usize {
+            f2.insert(&k);
+        }
+        let union = f1.union(&f2);
+        assert!((0..75usize).all(|k| union.contains(&k)));
+        let intersection = f1.intersection(&f2);
+        assert!((25..50usize).all(|k| intersection.contains(&k)));
+        assert!(intersection.estimated_len() < union.estimated_len());
+        // the union is the filter built from all the items
+        let mut all = BloomFilter::new(100, 0.001);
+        for k in 0..75usize {
+            all.insert(&k);
+        }
+        assert_eq!(union, all);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bloom_filter_incompatible() {
+        let f1 = BloomFilter::<usize>::new(100, 0.01);
+        let f2 = BloomFilter::<usize>::new(100, 0.1);
+        assert!(!f1.is_compatible(&f2));
+        f1.union(&f2);
+    }
+
+    #[test]
+    fn test_counting_bloom_filter() {
+        let mut filter = CountingBloomFilter::new(1000, 0.01);
+        assert!(filter.is_empty());
+        for k in 0..1000usize {
+            filter.insert(&k);
+        }
+        assert!((0..1000usize).all(|k| filter.contains(&k)));
+        for k in 0..500usize {
+            assert!(filter.remove(&k));
+        }
+        assert!((500..1000usize).all(|k| filter.contains(&k)));
+        let remaining = (0..500usize).filter(|k| filter.contains(k)).count();
+        assert!(remaining < 20, "{remaining}");
+        for k in 500..1000usize {
+            assert!(filter.remove(&k));
+        }
+        assert!(filter.is_empty());
+    }
+
+    #[test]
+    fn test_counting_bloom_filter_saturation() {
+        let mut filter = CountingBloomFilter::with_size(8, 1);
+        for _ in 0..300 {
+            filter.insert(&0usize);
+        }
+        assert_eq!(filter.count(&0usize), u8::MAX);
+        // saturated counters are never decremented
+        for _ in 0..300 {
+            assert!(filter.remove(&0usize));
+        }
+        assert!(filter.contains(&0usize));
+    }
+
+    #[test]
+    fn test_counting_bloom_filter_combine() {
+        let mut f1 = CountingBloomFilter::new(100, 0.001);
+        let mut f2 = CountingBloomFilter::new(100, 0.001);
+        for k in 0..50usize {
+            f1.insert(&k);
+        }
+        for k in 25..75usize {
+            f2.insert(&k);
+        }
+        let union = f1.union(&f2);
+        assert!((25..50usize).all(|k| union.count(&k) >= 2));
+        assert!((0..75usize).all(|k| union.contains(&k)));
+        let intersection = f1.intersection(&f2);
+        assert!((25..50usize).all(|k| intersection.contains(&k)));
+        let bloom = union.to_bloom_filter();
+        assert!((0..75usize).all(|k| bloom.contains(&k)));
+    }
+}
@@ -0,0 +1,565 @@
+mod page_store;
+#[cfg(test)]
+mod unit_test;
+pub use page_store::{Codec, FilePageStore, Header, MemoryPageStore, Page, PageId, PageStore};
+use std::io;
+use std::marker::PhantomData;
+
+/// Implementation of a B-tree of order `M`: every node holds at most `M - 1` sorted keys
+/// and `M` children, and every node but the root holds at least `ceil(M/2) - 1` keys,
+/// so that all the leaves are at the same depth. Nodes are split when they overflow on
+/// insertion, and borrow from or are merged with a sibling when they underflow on deletion.
+///
+/// The nodes are pages of a `PageStore`, kept in memory by default
+/// (`MemoryPageStore`) or in a file through a page cache (`FilePageStore`).
+/// Searches borrow the pages on their path and only copy the keys or values they return,
+/// whereas updates copy the pages they modify before writing them back to the store.
+/// # Errors
+/// The methods accessing the store return its IO errors, which never occur in memory.
+/// After an error, the tree may be partially updated and should not be used anymore.
+/// # Examples
+/// ```
+/// use algods::data_structure::BTree;
+/// let mut tree = BTree::<usize, &str, 4>::new();
+/// for (k, v) in [(3, "3"), (1, "1"), (4, "4"), (5, "5"), (9, "9"), (2, "2")] {
+///     tree.insert(k, v).unwrap();
+/// }
+/// assert_eq!(tree.len(), 6);
+/// assert_eq!(tree.get(&4).unwrap(), Some("4"));
+/// assert_eq!(tree.delete(&4).unwrap(), Some("4"));
+/// assert_eq!(tree.range_search(&2, &9).unwrap(), vec![2, 3, 5]);
+/// assert_eq!(tree.height().unwrap(), 2);
+/// ```
+/// With a file backed store, reopened after being flushed:
+/// ```
+/// use algods::data_structure::{BTree, FilePageStore};
+/// let path = std::env::temp_dir().join("algods_btree_doc.db");
+/// let store = FilePageStore::<u64, u64>::create(&path, 512, 8).unwrap();
+/// let mut tree = BTree::<u64, u64, 16, _>::with_store(store).unwrap();
+/// for k in 0..1000 {
+///     tree.insert(k, 2 * k).unwrap();
+/// }
+/// tree.flush().unwrap();
+/// drop(tree);
+/// let store = FilePageStore::<u64, u64>::open(&path, 8).unwrap();
+/// let tree = BTree::<u64, u64, 16, _>::open(store).unwrap();
+/// assert_eq!(tree.len(), 1000);
+/// assert_eq!(tree.get(&500).unwrap(), Some(1000));
+/// std::fs::remove_file(path).unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct BTree<K, V, const M: usize, S = MemoryPageStore<K, V>> {
+    store: S,
+    // id of the root page
+    root: PageId,
+    len: usize,
+    item: PhantomData<(K, V)>,
+    // Remarks:
+    // - an internal page with n keys has n + 1 children, the keys of the child i
+    //   being between the keys i - 1 and i of the page
+    // - the root is a leaf as long as the tree holds at most M - 1 keys
+    // - the root and the length are recorded in the header of the store
+}
+impl<K: Ord + Clone, V: Clone, const M: usize> Default for BTree<K, V, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K: Ord + Clone, V: Clone, const M: usize> BTree<K, V, M> {
+    /// Creates an empty tree whose nodes are kept in memory.
+    /// # Panics
+    /// If `M < 3`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let tree = BTree::<usize, isize, 3>::new();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::with_store(MemoryPageStore::new()).expect("a memory page store never fails")
+    }
+}
+enum Step<T> {
+    // result of the visit of a page when searching the tree
+    Found(T),
+    NotFound,
+    Child(PageId),
+}
+impl<K: Ord + Clone, V: Clone, const M: usize, S: PageStore<K, V>> BTree<K, V, M, S> {
+    // minimum number of keys of a page other than the root
+    const MIN_KEYS: usize = M.div_ceil(2) - 1;
+
+    /// Creates an empty tree whose nodes are kept in a given (empty) store.
+    /// # Panics
+    /// If `M < 3`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{BTree, MemoryPageStore};
+    /// let tree = BTree::<usize, isize, 8, _>::with_store(MemoryPageStore::new()).unwrap();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn with_store(mut store: S) -> io::Result<Self> {
+        if M < 3 {
+            panic!("the order M should be >= 3, got {M}");
+        }
+        let root = store.allocate(Page::default())?;
+        let mut tree = Self {
+            store,
+            root,
+            len: 0,
+            item: PhantomData,
+        };
+        tree.set_header();
+        Ok(tree)
+    }
+    /// Opens the tree whose root and length are recorded in the header of a store,
+    /// e.g. a `FilePageStore` reopened after a tree was flushed to it.
+    /// It returns an `InvalidData` error if the store holds no tree.
+    /// # Panics
+    /// If `M < 3`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(1, -1).unwrap();
+    /// let tree = BTree::<usize, isize, 3>::open(tree.into_store()).unwrap();
+    /// assert_eq!(tree.get(&1).unwrap(), Some(-1));
+    /// ```
+    pub fn open(store: S) -> io::Result<Self> {
+        if M < 3 {
+            panic!("the order M should be >= 3, got {M}");
+        }
+        match store.header() {
+            Some(Header { root, len }) => Ok(Self {
+                store,
+                root,
+                len,
+                item: PhantomData,
+            }),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "the store holds no tree",
+            )),
+        }
+    }
+    fn set_header(&mut self) {
+        self.store.set_header(Header {
+            root: self.root,
+            len: self.len,
+        });
+    }
+    /// Gives the number of (key, value) pairs in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(1, -1).unwrap();
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Tests whether or not the tree is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(1, -1).unwrap();
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Gives the number of levels of the tree (1 for a tree whose root is a leaf).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, usize, 3>::new();
+    /// for k in 0..3 {
+    ///     tree.insert(k, k).unwrap();
+    /// }
+    /// assert_eq!(tree.height().unwrap(), 2);
+    /// ```
+    pub fn height(&self) -> io::Result<usize> {
+        let mut height = 1;
+        let mut id = self.root;
+        while let Some(first) = self
+            .store
+            .with_page(id, |page| page.children.first().copied())?
+        {
+            id = first;
+            height += 1;
+        }
+        Ok(height)
+    }
+    /// Returns a reference to the store of the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let tree = BTree::<usize, usize, 3>::new();
+    /// assert_eq!(tree.store().nb_pages(), 1);
+    /// ```
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+    /// Returns the store of the tree, whose header records the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let tree = BTree::<usize, usize, 3>::new();
+    /// assert_eq!(tree.into_store().nb_pages(), 1);
+    /// ```
+    pub fn into_store(self) -> S {
+        self.store
+    }
+    /// Persists the pages not yet written to the storage of the tree, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, usize, 3>::new();
+    /// tree.insert(1, 1).unwrap();
+    /// tree.flush().unwrap();
+    /// ```
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.store.flush()
+    }
+    fn search<T, F: Fn(&Page<K, V>, usize) -> T>(
+        &self,
+        key: &K,
+        found: F,
+    ) -> io::Result<Option<T>> {
+        // visits the pages from the root to the page holding the key, if any
+        let mut id = self.root;
+        loop {
+            let step = self
+                .store
+                .with_page(id, |page| match page.keys.binary_search(key) {
+                    Ok(i) => Step::Found(found(page, i)),
+                    Err(_) if page.is_leaf() => Step::NotFound,
+                    Err(i) => Step::Child(page.children[i]),
+                })?;
+            match step {
+                Step::Found(res) => return Ok(Some(res)),
+                Step::NotFound => return Ok(None),
+                Step::Child(child) => id = child,
+            }
+        }
+    }
+    /// Returns a copy of the value associated with a key, if any.
+    /// The pages on the path from the root are borrowed from the store, not copied.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(1, -1).unwrap();
+    /// assert_eq!(tree.get(&1).unwrap(), Some(-1));
+    /// assert_eq!(tree.get(&2).unwrap(), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn get(&self, key: &K) -> io::Result<Option<V>> {
+        self.search(key, |page, i| page.values[i].clone())
+    }
+    /// Tests whether or not the tree contains a key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(1, -1).unwrap();
+    /// assert!(tree.contains(&1).unwrap());
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn contains(&self, key: &K) -> io::Result<bool> {
+        Ok(self.search(key, |_, _| ())?.is_some())
+    }
+    /// Returns the smallest key in the tree, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(2, -2).unwrap();
+    /// tree.insert(1, -1).unwrap();
+    /// assert_eq!(tree.min().unwrap(), Some(1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn min(&self) -> io::Result<Option<K>> {
+        let mut id = self.root;
+        loop {
+            let step = self
+                .store
+                .with_page(id, |page| match page.children.first() {
+                    Some(&first) => Step::Child(first),
+                    None => page
+                        .keys
+                        .first()
+                        .cloned()
+                        .map_or(Step::NotFound, Step::Found),
+                })?;
+            match step {
+                Step::Found(key) => return Ok(Some(key)),
+                Step::NotFound => return Ok(None),
+                Step::Child(child) => id = child,
+            }
+        }
+    }
+    /// Returns the largest key in the tree, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(2, -2).unwrap();
+    /// tree.insert(1, -1).unwrap();
+    /// assert_eq!(tree.max().unwrap(), Some(2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn max(&self) -> io::Result<Option<K>> {
+        let mut id = self.root;
+        loop {
+            let step = self
+                .store
+                .with_page(id, |page| match page.children.last() {
+                    Some(&last) => Step::Child(last),
+                    None => page
+                        .keys
+                        .last()
+                        .cloned()
+                        .map_or(Step::NotFound, Step::Found),
+                })?;
+            match step {
+                Step::Found(key) => return Ok(Some(key)),
+                Step::NotFound => return Ok(None),
+                Step::Child(child) => id = child,
+            }
+        }
+    }
+    /// Returns the list of keys in the tree that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<isize, usize, 3>::new();
+    /// for k in -3..3 {
+    ///     tree.insert(k, 0).unwrap();
+    /// }
+    /// assert_eq!(tree.range_search(&-2, &1).unwrap(), vec![-2, -1, 0]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N) + R) where R is the number of keys returned
+    pub fn range_search(&self, low: &K, high: &K) -> io::Result<Vec<K>> {
+        let mut keys = Vec::new();
+        if low < high {
+            self.collect(self.root, low, high, &mut keys)?;
+        }
+        Ok(keys)
+    }
+    fn collect(&self, id: PageId, low: &K, high: &K, keys: &mut Vec<K>) -> io::Result<()> {
+        // in order traversal of the subtrees which may hold keys in [low, high[:
+        // the keys of the page in range are copied, then the children are visited
+        let (in_range, children) = self.store.with_page(id, |page| {
+            let start = page.keys.partition_point(|key| key < low);
+            let in_range: Vec<K> = page.keys[start..]
+                .iter()
+                .take_while(|key| *key < high)
+                .cloned()
+                .collect();
+            let children = match page.is_leaf() {
+                true => Vec::new(),
+                false => page.children[start..=start + in_range.len()].to_vec(),
+            };
+            (in_range, children)
+        })?;
+        let mut in_range = in_range.into_iter();
+        for child in children {
+            self.collect(child, low, high, keys)?;
+            keys.extend(in_range.next());
+        }
+        keys.extend(in_range);
+        Ok(())
+    }
+    /// Inserts a (key, value) pair in the tree, the value replacing
+    /// the previous one if the key is already in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(1, -1).unwrap();
+    /// tree.insert(1, -2).unwrap();
+    /// assert_eq!(tree.len(), 1);
+    /// assert_eq!(tree.get(&1).unwrap(), Some(-2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn insert(&mut self, key: K, value: V) -> io::Result<()> {
+        if let Some((key, value, right)) = self.insert_into(self.root, key, value)? {
+            // the root was split: the tree grows by one level
+            let root = Page {
+                keys: vec![key],
+                values: vec![value],
+                children: vec![self.root, right],
+            };
+            self.root = self.store.allocate(root)?;
+        }
+        self.set_header();
+        Ok(())
+    }
+    fn insert_into(&mut self, id: PageId, key: K, value: V) -> io::Result<Option<(K, V, PageId)>> {
+        // inserts into the subtree rooted at page id and returns the median
+        // (key, value) pair and the new right page if the page was split
+        let mut page = self.store.read(id)?;
+        match page.keys.binary_search(&key) {
+            Ok(i) => page.values[i] = value,
+            Err(i) if page.is_leaf() => {
+                page.keys.insert(i, key);
+                page.values.insert(i, value);
+                self.len += 1;
+            }
+            Err(i) => match self.insert_into(page.children[i], key, value)? {
+                Some((key, value, right)) => {
+                    page.keys.insert(i, key);
+                    page.values.insert(i, value);
+                    page.children.insert(i + 1, right);
+                }
+                None => return Ok(None),
+            },
+        }
+        if page.keys.len() < M {
+            self.store.write(id, page)?;
+            return Ok(None);
+        }
+        // splits the page around its median key
+        let mid = M / 2;
+        let right = Page {
+            keys: page.keys.split_off(mid + 1),
+            values: page.values.split_off(mid + 1),
+            children: if page.is_leaf() {
+                Vec::new()
+            } else {
+                page.children.split_off(mid + 1)
+            },
+        };
+        let (key, value) = (page.keys.pop().unwrap(), page.values.pop().unwrap());
+        self.store.write(id, page)?;
+        Ok(Some((key, value, self.store.allocate(right)?)))
+    }
+    /// Deletes a key from the tree and returns its value, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BTree;
+    /// let mut tree = BTree::<usize, isize, 3>::new();
+    /// tree.insert(1, -1).unwrap();
+    /// assert_eq!(tree.delete(&1).unwrap(), Some(-1));
+    /// assert_eq!(tree.delete(&1).unwrap(), None);
+    /// assert!(tree.is_empty());
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn delete(&mut self, key: &K) -> io::Result<Option<V>> {
+        let res = self.delete_from(self.root, key)?;
+        if res.is_some() {
+            self.len -= 1;
+            let child = self.store.with_page(self.root, |root| {
+                (root.keys.is_empty() && !root.is_leaf()).then(|| root.children[0])
+            })?;
+            if let Some(child) = child {
+                // the root was merged into its only child: the tree shrinks by one level
+                self.store.free(self.root)?;
+                self.root = child;
+            }
+            self.set_header();
+        }
+        Ok(res)
+    }
+    fn delete_from(&mut self, id: PageId, key: &K) -> io::Result<Option<V>> {
+        let mut page = self.store.read(id)?;
+        let res = match page.keys.binary_search(key) {
+            Ok(i) if page.is_leaf() => {
+                page.keys.remove(i);
+                Some(page.values.remove(i))
+            }
+            Ok(i) => {
+                // replaces the key by its predecessor, deleted from the left subtree
+                let (key, value) = self.delete_max(page.children[i])?;
+                page.keys[i] = key;
+                let res = std::mem::replace(&mut page.values[i], value);
+                self.rebalance(&mut page, i)?;
+                Some(res)
+            }
+            Err(_) if page.is_leaf() => return Ok(None),
+            Err(i) => match self.delete_from(page.children[i], key)? {
+                Some(res) => {
+                    self.rebalance(&mut page, i)?;
+                    Some(res)
+                }
+                None => return Ok(None),
+            },
+        };
+        self.store.write(id, page)?;
+        Ok(res)
+    }
+    fn delete_max(&mut self, id: PageId) -> io::Result<(K, V)> {
+        // deletes the largest key of the subtree rooted at page id
+        let mut page = self.store.read(id)?;
+        let res = if page.is_leaf() {
+            (page.keys.pop().unwrap(), page.values.pop().unwrap())
+        } else {
+            let last = page.children.len() - 1;
+            let res = self.delete_max(page.children[last])?;
+            self.rebalance(&mut page, last)?;
+            res
+        };
+        self.store.write(id, page)?;
+        Ok(res)
+    }
+    fn rebalance(&mut self, parent: &mut Page<K, V>, i: usize) -> io::Result<()> {
+        // restores the minimum number of keys of the child i of a page
+        // by borrowing a key from one of its siblings or merging it with one of them
+        let mut child = self.store.read(parent.children[i])?;
+        if child.keys.len() >= Self::MIN_KEYS {
+            return Ok(());
+        }
+        if i > 0 {
+            let mut left = self.store.read(parent.children[i - 1])?;
+            if left.keys.len() > Self::MIN_KEYS {
+                // rotates the last key of the left sibling through the parent
+                let key = std::mem::replace(&mut parent.keys[i - 1], left.keys.pop().unwrap());
+                let value =
+                    std::mem::replace(&mut parent.values[i - 1], left.values.pop().unwrap());
+                child.keys.insert(0, key);
+                child.values.insert(0, value);
+                if let Some(last) = left.children.pop() {
+                    child.children.insert(0, last);
+                }
+                self.store.write(parent.children[i - 1], left)?;
+                return self.store.write(parent.children[i], child);
+            }
+        }
+        if i + 1 < parent.children.len() {
+            let mut right = self.store.read(parent.children[i + 1])?;
+            if right.keys.len() > Self::MIN_KEYS {
+                // rotates the first key of the right sibling through the parent
+                let key = std::mem::replace(&mut parent.keys[i], right.keys.remove(0));
+                let value = std::mem::replace(&mut parent.values[i], right.values.remove(0));
+                child.keys.push(key);
+                child.values.push(value);
+                if !right.is_leaf() {
+                    child.children.push(right.children.remove(0));
+                }
+                self.store.write(parent.children[i + 1], right)?;
+                return self.store.write(parent.children[i], child);
+            }
+        }
+        // merges the child with a sibling and the key separating them
+        let i = if i > 0 { i - 1 } else { i };
+        let mut left = self.store.read(parent.children[i])?;
+        let right = self.store.read(parent.children[i + 1])?;
+        left.keys.push(parent.keys.remove(i));
+        left.values.push(parent.values.remove(i));
+        left.keys.extend(right.keys);
+        left.values.extend(right.values);
+        left.children.extend(right.children);
+        self.store.write(parent.children[i], left)?;
+        self.store.free(parent.children.remove(i + 1))
+    }
+}
@@ -0,0 +1,584 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::Path;
+
+/// Identifier of a page in a `PageStore`.
+pub type PageId = usize;
+
+/// Node of a `BTree` as stored in a `PageStore`: sorted keys, their values
+/// and, for internal nodes, the ids of the `keys.len() + 1` children.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<K, V> {
+    pub keys: Vec<K>,
+    pub values: Vec<V>,
+    pub children: Vec<PageId>,
+}
+impl<K, V> Default for Page<K, V> {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+}
+impl<K, V> Page<K, V> {
+    /// Tests whether or not the page has no children.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Page;
+    /// let page = Page::<usize, usize>::default();
+    /// assert!(page.is_leaf());
+    /// ```
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+/// Root page and number of keys of a `BTree`, recorded by its `PageStore`
+/// so that the tree can be reopened.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub root: PageId,
+    pub len: usize,
+}
+
+/// Storage of the pages of a `BTree`: the tree only refers to its nodes
+/// through their ids, so that they can live in memory or on disk.
+/// Methods return the IO errors of the underlying storage, if any.
+pub trait PageStore<K, V> {
+    /// Stores a new page and returns its id.
+    fn allocate(&mut self, page: Page<K, V>) -> io::Result<PageId>;
+    /// Calls a function on a borrowed page with a given id and returns its result.
+    /// The function should not access the store.
+    fn with_page<R, F: FnOnce(&Page<K, V>) -> R>(&self, id: PageId, f: F) -> io::Result<R>;
+    /// Returns a copy of the page with a given id, e.g. to modify it before writing it back.
+    fn read(&self, id: PageId) -> io::Result<Page<K, V>>
+    where
+        K: Clone,
+        V: Clone,
+    {
+        self.with_page(id, Page::clone)
+    }
+    /// Overwrites the page with a given id.
+    fn write(&mut self, id: PageId, page: Page<K, V>) -> io::Result<()>;
+    /// Releases the page with a given id, which may be reused by a later allocation.
+    fn free(&mut self, id: PageId) -> io::Result<()>;
+    /// Returns the header recorded in the store, if any.
+    fn header(&self) -> Option<Header>;
+    /// Records a header in the store, persisted with the pages.
+    fn set_header(&mut self, header: Header);
+    /// Persists the pages (and the header) not yet written to the underlying storage, if any.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// In memory `PageStore`: an arena of pages whose free slots are reused.
+/// # Example
+/// ```
+/// use algods::data_structure::{MemoryPageStore, Page, PageStore};
+/// let mut store = MemoryPageStore::new();
+/// let id = store.allocate(Page { keys: vec![1], values: vec!["1"], children: vec![] }).unwrap();
+/// assert_eq!(store.read(id).unwrap().keys, vec![1]);
+/// store.free(id).unwrap();
+/// assert_eq!(store.nb_pages(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MemoryPageStore<K, V> {
+    // arena of pages, None slots are free
+    pages: Vec<Option<Page<K, V>>>,
+    // free slots in the arena
+    free: Vec<PageId>,
+    header: Option<Header>,
+}
+impl<K, V> Default for MemoryPageStore<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K, V> MemoryPageStore<K, V> {
+    /// Creates an empty store.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MemoryPageStore;
+    /// let store = MemoryPageStore::<usize, usize>::new();
+    /// assert_eq!(store.nb_pages(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            free: Vec::new(),
+            header: None,
+        }
+    }
+    /// Gives the number of pages in use.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{MemoryPageStore, Page, PageStore};
+    /// let mut store = MemoryPageStore::<usize, usize>::new();
+    /// store.allocate(Page::default()).unwrap();
+    /// assert_eq!(store.nb_pages(), 1);
+    /// ```
+    pub fn nb_pages(&self) -> usize {
+        self.pages.len() - self.free.len()
+    }
+    fn page(&self, id: PageId) -> &Page<K, V> {
+        match &self.pages[id] {
+            Some(page) => page,
+            None => panic!("page {id} is not allocated"),
+        }
+    }
+}
+impl<K, V> PageStore<K, V> for MemoryPageStore<K, V> {
+    fn allocate(&mut self, page: Page<K, V>) -> io::Result<PageId> {
+        match self.free.pop() {
+            Some(id) => {
+                self.pages[id] = Some(page);
+                Ok(id)
+            }
+            None => {
+                self.pages.push(Some(page));
+                Ok(self.pages.len() - 1)
+            }
+        }
+    }
+    fn with_page<R, F: FnOnce(&Page<K, V>) -> R>(&self, id: PageId, f: F) -> io::Result<R> {
+        Ok(f(self.page(id)))
+    }
+    fn write(&mut self, id: PageId, page: Page<K, V>) -> io::Result<()> {
+        self.page(id);
+        self.pages[id] = Some(page);
+        Ok(())
+    }
+    fn free(&mut self, id: PageId) -> io::Result<()> {
+        if self.pages[id].take().is_some() {
+            self.free.push(id);
+        }
+        Ok(())
+    }
+    fn header(&self) -> Option<Header> {
+        self.header
+    }
+    fn set_header(&mut self, header: Header) {
+        self.header = Some(header);
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Binary encoding of the keys and values of a `FilePageStore`.
+pub trait Codec: Sized {
+    /// Appends the encoding of the object to a buffer.
+    fn encode(&self, buf: &mut Vec<u8>);
+    /// Decodes an object from the start of a buffer and returns it
+    /// with the number of bytes read. Invalid bytes give an `InvalidData` error.
+    fn decode(bytes: &[u8]) -> io::Result<(Self, usize)>;
+}
+macro_rules! impl_codec {
+    ($($t:ty),*) => {
+        $(
+            impl Codec for $t {
+                fn encode(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes());
+                }
+                fn decode(bytes: &[u8]) -> io::Result<(Self, usize)> {
+                    const SIZE: usize = std::mem::size_of::<$t>();
+                    match bytes.get(..SIZE) {
+                        Some(slice) => {
+                            let mut array = [0; SIZE];
+                            array.copy_from_slice(slice);
+                            Ok((<$t>::from_le_bytes(array), SIZE))
+                        }
+                        None => Err(invalid_data(format!(
+                            "{} bytes left, {SIZE} needed to decode a {}",
+                            bytes.len(),
+                            stringify!($t)
+                        ))),
+                    }
+                }
+            }
+        )*
+    };
+}
+impl_codec!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+impl Codec for String {
+    fn encode(&self, buf: &mut Vec<u8>) {
+        // length prefixed utf-8 bytes
+        (self.len() as u32).encode(buf);
+        buf.extend_from_slice(self.as_bytes());
+    }
+    fn decode(bytes: &[u8]) -> io::Result<(Self, usize)> {
+        let (len, start) = u32::decode(bytes)?;
+        let end = start + len as usize;
+        let Some(slice) = bytes.get(start..end) else {
+            return Err(invalid_data(format!(
+                "{} bytes left, {end} needed to decode a string",
+                bytes.len()
+            )));
+        };
+        match String::from_utf8(slice.to_vec()) {
+            Ok(string) => Ok((string, end)),
+            Err(error) => Err(invalid_data(error.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CachedPage<K, V> {
+    page: Page<K, V>,
+    // whether or not the page differs from its copy on disk
+    dirty: bool,
+    // logical time of the last access, used to evict the least recently used page
+    last_used: u64,
+}
+
+// Identifies the files written by a FilePageStore
+const MAGIC: &[u8; 8] = b"ALGODSBT";
+// Number of bytes of the header of the file
+const HEADER_SIZE: usize = 64;
+// Id written in the header in place of a missing page
+const NO_PAGE: u64 = u64::MAX;
+
+/// File backed `PageStore`: pages of a fixed number of bytes are written after the header
+/// of the file, at offset `HEADER_SIZE + id * page_size`, and the most recently used ones
+/// are kept decoded in a cache of bounded capacity. Modified pages are written back to
+/// the file when they are evicted from the cache or when the store is flushed.
+///
+/// The header records the page size, the number of pages, the free pages (chained through
+/// the pages themselves) and the header of the tree, so that a flushed store can be reopened.
+/// The store is flushed when dropped, ignoring errors: call `flush` to handle them.
+/// # Panics
+/// If a page whose encoding exceeds the page size is written, then it panics.
+/// # Example
+/// ```
+/// use algods::data_structure::{FilePageStore, Page, PageStore};
+/// let path = std::env::temp_dir().join("algods_file_page_store_doc.db");
+/// let mut store = FilePageStore::<u64, String>::create(&path, 256, 2).unwrap();
+/// let page = Page { keys: vec![1], values: vec!["one".to_string()], children: vec![] };
+/// let id = store.allocate(page).unwrap();
+/// for k in 0..3 {
+///     // evicts the first page from the cache
+///     store.allocate(Page { keys: vec![k], values: vec![k.to_string()], children: vec![] }).unwrap();
+/// }
+/// assert_eq!(store.read(id).unwrap().values, vec!["one".to_string()]);
+/// drop(store);
+/// let store = FilePageStore::<u64, String>::open(&path, 2).unwrap();
+/// assert_eq!(store.nb_pages(), 4);
+/// std::fs::remove_file(path).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct FilePageStore<K: Codec, V: Codec> {
+    file: File,
+    page_size: usize,
+    // number of pages in the file, allocated or free
+    nb_pages: usize,
+    // first free page, each free page starting with the id of the next one
+    free_head: Option<PageId>,
+    nb_free: usize,
+    header: Option<Header>,
+    cache: RefCell<HashMap<PageId, CachedPage<K, V>>>,
+    cache_capacity: usize,
+    clock: Cell<u64>,
+}
+impl<K: Codec, V: Codec> FilePageStore<K, V> {
+    /// Number of bytes at the start of the file holding the header of the store.
+    pub const HEADER_SIZE: usize = HEADER_SIZE;
+}
+impl<K: Codec, V: Codec> FilePageStore<K, V> {
+    fn with_file(file: File, page_size: usize, cache_capacity: usize) -> Self {
+        if page_size < 8 {
+            panic!("page_size should be >= 8, got {page_size}");
+        }
+        if cache_capacity == 0 {
+            panic!("cache_capacity should be > 0");
+        }
+        Self {
+            file,
+            page_size,
+            nb_pages: 0,
+            free_head: None,
+            nb_free: 0,
+            header: None,
+            cache: RefCell::new(HashMap::new()),
+            cache_capacity,
+            clock: Cell::new(0),
+        }
+    }
+    /// Creates an empty store backed by the file at `path` (created or truncated), with pages
+    /// of `page_size` bytes and a cache of at most `cache_capacity` pages.
+    /// # Panics
+    /// If `page_size < 8` or `cache_capacity == 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FilePageStore;
+    /// let path = std::env::temp_dir().join("algods_file_page_store_create_doc.db");
+    /// let store = FilePageStore::<u64, u64>::create(&path, 4096, 16).unwrap();
+    /// assert_eq!(store.nb_pages(), 0);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn create<P: AsRef<Path>>(
+        path: P,
+        page_size: usize,
+        cache_capacity: usize,
+    ) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        let mut store = Self::with_file(file, page_size, cache_capacity);
+        store.write_header()?;
+        Ok(store)
+    }
+    /// Opens a store previously flushed to the file at `path`, with a cache of at most
+    /// `cache_capacity` pages. The page size is read from the file.
+    /// # Panics
+    /// If `cache_capacity == 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FilePageStore, Page, PageStore};
+    /// let path = std::env::temp_dir().join("algods_file_page_store_open_doc.db");
+    /// let mut store = FilePageStore::<u64, u64>::create(&path, 64, 1).unwrap();
+    /// let id = store.allocate(Page { keys: vec![1], values: vec![2], children: vec![] }).unwrap();
+    /// store.flush().unwrap();
+    /// let store = FilePageStore::<u64, u64>::open(&path, 1).unwrap();
+    /// assert_eq!(store.page_size(), 64);
+    /// assert_eq!(store.read(id).unwrap().values, vec![2]);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn open<P: AsRef<Path>>(path: P, cache_capacity: usize) -> io::Result<Self> {
+        let mut file = OpenOptions::new().read(true).write(true).open(path)?;
+        let mut bytes = [0; HEADER_SIZE];
+        file.read_exact(&mut bytes)?;
+        if &bytes[..8] != MAGIC {
+            return Err(invalid_data("not a file page store".to_string()));
+        }
+        let mut fields = [0u64; 6];
+        for (k, field) in fields.iter_mut().enumerate() {
+            *field = u64::decode(&bytes[8 * (k + 1)..])?.0;
+        }
+        let [page_size, nb_pages, free_head, nb_free, root, len] = fields;
+        let mut store = Self::with_file(file, page_size as usize, cache_capacity);
+        store.nb_pages = nb_pages as usize;
+        store.free_head = (free_head != NO_PAGE).then_some(free_head as PageId);
+        store.nb_free = nb_free as usize;
+        store.header = (root != NO_PAGE).then_some(Header {
+            root: root as PageId,
+            len: len as usize,
+        });
+        Ok(store)
+    }
+    /// Gives the number of pages in use.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FilePageStore, Page, PageStore};
+    /// let path = std::env::temp_dir().join("algods_file_page_store_nb_pages_doc.db");
+    /// let mut store = FilePageStore::<u64, u64>::create(&path, 64, 1).unwrap();
+    /// store.allocate(Page::default()).unwrap();
+    /// assert_eq!(store.nb_pages(), 1);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn nb_pages(&self) -> usize {
+        self.nb_pages - self.nb_free
+    }
+    /// Gives the size of the pages in bytes.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FilePageStore;
+    /// let path = std::env::temp_dir().join("algods_file_page_store_page_size_doc.db");
+    /// let store = FilePageStore::<u64, u64>::create(&path, 64, 1).unwrap();
+    /// assert_eq!(store.page_size(), 64);
+    /// std::fs::remove_file(path).unwrap();
+    /// ```
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+    fn tick(&self) -> u64 {
+        let time = self.clock.get() + 1;
+        self.clock.set(time);
+        time
+    }
+    fn offset(&self, id: PageId) -> u64 {
+        (HEADER_SIZE + id * self.page_size) as u64
+    }
+    fn write_header(&mut self) -> io::Result<()> {
+        let mut buf = Vec::with_capacity(HEADER_SIZE);
+        buf.extend_from_slice(MAGIC);
+        let (root, len) = match self.header {
+            Some(header) => (header.root as u64, header.len as u64),
+            None => (NO_PAGE, 0),
+        };
+        let free_head = self.free_head.map_or(NO_PAGE, |id| id as u64);
+        for field in [
+            self.page_size as u64,
+            self.nb_pages as u64,
+            free_head,
+            self.nb_free as u64,
+            root,
+            len,
+        ] {
+            field.encode(&mut buf);
+        }
+        buf.resize(HEADER_SIZE, 0);
+        self.file.seek(SeekFrom::Start(0))?;
+        self.file.write_all(&buf)
+    }
+    fn encode(&self, page: &Page<K, V>) -> Vec<u8> {
+        // number of keys, then the keys, the values and the children ids
+        let mut buf = Vec::with_capacity(self.page_size);
+        (page.keys.len() as u32).encode(&mut buf);
+        (page.children.len() as u32).encode(&mut buf);
+        page.keys.iter().for_each(|key| key.encode(&mut buf));
+        page.values.iter().for_each(|value| value.encode(&mut buf));
+        page.children
+            .iter()
+            .for_each(|id| (*id as u64).encode(&mut buf));
+        if buf.len() > self.page_size {
+            panic!(
+                "encoded page of {} bytes exceeds the page size of {} bytes",
+                buf.len(),
+                self.page_size
+            );
+        }
+        buf.resize(self.page_size, 0);
+        buf
+    }
+    fn decode(bytes: &[u8]) -> io::Result<Page<K, V>> {
+        let mut start = 0;
+        let next = |start: &mut usize| -> io::Result<usize> {
+            let (n, read) = u32::decode(&bytes[*start..])?;
+            *start += read;
+            Ok(n as usize)
+        };
+        let (nb_keys, nb_children) = (next(&mut start)?, next(&mut start)?);
+        let mut page = Page::default();
+        for _ in 0..nb_keys {
+            let (key, read) = K::decode(&bytes[start..])?;
+            page.keys.push(key);
+            start += read;
+        }
+        for _ in 0..nb_keys {
+            let (value, read) = V::decode(&bytes[start..])?;
+            page.values.push(value);
+            start += read;
+        }
+        for _ in 0..nb_children {
+            let (id, read) = u64::decode(&bytes[start..])?;
+            page.children.push(id as PageId);
+            start += read;
+        }
+        Ok(page)
+    }
+    fn write_bytes(&self, id: PageId, bytes: &[u8]) -> io::Result<()> {
+        let mut file = &self.file;
+        file.seek(SeekFrom::Start(self.offset(id)))?;
+        file.write_all(bytes)
+    }
+    fn read_bytes(&self, id: PageId, bytes: &mut [u8]) -> io::Result<()> {
+        let mut file = &self.file;
+        file.seek(SeekFrom::Start(self.offset(id)))?;
+        file.read_exact(bytes)
+    }
+    fn load(&self, id: PageId) -> io::Result<()> {
+        // makes sure the page is in the cache, evicting the least recently used page if needed
+        let time = self.tick();
+        if let Some(cached) = self.cache.borrow_mut().get_mut(&id) {
+            cached.last_used = time;
+            return Ok(());
+        }
+        let mut bytes = vec![0; self.page_size];
+        self.read_bytes(id, &mut bytes)?;
+        self.cache(id, Self::decode(&bytes)?, false)
+    }
+    fn cache(&self, id: PageId, page: Page<K, V>, dirty: bool) -> io::Result<()> {
+        let mut cache = self.cache.borrow_mut();
+        if !cache.contains_key(&id) && cache.len() == self.cache_capacity {
+            // evicts the least recently used page, written back if modified
+            let lru = *cache
+                .iter()
+                .min_by_key(|(_, cached)| cached.last_used)
+                .map(|(id, _)| id)
+                .unwrap();
+            if cache[&lru].dirty {
+                self.write_bytes(lru, &self.encode(&cache[&lru].page))?;
+            }
+            cache.remove(&lru);
+        }
+        let last_used = self.tick();
+        cache.insert(
+            id,
+            CachedPage {
+                page,
+                dirty,
+                last_used,
+            },
+        );
+        Ok(())
+    }
+}
+impl<K: Codec, V: Codec> PageStore<K, V> for FilePageStore<K, V> {
+    fn allocate(&mut self, page: Page<K, V>) -> io::Result<PageId> {
+        let id = match self.free_head {
+            Some(id) => {
+                // the free page holds the id of the next free page
+                let mut bytes = [0; 8];
+                self.read_bytes(id, &mut bytes)?;
+                let next = u64::from_le_bytes(bytes);
+                self.free_head = (next != NO_PAGE).then_some(next as PageId);
+                self.nb_free -= 1;
+                id
+            }
+            None => {
+                self.nb_pages += 1;
+                self.nb_pages - 1
+            }
+        };
+        self.write(id, page)?;
+        Ok(id)
+    }
+    fn with_page<R, F: FnOnce(&Page<K, V>) -> R>(&self, id: PageId, f: F) -> io::Result<R> {
+        self.load(id)?;
+        Ok(f(&self.cache.borrow()[&id].page))
+    }
+    fn write(&mut self, id: PageId, page: Page<K, V>) -> io::Result<()> {
+        // checks that the page fits before it is only kept in the cache
+        self.encode(&page);
+        self.cache(id, page, true)
+    }
+    fn free(&mut self, id: PageId) -> io::Result<()> {
+        self.cache.get_mut().remove(&id);
+        let next = self.free_head.map_or(NO_PAGE, |id| id as u64);
+        self.write_bytes(id, &next.to_le_bytes())?;
+        self.free_head = Some(id);
+        self.nb_free += 1;
+        Ok(())
+    }
+    fn header(&self) -> Option<Header> {
+        self.header
+    }
+    fn set_header(&mut self, header: Header) {
+        self.header = Some(header);
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        for (id, cached) in self.cache.borrow_mut().iter_mut() {
+            if cached.dirty {
+                self.write_bytes(*id, &self.encode(&cached.page))?;
+                cached.dirty = false;
+            }
+        }
+        self.write_header()?;
+        self.file.flush()
+    }
+}
+impl<K: Codec, V: Codec> Drop for FilePageStore<K, V> {
+    fn drop(&mut self) {
+        // best effort: errors can only be handled by flushing before the store is dropped
+        let _ = self.flush();
+    }
+}
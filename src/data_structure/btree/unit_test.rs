@@ -0,0 +1,281 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    fn check<K, V, const M: usize, S>(tree: &BTree<K, V, M, S>)
+    where
+        K: Ord + Clone + std::fmt::Debug,
+        V: Clone,
+        S: PageStore<K, V>,
+    {
+        // checks the ordering, the number of keys per page and the depth of the leaves
+        fn walk<K: Ord + Clone, V: Clone, S: PageStore<K, V>>(
+            store: &S,
+            id: PageId,
+            depth: usize,
+            is_root: bool,
+            order: usize,
+            leaf_depths: &mut Vec<usize>,
+            keys: &mut Vec<K>,
+        ) {
+            let page = store.read(id).unwrap();
+            assert!(page.keys.len() < order);
+            if !is_root {
+                assert!(page.keys.len() >= order.div_ceil(2) - 1);
+            }
+            assert_eq!(page.keys.len(), page.values.len());
+            if page.is_leaf() {
+                leaf_depths.push(depth);
+                keys.extend(page.keys);
+                return;
+            }
+            assert_eq!(page.children.len(), page.keys.len() + 1);
+            for (i, child) in page.children.iter().enumerate() {
+                walk(store, *child, depth + 1, false, order, leaf_depths, keys);
+                if i < page.keys.len() {
+                    keys.push(page.keys[i].clone());
+                }
+            }
+        }
+        let (mut leaf_depths, mut keys) = (Vec::new(), Vec::new());
+        walk(
+            tree.store(),
+            tree.root,
+            1,
+            true,
+            M,
+            &mut leaf_depths,
+            &mut keys,
+        );
+        assert!(leaf_depths
+            .iter()
+            .all(|depth| *depth == tree.height().unwrap()));
+        assert!(keys.windows(2).all(|w| w[0] < w[1]), "{keys:?}");
+        assert_eq!(keys.len(), tree.len());
+    }
+
+    #[test]
+    fn test_btree_new() {
+        let tree = BTree::<usize, isize, 3>::new();
+        assert!(tree.is_empty());
+        assert_eq!(tree.height().unwrap(), 1);
+        assert_eq!(tree.min().unwrap(), None);
+        assert_eq!(tree.max().unwrap(), None);
+        assert_eq!(tree.get(&0).unwrap(), None);
+        assert!(tree.range_search(&0, &10).unwrap().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_btree_order_too_small() {
+        BTree::<usize, isize, 2>::new();
+    }
+
+    fn random_operations<const M: usize, S: PageStore<u16, u32>>(
+        tree: &mut BTree<u16, u32, M, S>,
+        seed: u64,
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut map = BTreeMap::new();
+        for step in 0..5000 {
+            let key = rng.gen_range(0..500);
+            if rng.gen_bool(0.55) {
+                tree.insert(key, step).unwrap();
+                map.insert(key, step);
+            } else {
+                assert_eq!(tree.delete(&key).unwrap(), map.remove(&key));
+            }
+            assert_eq!(tree.len(), map.len());
+            if step % 250 == 0 {
+                check(tree);
+                let (low, high) = (rng.gen_range(0..500), rng.gen_range(0..500));
+                assert_eq!(
+                    tree.range_search(&low, &high).unwrap(),
+                    map.range(low..high.max(low))
+                        .map(|(k, _)| *k)
+                        .collect::<Vec<_>>()
+                );
+            }
+        }
+        check(tree);
+        for (key, value) in map.iter() {
+            assert_eq!(tree.get(key).unwrap(), Some(*value));
+        }
+        assert_eq!(tree.min().unwrap(), map.keys().next().copied());
+        assert_eq!(tree.max().unwrap(), map.keys().next_back().copied());
+        for key in map.keys() {
+            assert!(tree.delete(key).unwrap().is_some());
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.height().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_btree_random_operations() {
+        random_operations(&mut BTree::<u16, u32, 3>::new(), 0);
+        random_operations(&mut BTree::<u16, u32, 4>::new(), 1);
+        random_operations(&mut BTree::<u16, u32, 5>::new(), 2);
+        random_operations(&mut BTree::<u16, u32, 64>::new(), 3);
+    }
+
+    #[test]
+    fn test_btree_frees_pages() {
+        let mut tree = BTree::<usize, usize, 4>::new();
+        for k in 0..1000 {
+            tree.insert(k, k).unwrap();
+        }
+        assert!(tree.store().nb_pages() > 300);
+        for k in 0..1000 {
+            tree.delete(&k).unwrap();
+        }
+        assert_eq!(tree.store().nb_pages(), 1);
+    }
+
+    #[test]
+    fn test_file_page_store() {
+        let path = std::env::temp_dir().join("algods_test_file_page_store.db");
+        let mut store = FilePageStore::<i64, String>::create(&path, 128, 2).unwrap();
+        let pages = (0..10)
+            .map(|k| Page {
+                keys: vec![k, -k],
+                values: vec![k.to_string(), "é".repeat(k as usize)],
+                children: vec![k as usize; 3],
+            })
+            .collect::<Vec<_>>();
+        let ids = pages
+            .iter()
+            .map(|page| store.allocate(page.clone()).unwrap())
+            .collect::<Vec<_>>();
+        for (id, page) in ids.iter().zip(pages.iter()) {
+            assert_eq!(&store.read(*id).unwrap(), page);
+        }
+        store.free(ids[3]).unwrap();
+        assert_eq!(store.nb_pages(), 9);
+        assert_eq!(store.allocate(Page::default()).unwrap(), ids[3]);
+        store.flush().unwrap();
+        assert_eq!(
+            std::fs::metadata(&path).unwrap().len(),
+            (FilePageStore::<i64, String>::HEADER_SIZE + 10 * store.page_size()) as u64
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_file_page_store_overflow() {
+        let path = std::env::temp_dir().join("algods_test_file_page_store_overflow.db");
+        let mut store = FilePageStore::<u64, u64>::create(&path, 16, 2).unwrap();
+        std::fs::remove_file(path).unwrap();
+        store
+            .allocate(Page {
+                keys: vec![1, 2],
+                values: vec![1, 2],
+                children: Vec::new(),
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn test_btree_file_store() {
+        let path = std::env::temp_dir().join("algods_test_btree_file_store.db");
+        // a page of order 5 holds at most 4 keys, 4 values and 5 children
+        let store = FilePageStore::<u16, u32>::create(&path, 8 + 4 * 6 + 5 * 8, 4).unwrap();
+        let mut tree = BTree::<u16, u32, 5, _>::with_store(store).unwrap();
+        random_operations(&mut tree, 4);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_file_page_store_reopen() {
+        let path = std::env::temp_dir().join("algods_test_file_page_store_reopen.db");
+        let mut store = FilePageStore::<u64, u64>::create(&path, 64, 2).unwrap();
+        let ids = (0..5)
+            .map(|k| {
+                let page = Page {
+                    keys: vec![k],
+                    values: vec![2 * k],
+                    children: Vec::new(),
+                };
+                store.allocate(page).unwrap()
+            })
+            .collect::<Vec<_>>();
+        store.free(ids[1]).unwrap();
+        store.free(ids[3]).unwrap();
+        // the store is flushed when dropped
+        drop(store);
+        let mut store = FilePageStore::<u64, u64>::open(&path, 2).unwrap();
+        assert_eq!(store.page_size(), 64);
+        assert_eq!(store.nb_pages(), 3);
+        assert_eq!(store.header(), None);
+        for k in [0, 2, 4] {
+            assert_eq!(store.read(ids[k]).unwrap().values, vec![2 * k as u64]);
+        }
+        // the free pages are reused, the last freed first
+        assert_eq!(store.allocate(Page::default()).unwrap(), ids[3]);
+        assert_eq!(store.allocate(Page::default()).unwrap(), ids[1]);
+        assert_eq!(store.allocate(Page::default()).unwrap(), ids[4] + 1);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_file_page_store_open_invalid() {
+        let path = std::env::temp_dir().join("algods_test_file_page_store_open_invalid.db");
+        std::fs::write(&path, [0; 64]).unwrap();
+        let error = FilePageStore::<u64, u64>::open(&path, 2).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        std::fs::write(&path, [0; 8]).unwrap();
+        let error = FilePageStore::<u64, u64>::open(&path, 2).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_codec_invalid_data() {
+        let mut buf = Vec::new();
+        "é".to_string().encode(&mut buf);
+        assert_eq!(String::decode(&buf).unwrap(), ("é".to_string(), 6));
+        // truncated string, then invalid utf-8
+        let error = String::decode(&buf[..5]).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        buf[5] = 0xff;
+        let error = String::decode(&buf).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        assert!(u64::decode(&[0; 7]).is_err());
+    }
+
+    #[test]
+    fn test_btree_open() {
+        assert!(BTree::<u64, u64, 3, _>::open(MemoryPageStore::new()).is_err());
+        let path = std::env::temp_dir().join("algods_test_btree_open.db");
+        let store = FilePageStore::<u16, u32>::create(&path, 8 + 4 * 6 + 5 * 8, 4).unwrap();
+        let mut tree = BTree::<u16, u32, 5, _>::with_store(store).unwrap();
+        for k in 0..500 {
+            tree.insert(k, k as u32).unwrap();
+        }
+        for k in (0..500).step_by(3) {
+            tree.delete(&k).unwrap();
+        }
+        let (len, height) = (tree.len(), tree.height().unwrap());
+        tree.flush().unwrap();
+        drop(tree);
+        let store = FilePageStore::<u16, u32>::open(&path, 4).unwrap();
+        let mut tree = BTree::<u16, u32, 5, _>::open(store).unwrap();
+        assert_eq!(tree.len(), len);
+        assert_eq!(tree.height().unwrap(), height);
+        check(&tree);
+        for k in 0..500 {
+            let expected = (k % 3 != 0).then_some(k as u32);
+            assert_eq!(tree.get(&k).unwrap(), expected);
+            if expected.is_some() {
+                tree.delete(&k).unwrap();
+            }
+        }
+        assert_eq!(tree.store().nb_pages(), 1);
+        // the pages freed since the store was reopened are reused
+        random_operations(&mut tree, 5);
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,763 @@
+#[cfg(test)]
+mod unit_test;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+// Function called with the (key, value) pairs evicted from a cache
+type EvictionCallback<K, V> = Box<dyn FnMut(K, V)>;
+
+/// Hit and miss statistics of a cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// number of lookups which found their key
+    pub hits: u64,
+    /// number of lookups which did not find their key
+    pub misses: u64,
+    /// number of pairs evicted to make room for new ones
+    pub evictions: u64,
+}
+impl CacheStats {
+    /// Gives the proportion of lookups which found their key (0 if there was no lookup).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CacheStats;
+    /// let stats = CacheStats { hits: 3, misses: 1, evictions: 0 };
+    /// assert_eq!(stats.hit_ratio(), 0.75);
+    /// ```
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    // number of accesses (only used by the LFU cache)
+    freq: u64,
+    // neighbours in the ordering list of the entry
+    prev: Option<usize>,
+    next: Option<usize>,
+}
+
+// Ends of a doubly linked list of entries
+#[derive(Debug, Default, Clone, Copy)]
+struct List {
+    head: Option<usize>,
+    tail: Option<usize>,
+}
+
+// Arena of entries linked into intrusive lists, whose free slots are reused
+#[derive(Debug)]
+struct Slab<K, V> {
+    entries: Vec<Option<Entry<K, V>>>,
+    free: Vec<usize>,
+}
+impl<K, V> Slab<K, V> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            entries: Vec::with_capacity(capacity),
+            free: Vec::new(),
+        }
+    }
+    fn entry(&self, index: usize) -> &Entry<K, V> {
+        self.entries[index].as_ref().unwrap()
+    }
+    fn entry_mut(&mut self, index: usize) -> &mut Entry<K, V> {
+        self.entries[index].as_mut().unwrap()
+    }
+    fn insert(&mut self, key: K, value: V) -> usize {
+        let entry = Some(Entry {
+            key,
+            value,
+            freq: 1,
+            prev: None,
+            next: None,
+        });
+        match self.free.pop() {
+            Some(index) => {
+                self.entries[index] = entry;
+                index
+            }
+            None => {
+                self.entries.push(entry);
+                self.entries.len() - 1
+            }
+        }
+    }
+    fn remove(&mut self, index: usize) -> Entry<K, V> {
+        self.free.push(index);
+        self.entries[index].take().unwrap()
+    }
+    fn push_front(&mut self, list: &mut List, index: usize) {
+        let entry = self.entry_mut(index);
+        entry.prev = None;
+        entry.next = list.head;
+        match list.head {
+            Some(head) => self.entry_mut(head).prev = Some(index),
+            None => list.tail = Some(index),
+        }
+        list.head = Some(index);
+    }
+    fn unlink(&mut self, list: &mut List, index: usize) {
+        let (prev, next) = {
+            let entry = self.entry(index);
+            (entry.prev, entry.next)
+        };
+        match prev {
+            Some(prev) => self.entry_mut(prev).next = next,
+            None => list.head = next,
+        }
+        match next {
+            Some(next) => self.entry_mut(next).prev = prev,
+            None => list.tail = prev,
+        }
+    }
+}
+
+// Entries of a LFU cache with the same access count, linked to the buckets
+// of the closest lower and higher access counts
+#[derive(Debug)]
+struct Bucket {
+    list: List,
+    lower: Option<u64>,
+    higher: Option<u64>,
+}
+
+/// Implementation of a least recently used (LRU) cache: once the cache is full, inserting
+/// a new key evicts the pair whose key was the least recently read or written.
+///
+/// A hash index maps each key to its entry in an arena, the entries being linked
+/// in a list ordered by recency, so that every operation runs in O(1).
+/// # Examples
+/// ```
+/// use algods::data_structure::LruCache;
+/// let mut cache = LruCache::new(2);
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// assert_eq!(cache.get(&"a"), Some(&1));
+/// // "b" is the least recently used key
+/// cache.put("c", 3);
+/// assert_eq!(cache.get(&"b"), None);
+/// assert_eq!(cache.stats().hits, 1);
+/// assert_eq!(cache.stats().misses, 1);
+/// assert_eq!(cache.stats().evictions, 1);
+/// ```
+pub struct LruCache<K, V> {
+    index: HashMap<K, usize>,
+    slab: Slab<K, V>,
+    // from the most recently used entry to the least recently used one
+    order: List,
+    capacity: usize,
+    stats: CacheStats,
+    on_evict: Option<EvictionCallback<K, V>>,
+}
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for LruCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LruCache")
+            .field("slab", &self.slab)
+            .field("order", &self.order)
+            .field("capacity", &self.capacity)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// Creates an empty cache holding at most `capacity` pairs.
+    /// # Panics
+    /// If `capacity == 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let cache = LruCache::<usize, usize>::new(10);
+    /// assert_eq!(cache.capacity(), 10);
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        if capacity == 0 {
+            panic!("capacity should be > 0");
+        }
+        Self {
+            index: HashMap::with_capacity(capacity),
+            slab: Slab::with_capacity(capacity),
+            order: List::default(),
+            capacity,
+            stats: CacheStats::default(),
+            on_evict: None,
+        }
+    }
+    /// Sets a function called with every (key, value) pair evicted from the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// let evicted = Rc::new(RefCell::new(Vec::new()));
+    /// let mut cache = LruCache::new(1);
+    /// let sink = Rc::clone(&evicted);
+    /// cache.set_eviction_callback(move |key, value| sink.borrow_mut().push((key, value)));
+    /// cache.put(1, "1");
+    /// cache.put(2, "2");
+    /// assert_eq!(*evicted.borrow(), vec![(1, "1")]);
+    /// ```
+    pub fn set_eviction_callback<F: FnMut(K, V) + 'static>(&mut self, callback: F) {
+        self.on_evict = Some(Box::new(callback));
+    }
+    /// Gives the maximum number of pairs in the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let cache = LruCache::<usize, usize>::new(10);
+    /// assert_eq!(cache.capacity(), 10);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Gives the number of pairs in the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, 1);
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+    /// Tests whether or not the cache is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, 1);
+    /// assert!(!cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+    /// Returns the hit and miss statistics of the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, 1);
+    /// cache.get(&1);
+    /// cache.get(&2);
+    /// assert_eq!(cache.stats().hit_ratio(), 0.5);
+    /// ```
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+    /// Resets the hit and miss statistics of the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{CacheStats, LruCache};
+    /// let mut cache = LruCache::<usize, usize>::new(10);
+    /// cache.get(&1);
+    /// cache.reset_stats();
+    /// assert_eq!(cache.stats(), CacheStats::default());
+    /// ```
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+    /// Tests whether or not the cache contains a key, without updating its recency.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, 1);
+    /// assert!(cache.contains(&1));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+    /// Returns the value associated with a key, if any, without updating
+    /// its recency nor the statistics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, "1");
+    /// assert_eq!(cache.peek(&1), Some(&"1"));
+    /// assert_eq!(cache.stats().hits, 0);
+    /// ```
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.index
+            .get(key)
+            .map(|index| &self.slab.entry(*index).value)
+    }
+    fn touch(&mut self, key: &K) -> Option<usize> {
+        // moves the entry of the key, if any, to the front of the list
+        match self.index.get(key) {
+            Some(&index) => {
+                self.stats.hits += 1;
+                self.slab.unlink(&mut self.order, index);
+                self.slab.push_front(&mut self.order, index);
+                Some(index)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+    /// Returns the value associated with a key, if any, which becomes the most recently used.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, "1");
+    /// assert_eq!(cache.get(&1), Some(&"1"));
+    /// assert_eq!(cache.get(&2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let index = self.touch(key)?;
+        Some(&self.slab.entry(index).value)
+    }
+    /// Returns a mutable reference to the value associated with a key, if any,
+    /// which becomes the most recently used.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, 1);
+    /// *cache.get_mut(&1).unwrap() += 1;
+    /// assert_eq!(cache.peek(&1), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.touch(key)?;
+        Some(&mut self.slab.entry_mut(index).value)
+    }
+    /// Inserts a (key, value) pair in the cache, which becomes the most recently used,
+    /// and returns the previous value of the key, if any. If the cache is full and
+    /// the key is new, the least recently used pair is evicted first.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(1);
+    /// assert_eq!(cache.put(1, "1"), None);
+    /// assert_eq!(cache.put(1, "one"), Some("1"));
+    /// cache.put(2, "2");
+    /// assert!(!cache.contains(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&index) = self.index.get(&key) {
+            self.slab.unlink(&mut self.order, index);
+            self.slab.push_front(&mut self.order, index);
+            return Some(std::mem::replace(
+                &mut self.slab.entry_mut(index).value,
+                value,
+            ));
+        }
+        if self.len() == self.capacity {
+            let lru = self.order.tail.unwrap();
+            let entry = self.remove_entry(lru);
+            self.stats.evictions += 1;
+            if let Some(callback) = self.on_evict.as_mut() {
+                callback(entry.key, entry.value);
+            }
+        }
+        let index = self.slab.insert(key.clone(), value);
+        self.slab.push_front(&mut self.order, index);
+        self.index.insert(key, index);
+        None
+    }
+    fn remove_entry(&mut self, index: usize) -> Entry<K, V> {
+        self.slab.unlink(&mut self.order, index);
+        let entry = self.slab.remove(index);
+        self.index.remove(&entry.key);
+        entry
+    }
+    /// Removes a key from the cache and returns its value, if any.
+    /// The eviction callback is not called.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, "1");
+    /// assert_eq!(cache.remove(&1), Some("1"));
+    /// assert!(cache.is_empty());
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = *self.index.get(key)?;
+        Some(self.remove_entry(index).value)
+    }
+    /// Returns the keys of the cache, from the most recently used to the least recently used.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LruCache;
+    /// let mut cache = LruCache::new(10);
+    /// cache.put(1, "1");
+    /// cache.put(2, "2");
+    /// cache.get(&1);
+    /// assert_eq!(cache.keys(), vec![&1, &2]);
+    /// ```
+    pub fn keys(&self) -> Vec<&K> {
+        let mut keys = Vec::with_capacity(self.len());
+        let mut current = self.order.head;
+        while let Some(index) = current {
+            let entry = self.slab.entry(index);
+            keys.push(&entry.key);
+            current = entry.next;
+        }
+        keys
+    }
+}
+
+/// Implementation of a least frequently used (LFU) cache: once the cache is full, inserting
+/// a new key evicts the pair whose key was the least often read or written since it entered
+/// the cache, the least recently used one among them in case of ties.
+///
+/// A hash index maps each key to its entry in an arena, the entries being linked in one
+/// list per access count ordered by recency, and the lists in increasing order of access
+/// count, so that every operation runs in O(1).
+/// # Examples
+/// ```
+/// use algods::data_structure::LfuCache;
+/// let mut cache = LfuCache::new(2);
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// cache.get(&"a");
+/// cache.get(&"a");
+/// cache.get(&"b");
+/// // "b" was accessed less often than "a"
+/// cache.put("c", 3);
+/// assert!(!cache.contains(&"b"));
+/// assert_eq!(cache.frequency(&"a"), Some(3));
+/// assert_eq!(cache.stats().evictions, 1);
+/// ```
+pub struct LfuCache<K, V> {
+    index: HashMap<K, usize>,
+    slab: Slab<K, V>,
+    // entries by access count, from the most recently used to the least recently used
+    buckets: HashMap<u64, Bucket>,
+    // smallest access count in the cache
+    lowest: Option<u64>,
+    capacity: usize,
+    stats: CacheStats,
+    on_evict: Option<EvictionCallback<K, V>>,
+}
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for LfuCache<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LfuCache")
+            .field("slab", &self.slab)
+            .field("buckets", &self.buckets)
+            .field("capacity", &self.capacity)
+            .field("stats", &self.stats)
+            .finish()
+    }
+}
+impl<K: Hash + Eq + Clone, V> LfuCache<K, V> {
+    /// Creates an empty cache holding at most `capacity` pairs.
+    /// # Panics
+    /// If `capacity == 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let cache = LfuCache::<usize, usize>::new(10);
+    /// assert_eq!(cache.capacity(), 10);
+    /// assert!(cache.is_empty());
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        if capacity == 0 {
+            panic!("capacity should be > 0");
+        }
+        Self {
+            index: HashMap::with_capacity(capacity),
+            slab: Slab::with_capacity(capacity),
+            buckets: HashMap::new(),
+            lowest: None,
+            capacity,
+            stats: CacheStats::default(),
+            on_evict: None,
+        }
+    }
+    /// Sets a function called with every (key, value) pair evicted from the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// use std::cell::RefCell;
+    /// use std::rc::Rc;
+    /// let evicted = Rc::new(RefCell::new(Vec::new()));
+    /// let mut cache = LfuCache::new(1);
+    /// let sink = Rc::clone(&evicted);
+    /// cache.set_eviction_callback(move |key, value| sink.borrow_mut().push((key, value)));
+    /// cache.put(1, "1");
+    /// cache.put(2, "2");
+    /// assert_eq!(*evicted.borrow(), vec![(1, "1")]);
+    /// ```
+    pub fn set_eviction_callback<F: FnMut(K, V) + 'static>(&mut self, callback: F) {
+        self.on_evict = Some(Box::new(callback));
+    }
+    /// Gives the maximum number of pairs in the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let cache = LfuCache::<usize, usize>::new(10);
+    /// assert_eq!(cache.capacity(), 10);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Gives the number of pairs in the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, 1);
+    /// assert_eq!(cache.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+    /// Tests whether or not the cache is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, 1);
+    /// assert!(!cache.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+    /// Returns the hit and miss statistics of the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, 1);
+    /// cache.get(&1);
+    /// cache.get(&2);
+    /// assert_eq!(cache.stats().hit_ratio(), 0.5);
+    /// ```
+    pub fn stats(&self) -> CacheStats {
+        self.stats
+    }
+    /// Resets the hit and miss statistics of the cache.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{CacheStats, LfuCache};
+    /// let mut cache = LfuCache::<usize, usize>::new(10);
+    /// cache.get(&1);
+    /// cache.reset_stats();
+    /// assert_eq!(cache.stats(), CacheStats::default());
+    /// ```
+    pub fn reset_stats(&mut self) {
+        self.stats = CacheStats::default();
+    }
+    /// Tests whether or not the cache contains a key, without updating its access count.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, 1);
+    /// assert!(cache.contains(&1));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.index.contains_key(key)
+    }
+    /// Returns the number of times a key was read or written since it entered the cache, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, 1);
+    /// cache.get(&1);
+    /// assert_eq!(cache.frequency(&1), Some(2));
+    /// assert_eq!(cache.frequency(&2), None);
+    /// ```
+    pub fn frequency(&self, key: &K) -> Option<u64> {
+        self.index
+            .get(key)
+            .map(|index| self.slab.entry(*index).freq)
+    }
+    /// Returns the value associated with a key, if any, without updating
+    /// its access count nor the statistics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, "1");
+    /// assert_eq!(cache.peek(&1), Some(&"1"));
+    /// assert_eq!(cache.frequency(&1), Some(1));
+    /// ```
+    pub fn peek(&self, key: &K) -> Option<&V> {
+        self.index
+            .get(key)
+            .map(|index| &self.slab.entry(*index).value)
+    }
+    fn insert_bucket(&mut self, freq: u64, lower: Option<u64>) {
+        // adds an empty bucket right after the bucket of access count lower
+        // (at the start of the list of buckets if None)
+        let higher = match lower {
+            Some(lower) => self.buckets[&lower].higher,
+            None => self.lowest,
+        };
+        self.buckets.insert(
+            freq,
+            Bucket {
+                list: List::default(),
+                lower,
+                higher,
+            },
+        );
+        match lower {
+            Some(lower) => self.buckets.get_mut(&lower).unwrap().higher = Some(freq),
+            None => self.lowest = Some(freq),
+        }
+        if let Some(higher) = higher {
+            self.buckets.get_mut(&higher).unwrap().lower = Some(freq);
+        }
+    }
+    fn unlink(&mut self, index: usize) {
+        // removes the entry from the bucket of its access count,
+        // and the bucket from the list of buckets if it becomes empty
+        let freq = self.slab.entry(index).freq;
+        let bucket = self.buckets.get_mut(&freq).unwrap();
+        self.slab.unlink(&mut bucket.list, index);
+        if bucket.list.head.is_none() {
+            let Bucket { lower, higher, .. } = self.buckets.remove(&freq).unwrap();
+            match lower {
+                Some(lower) => self.buckets.get_mut(&lower).unwrap().higher = higher,
+                None => self.lowest = higher,
+            }
+            if let Some(higher) = higher {
+                self.buckets.get_mut(&higher).unwrap().lower = lower;
+            }
+        }
+    }
+    fn link(&mut self, index: usize) {
+        // adds the entry at the front of the bucket of its access count
+        let freq = self.slab.entry(index).freq;
+        let bucket = self.buckets.get_mut(&freq).unwrap();
+        self.slab.push_front(&mut bucket.list, index);
+    }
+    fn increment(&mut self, index: usize) {
+        let freq = self.slab.entry(index).freq;
+        if !self.buckets.contains_key(&(freq + 1)) {
+            self.insert_bucket(freq + 1, Some(freq));
+        }
+        self.unlink(index);
+        self.slab.entry_mut(index).freq += 1;
+        self.link(index);
+    }
+    fn touch(&mut self, key: &K) -> Option<usize> {
+        match self.index.get(key) {
+            Some(&index) => {
+                self.stats.hits += 1;
+                self.increment(index);
+                Some(index)
+            }
+            None => {
+                self.stats.misses += 1;
+                None
+            }
+        }
+    }
+    /// Returns the value associated with a key, if any, whose access count is incremented.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, "1");
+    /// assert_eq!(cache.get(&1), Some(&"1"));
+    /// assert_eq!(cache.get(&2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let index = self.touch(key)?;
+        Some(&self.slab.entry(index).value)
+    }
+    /// Returns a mutable reference to the value associated with a key, if any,
+    /// whose access count is incremented.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, 1);
+    /// *cache.get_mut(&1).unwrap() += 1;
+    /// assert_eq!(cache.peek(&1), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let index = self.touch(key)?;
+        Some(&mut self.slab.entry_mut(index).value)
+    }
+    /// Inserts a (key, value) pair in the cache and returns the previous value of the key,
+    /// if any, in which case its access count is incremented. If the cache is full and
+    /// the key is new, the least frequently used pair is evicted first.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(1);
+    /// assert_eq!(cache.put(1, "1"), None);
+    /// assert_eq!(cache.put(1, "one"), Some("1"));
+    /// cache.put(2, "2");
+    /// assert!(!cache.contains(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&index) = self.index.get(&key) {
+            self.increment(index);
+            return Some(std::mem::replace(
+                &mut self.slab.entry_mut(index).value,
+                value,
+            ));
+        }
+        if self.len() == self.capacity {
+            let lfu = self.buckets[&self.lowest.unwrap()].list.tail.unwrap();
+            let entry = self.remove_entry(lfu);
+            self.stats.evictions += 1;
+            if let Some(callback) = self.on_evict.as_mut() {
+                callback(entry.key, entry.value);
+            }
+        }
+        let index = self.slab.insert(key.clone(), value);
+        if !self.buckets.contains_key(&1) {
+            self.insert_bucket(1, None);
+        }
+        self.link(index);
+        self.index.insert(key, index);
+        None
+    }
+    fn remove_entry(&mut self, index: usize) -> Entry<K, V> {
+        self.unlink(index);
+        let entry = self.slab.remove(index);
+        self.index.remove(&entry.key);
+        entry
+    }
+    /// Removes a key from the cache and returns its value, if any.
+    /// The eviction callback is not called.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::LfuCache;
+    /// let mut cache = LfuCache::new(10);
+    /// cache.put(1, "1");
+    /// assert_eq!(cache.remove(&1), Some("1"));
+    /// assert!(cache.is_empty());
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = *self.index.get(key)?;
+        Some(self.remove_entry(index).value)
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    // brute force cache: (key, value, access count, time of last access)
+    struct Model {
+        entries: Vec<(usize, usize, u64, usize)>,
+        capacity: usize,
+        lfu: bool,
+    }
+    impl Model {
+        fn get(&mut self, key: usize, time: usize) -> Option<usize> {
+            let entry = self.entries.iter_mut().find(|e| e.0 == key)?;
+            entry.2 += 1;
+            entry.3 = time;
+            Some(entry.1)
+        }
+        fn put(&mut self, key: usize, value: usize, time: usize) -> Option<(usize, usize)> {
+            if let Some(entry) = self.entries.iter_mut().find(|e| e.0 == key) {
+                entry.1 = value;
+                entry.2 += 1;
+                entry.3 = time;
+                return None;
+            }
+            let mut evicted = None;
+            if self.entries.len() == self.capacity {
+                let lfu = self.lfu;
+                let position = (0..self.entries.len())
+                    .min_by_key(|i| {
+                        let e = self.entries[*i];
+                        (if lfu { e.2 } else { 0 }, e.3)
+                    })
+                    .unwrap();
+                let e = self.entries.swap_remove(position);
+                evicted = Some((e.0, e.1));
+            }
+            self.entries.push((key, value, 1, time));
+            evicted
+        }
+        fn remove(&mut self, key: usize) -> Option<usize> {
+            let position = self.entries.iter().position(|e| e.0 == key)?;
+            Some(self.entries.swap_remove(position).1)
+        }
+    }
+
+    macro_rules! random_operations {
+        ($cache:ident, $lfu:expr, $seed:expr) => {
+            let mut rng = StdRng::seed_from_u64($seed);
+            let capacity = 8;
+            let mut cache = $cache::new(capacity);
+            let evicted = Rc::new(RefCell::new(Vec::new()));
+            let sink = Rc::clone(&evicted);
+            cache.set_eviction_callback(move |k, v| sink.borrow_mut().push((k, v)));
+            let mut model = Model {
+                entries: Vec::new(),
+                capacity,
+                lfu: $lfu,
+            };
+            let mut stats = CacheStats::default();
+            for time in 0..20000 {
+                let key = rng.gen_range(0..20);
+                match rng.gen_range(0..10) {
+                    0..=4 => {
+                        let expected = model.get(key, time);
+                        match expected {
+                            Some(_) => stats.hits += 1,
+                            None => stats.misses += 1,
+                        }
+                        assert_eq!(cache.get(&key).copied(), expected);
+                    }
+                    5..=8 => {
+                        cache.put(key, time);
+                        if let Some(pair) = model.put(key, time, time) {
+                            stats.evictions += 1;
+                            assert_eq!(evicted.borrow_mut().pop(), Some(pair));
+                        }
+                        assert!(evicted.borrow().is_empty());
+                    }
+                    _ => assert_eq!(cache.remove(&key), model.remove(key)),
+                }
+                assert_eq!(cache.len(), model.entries.len());
+                assert_eq!(cache.stats(), stats);
+            }
+        };
+    }
+
+    #[test]
+    fn test_lru_cache_random_operations() {
+        random_operations!(LruCache, false, 0);
+    }
+
+    #[test]
+    fn test_lfu_cache_random_operations() {
+        random_operations!(LfuCache, true, 1);
+    }
+
+    #[test]
+    fn test_lru_cache_order() {
+        let mut cache = LruCache::new(3);
+        for k in 0..3 {
+            cache.put(k, k);
+        }
+        assert_eq!(cache.keys(), vec![&2, &1, &0]);
+        cache.get(&0);
+        cache.put(1, 10);
+        assert_eq!(cache.keys(), vec![&1, &0, &2]);
+        assert_eq!(cache.peek(&2), Some(&2));
+        assert_eq!(cache.keys(), vec![&1, &0, &2]);
+        cache.put(3, 3);
+        assert_eq!(cache.keys(), vec![&3, &1, &0]);
+        assert_eq!(cache.stats().hits, 1);
+        assert_eq!(cache.stats().evictions, 1);
+    }
+
+    #[test]
+    fn test_lfu_cache_remove_lowest() {
+        let mut cache = LfuCache::new(2);
+        cache.put(1, "1");
+        cache.put(2, "2");
+        cache.get(&2);
+        cache.get(&2);
+        // removing the only key with the smallest count leaves a gap in the counts
+        assert_eq!(cache.remove(&1), Some("1"));
+        cache.put(3, "3");
+        cache.get(&3);
+        cache.put(4, "4");
+        assert!(cache.contains(&2));
+        assert!(!cache.contains(&3));
+        assert_eq!(cache.frequency(&2), Some(3));
+        assert_eq!(cache.frequency(&4), Some(1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cache_zero_capacity() {
+        LruCache::<usize, usize>::new(0);
+    }
+}
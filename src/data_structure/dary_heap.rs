@@ -0,0 +1,489 @@
+#[cfg(test)]
+mod unit_test;
+use crate::data_structure::Orientation;
+
+/// Implementation of a d-ary heap: a priority queue whose nodes have `D` children.
+/// A wider fan-out makes the tree shallower, so that insertions are faster and the
+/// children of a node are contiguous in memory, at the cost of more comparisons per deletion.
+/// See `IndexDaryHeap` to change the priority of objects already in the heap.
+/// # Examples
+/// ```
+/// use algods::data_structure::{DaryHeap, Orientation};
+/// let mut heap = DaryHeap::<_, 4>::new(Orientation::Min);
+/// heap.insert(3);
+/// heap.insert(1);
+/// heap.insert(2);
+/// assert_eq!(heap.extremum(), Some(&1));
+/// assert_eq!(heap.delete(), Some(1));
+/// assert_eq!(heap.delete(), Some(2));
+/// assert_eq!(heap.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DaryHeap<T, const D: usize> {
+    // vector of objects
+    vec: Vec<T>,
+    // type of priority queue
+    kind: Orientation,
+    // Remarks:
+    // - objects are stored from index = 0 so that:
+    //     - each node k's parent is at position (k-1)/D
+    //     - each node k's children are at positions Dk+1 to Dk+D
+    // - in the max oriented heap parents are larger than their children
+    //   (smaller for min oriented heap)
+}
+impl<T: Ord, const D: usize> Default for DaryHeap<T, D> {
+    fn default() -> Self {
+        Self::new(Orientation::default())
+    }
+}
+impl<T: Ord, const D: usize> DaryHeap<T, D> {
+    /// Creates an empty heap.
+    /// # Panics
+    /// If `D < 2`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let heap = DaryHeap::<usize, 3>::new(Orientation::Max);
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new(k: Orientation) -> Self {
+        Self::with_capacity(0, k)
+    }
+    /// Creates an empty heap with an initial capacity.
+    /// # Panics
+    /// If `D < 2`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let heap = DaryHeap::<usize, 8>::with_capacity(10, Orientation::Max);
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize, k: Orientation) -> Self {
+        if D < 2 {
+            panic!("the number of children D should be >= 2, got {D}");
+        }
+        Self {
+            vec: Vec::with_capacity(capacity),
+            kind: k,
+        }
+    }
+    /// Creates a heap from a `Vec` of objects.
+    /// # Panics
+    /// If `D < 2`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let heap = DaryHeap::<_, 4>::from_vec(vec![3, 1, 4, 1, 5], Orientation::Max);
+    /// assert_eq!(heap.extremum(), Some(&5));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_vec(vec: Vec<T>, k: Orientation) -> Self {
+        let mut heap = Self::new(k);
+        heap.vec = vec;
+        // sinks every parent node, from the bottom of the tree
+        if heap.vec.len() > 1 {
+            for node in (0..=(heap.vec.len() - 2) / D).rev() {
+                heap.sink(node);
+            }
+        }
+        heap
+    }
+    /// Tests whether or not the heap is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let mut heap = DaryHeap::<_, 2>::new(Orientation::Max);
+    /// heap.insert(1);
+    /// assert!(!heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+    /// Gives the number of objects in the heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let heap = DaryHeap::<_, 3>::from_vec(vec![1, 2], Orientation::Max);
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+    /// Returns the extremal (smallest in min oriented heap
+    /// and largest in max oriented heap) object in the heap, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let heap = DaryHeap::<_, 3>::from_vec(vec![1, 2], Orientation::Min);
+    /// assert_eq!(heap.extremum(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn extremum(&self) -> Option<&T> {
+        self.vec.first()
+    }
+    fn less(&self, i: usize, j: usize) -> bool {
+        // tests whether or not the object at position i has a lower priority
+        // than the object at position j
+        match self.kind {
+            Orientation::Max => self.vec[i] < self.vec[j],
+            Orientation::Min => self.vec[i] > self.vec[j],
+        }
+    }
+    fn swim(&mut self, mut k: usize) {
+        while k > 0 && self.less((k - 1) / D, k) {
+            self.vec.swap(k, (k - 1) / D);
+            k = (k - 1) / D;
+        }
+    }
+    fn sink(&mut self, mut k: usize) {
+        loop {
+            let first = D * k + 1;
+            if first >= self.vec.len() {
+                break;
+            }
+            // find the child of node k with the highest priority
+            let last = (first + D).min(self.vec.len());
+            let mut j = first;
+            for child in first + 1..last {
+                if self.less(j, child) {
+                    j = child;
+                }
+            }
+            if !self.less(k, j) {
+                break;
+            }
+            self.vec.swap(k, j);
+            k = j;
+        }
+    }
+    /// Inserts an object into the heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let mut heap = DaryHeap::<_, 4>::new(Orientation::Max);
+    /// heap.insert(1);
+    /// heap.insert(2);
+    /// assert_eq!(heap.extremum(), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N) / log(D))
+    pub fn insert(&mut self, key: T) {
+        self.vec.push(key);
+        self.swim(self.vec.len() - 1);
+    }
+    /// Deletes and returns the extremal (smallest in min oriented heap
+    /// and largest in max oriented heap) object in the heap, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let mut heap = DaryHeap::<_, 4>::from_vec(vec![2, 1], Orientation::Max);
+    /// assert_eq!(heap.delete(), Some(2));
+    /// assert_eq!(heap.delete(), Some(1));
+    /// assert_eq!(heap.delete(), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D log(N) / log(D))
+    pub fn delete(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let res = self.vec.swap_remove(0);
+        self.sink(0);
+        Some(res)
+    }
+    /// Returns the objects of the heap in the order they would be deleted.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{DaryHeap, Orientation};
+    /// let heap = DaryHeap::<_, 3>::from_vec(vec![2, 3, 1], Orientation::Min);
+    /// assert_eq!(heap.into_sorted_vec(), vec![1, 2, 3]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D N log(N) / log(D))
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len());
+        while let Some(key) = self.delete() {
+            vec.push(key);
+        }
+        vec
+    }
+}
+
+/// Implementation of an indexed d-ary heap: each object is associated to an index
+/// in `0..capacity`, by which its priority can later be changed or the object removed,
+/// e.g. the distance to a vertex in the Dijkstra algorithm.
+/// The wide fan-out makes priority increases (which only swim up) cheaper.
+/// # Example
+/// ```
+/// use algods::data_structure::{IndexDaryHeap, Orientation};
+/// let mut heap = IndexDaryHeap::<usize, 4>::with_capacity(3, Orientation::Min);
+/// heap.insert(0, 10);
+/// heap.insert(1, 5);
+/// heap.insert(2, 7);
+/// heap.decrease_key(0, 1);
+/// assert_eq!(heap.extremum(), Some((0, &1)));
+/// assert_eq!(heap.delete(), Some((0, 1)));
+/// assert_eq!(heap.delete(), Some((1, 5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexDaryHeap<T, const D: usize> {
+    // indices of the objects, in heap order
+    heap: Vec<usize>,
+    // position in the heap of each index, if the index is in the heap
+    positions: Vec<Option<usize>>,
+    // object associated to each index
+    keys: Vec<Option<T>>,
+    // type of priority queue
+    kind: Orientation,
+    // Remarks:
+    // - self.heap is laid out as the objects of DaryHeap
+}
+impl<T: Ord, const D: usize> IndexDaryHeap<T, D> {
+    /// Creates an empty heap whose objects are associated to indices in `0..capacity`.
+    /// # Panics
+    /// If `D < 2`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{IndexDaryHeap, Orientation};
+    /// let heap = IndexDaryHeap::<usize, 4>::with_capacity(10, Orientation::Min);
+    /// assert_eq!(heap.capacity(), 10);
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize, k: Orientation) -> Self {
+        if D < 2 {
+            panic!("the number of children D should be >= 2, got {D}");
+        }
+        let mut keys = Vec::with_capacity(capacity);
+        keys.resize_with(capacity, || None);
+        Self {
+            heap: Vec::with_capacity(capacity),
+            positions: vec![None; capacity],
+            keys,
+            kind: k,
+        }
+    }
+    /// Gives the number of indices objects can be associated to.
+    pub fn capacity(&self) -> usize {
+        self.keys.len()
+    }
+    /// Tests whether or not the heap is empty.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    /// Gives the number of objects in the heap.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    /// Tests whether or not an index has an object in the heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{IndexDaryHeap, Orientation};
+    /// let mut heap = IndexDaryHeap::<usize, 2>::with_capacity(2, Orientation::Min);
+    /// heap.insert(1, 3);
+    /// assert!(heap.contains(1));
+    /// assert!(!heap.contains(0));
+    /// ```
+    pub fn contains(&self, index: usize) -> bool {
+        self.positions.get(index).is_some_and(Option::is_some)
+    }
+    /// Returns the object associated to an index, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{IndexDaryHeap, Orientation};
+    /// let mut heap = IndexDaryHeap::<usize, 2>::with_capacity(2, Orientation::Min);
+    /// heap.insert(1, 3);
+    /// assert_eq!(heap.get(1), Some(&3));
+    /// assert_eq!(heap.get(0), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.keys.get(index).and_then(Option::as_ref)
+    }
+    /// Returns the extremal (smallest in min oriented heap and largest in max oriented heap)
+    /// object in the heap with its index, if any. Returns `None` otherwise.
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn extremum(&self) -> Option<(usize, &T)> {
+        self.heap.first().map(|index| (*index, self.key(*index)))
+    }
+    fn key(&self, index: usize) -> &T {
+        self.keys[index]
+            .as_ref()
+            .expect("indices in the heap have an object")
+    }
+    fn less(&self, i: usize, j: usize) -> bool {
+        // tests whether or not the object at position i has a lower priority
+        // than the object at position j
+        let (a, b) = (self.key(self.heap[i]), self.key(self.heap[j]));
+        match self.kind {
+            Orientation::Max => a < b,
+            Orientation::Min => a > b,
+        }
+    }
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.positions[self.heap[i]] = Some(i);
+        self.positions[self.heap[j]] = Some(j);
+    }
+    fn swim(&mut self, mut k: usize) {
+        while k > 0 && self.less((k - 1) / D, k) {
+            self.swap(k, (k - 1) / D);
+            k = (k - 1) / D;
+        }
+    }
+    fn sink(&mut self, mut k: usize) {
+        loop {
+            let first = D * k + 1;
+            if first >= self.heap.len() {
+                break;
+            }
+            // find the child of node k with the highest priority
+            let last = (first + D).min(self.heap.len());
+            let mut j = first;
+            for child in first + 1..last {
+                if self.less(j, child) {
+                    j = child;
+                }
+            }
+            if !self.less(k, j) {
+                break;
+            }
+            self.swap(k, j);
+            k = j;
+        }
+    }
+    fn position(&self, index: usize) -> usize {
+        match self.positions.get(index) {
+            Some(Some(position)) => *position,
+            Some(None) => panic!("index {index} has no object in the heap"),
+            None => panic!(
+                "index {index} is out of bounds, the capacity is {}",
+                self.capacity()
+            ),
+        }
+    }
+    /// Inserts an object associated to an index.
+    /// # Panics
+    /// If the index is out of bounds or already has an object, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{IndexDaryHeap, Orientation};
+    /// let mut heap = IndexDaryHeap::<_, 4>::with_capacity(2, Orientation::Max);
+    /// heap.insert(0, "a");
+    /// heap.insert(1, "b");
+    /// assert_eq!(heap.extremum(), Some((1, &"b")));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N) / log(D))
+    pub fn insert(&mut self, index: usize, key: T) {
+        if index >= self.capacity() {
+            panic!(
+                "index {index} is out of bounds, the capacity is {}",
+                self.capacity()
+            );
+        }
+        if self.contains(index) {
+            panic!("index {index} already has an object in the heap");
+        }
+        self.keys[index] = Some(key);
+        self.positions[index] = Some(self.heap.len());
+        self.heap.push(index);
+        self.swim(self.heap.len() - 1);
+    }
+    /// Replaces the object associated to an index, moving it up or down the heap.
+    /// # Panics
+    /// If the index has no object in the heap, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{IndexDaryHeap, Orientation};
+    /// let mut heap = IndexDaryHeap::<_, 4>::with_capacity(2, Orientation::Min);
+    /// heap.insert(0, 1);
+    /// heap.insert(1, 2);
+    /// heap.change_priority(0, 3);
+    /// assert_eq!(heap.extremum(), Some((1, &2)));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D log(N) / log(D))
+    pub fn change_priority(&mut self, index: usize, key: T) {
+        let position = self.position(index);
+        self.keys[index] = Some(key);
+        self.swim(position);
+        self.sink(self.position(index));
+    }
+    /// Decreases the object associated to an index. It moves up a min oriented heap,
+    /// which only takes O(log(N) / log(D)) comparisons.
+    /// # Panics
+    /// If the index has no object in the heap or the new object is larger, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{IndexDaryHeap, Orientation};
+    /// let mut heap = IndexDaryHeap::<_, 4>::with_capacity(2, Orientation::Min);
+    /// heap.insert(0, 5);
+    /// heap.insert(1, 2);
+    /// heap.decrease_key(0, 1);
+    /// assert_eq!(heap.extremum(), Some((0, &1)));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N) / log(D)) for a min oriented heap,
+    /// O(D log(N) / log(D)) for a max oriented heap
+    pub fn decrease_key(&mut self, index: usize, key: T) {
+        let position = self.position(index);
+        if &key > self.key(index) {
+            panic!("cannot decrease the object of index {index} to a larger one");
+        }
+        self.keys[index] = Some(key);
+        match self.kind {
+            Orientation::Min => self.swim(position),
+            Orientation::Max => self.sink(position),
+        }
+    }
+    /// Removes and returns the object associated to an index, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{IndexDaryHeap, Orientation};
+    /// let mut heap = IndexDaryHeap::<_, 4>::with_capacity(3, Orientation::Min);
+    /// heap.insert(0, 1);
+    /// heap.insert(2, 3);
+    /// assert_eq!(heap.remove(0), Some(1));
+    /// assert_eq!(heap.remove(0), None);
+    /// assert_eq!(heap.extremum(), Some((2, &3)));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D log(N) / log(D))
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let position = (*self.positions.get(index)?)?;
+        let last = self.heap.len() - 1;
+        self.swap(position, last);
+        self.heap.pop();
+        self.positions[index] = None;
+        if position < self.heap.len() {
+            // the last object took the place of the removed one
+            let moved = self.heap[position];
+            self.swim(position);
+            self.sink(self.position(moved));
+        }
+        self.keys[index].take()
+    }
+    /// Deletes and returns the extremal (smallest in min oriented heap and largest
+    /// in max oriented heap) object in the heap with its index, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{IndexDaryHeap, Orientation};
+    /// let mut heap = IndexDaryHeap::<_, 3>::with_capacity(2, Orientation::Max);
+    /// heap.insert(1, 4);
+    /// heap.insert(0, 2);
+    /// assert_eq!(heap.delete(), Some((1, 4)));
+    /// assert_eq!(heap.delete(), Some((0, 2)));
+    /// assert_eq!(heap.delete(), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D log(N) / log(D))
+    pub fn delete(&mut self) -> Option<(usize, T)> {
+        let index = *self.heap.first()?;
+        self.remove(index).map(|key| (index, key))
+    }
+}
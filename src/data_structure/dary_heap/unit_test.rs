@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn check_heap<const D: usize>(heap: &DaryHeap<isize, D>) {
+        for k in 1..heap.len() {
+            assert!(!heap.less((k - 1) / D, k));
+        }
+    }
+
+    fn check_against_sort<const D: usize>(kind: Orientation) {
+        let mut rng = StdRng::seed_from_u64(D as u64);
+        let vec = (0..1000)
+            .map(|_| rng.gen_range(-500..500))
+            .collect::<Vec<isize>>();
+        let mut sorted = vec.clone();
+        sorted.sort();
+        if let Orientation::Max = kind {
+            sorted.reverse();
+        }
+        let heap = DaryHeap::<isize, D>::from_vec(vec.clone(), kind.clone());
+        check_heap(&heap);
+        assert_eq!(heap.into_sorted_vec(), sorted);
+        let mut heap = DaryHeap::<isize, D>::new(kind);
+        for key in vec {
+            heap.insert(key);
+        }
+        check_heap(&heap);
+        assert_eq!(heap.into_sorted_vec(), sorted);
+    }
+
+    #[test]
+    fn test_dary_heap_against_sort() {
+        check_against_sort::<2>(Orientation::Min);
+        check_against_sort::<3>(Orientation::Max);
+        check_against_sort::<4>(Orientation::Min);
+        check_against_sort::<16>(Orientation::Max);
+    }
+
+    #[test]
+    fn test_dary_heap_interleaved() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut heap = DaryHeap::<isize, 4>::default();
+        let mut reference = Vec::new();
+        for _ in 0..5000 {
+            if rng.gen_bool(0.6) {
+                let key = rng.gen_range(-100..100);
+                heap.insert(key);
+                reference.push(key);
+            } else {
+                reference.sort();
+                assert_eq!(heap.delete(), reference.pop());
+            }
+            assert_eq!(heap.len(), reference.len());
+            assert_eq!(heap.extremum(), reference.iter().max());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_dary_heap_unary() {
+        DaryHeap::<usize, 1>::new(Orientation::Min);
+    }
+
+    fn check_index_heap<const D: usize>(heap: &IndexDaryHeap<isize, D>) {
+        for k in 1..heap.len() {
+            assert!(!heap.less((k - 1) / D, k));
+        }
+        for (position, index) in heap.heap.iter().enumerate() {
+            assert_eq!(heap.positions[*index], Some(position));
+        }
+    }
+
+    fn check_index_heap_against_reference<const D: usize>(kind: Orientation) {
+        let n = 200;
+        let mut rng = StdRng::seed_from_u64(D as u64);
+        let mut heap = IndexDaryHeap::<isize, D>::with_capacity(n, kind.clone());
+        // reference objects by index
+        let mut reference = std::collections::BTreeMap::new();
+        for _ in 0..5000 {
+            let index = rng.gen_range(0..n);
+            let key = rng.gen_range(-500..500);
+            match rng.gen_range(0..5) {
+                0 if !heap.contains(index) => {
+                    heap.insert(index, key);
+                    reference.insert(index, key);
+                }
+                1 if heap.contains(index) => {
+                    heap.change_priority(index, key);
+                    reference.insert(index, key);
+                }
+                2 if heap.contains(index) => {
+                    let key = key.min(reference[&index]);
+                    heap.decrease_key(index, key);
+                    reference.insert(index, key);
+                }
+                3 => assert_eq!(heap.remove(index), reference.remove(&index)),
+                _ => {
+                    let expected = match kind {
+                        Orientation::Max => reference.values().max(),
+                        Orientation::Min => reference.values().min(),
+                    };
+                    let deleted = heap.delete();
+                    assert_eq!(deleted.as_ref().map(|(_, key)| key), expected);
+                    if let Some((index, _)) = deleted {
+                        reference.remove(&index);
+                    }
+                }
+            }
+            check_index_heap(&heap);
+            assert_eq!(heap.len(), reference.len());
+            assert!((0..n).all(|index| heap.get(index) == reference.get(&index)));
+        }
+    }
+
+    #[test]
+    fn test_index_dary_heap() {
+        check_index_heap_against_reference::<2>(Orientation::Min);
+        check_index_heap_against_reference::<4>(Orientation::Min);
+        check_index_heap_against_reference::<3>(Orientation::Max);
+        check_index_heap_against_reference::<8>(Orientation::Max);
+    }
+
+    #[test]
+    fn test_index_dary_heap_dijkstra() {
+        // shortest distances from vertex 0 in a complete graph with random weights
+        let n = 50;
+        let mut rng = StdRng::seed_from_u64(0);
+        let weights = (0..n)
+            .map(|_| {
+                (0..n)
+                    .map(|_| rng.gen_range(1..100))
+                    .collect::<Vec<isize>>()
+            })
+            .collect::<Vec<Vec<isize>>>();
+        let mut heap = IndexDaryHeap::<isize, 4>::with_capacity(n, Orientation::Min);
+        let mut distances = vec![isize::MAX; n];
+        distances[0] = 0;
+        heap.insert(0, 0);
+        while let Some((v, distance)) = heap.delete() {
+            for w in 0..n {
+                if distance + weights[v][w] < distances[w] {
+                    distances[w] = distance + weights[v][w];
+                    if heap.contains(w) {
+                        heap.decrease_key(w, distances[w]);
+                    } else {
+                        heap.insert(w, distances[w]);
+                    }
+                }
+            }
+        }
+        // Bellman-Ford like relaxation until stable
+        let mut expected = vec![isize::MAX; n];
+        expected[0] = 0;
+        for _ in 0..n {
+            for v in 0..n {
+                for w in 0..n {
+                    if expected[v] != isize::MAX && expected[v] + weights[v][w] < expected[w] {
+                        expected[w] = expected[v] + weights[v][w];
+                    }
+                }
+            }
+        }
+        assert_eq!(distances, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_dary_heap_increase_with_decrease_key() {
+        let mut heap = IndexDaryHeap::<isize, 2>::with_capacity(1, Orientation::Min);
+        heap.insert(0, 1);
+        heap.decrease_key(0, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_dary_heap_duplicate_index() {
+        let mut heap = IndexDaryHeap::<isize, 2>::with_capacity(1, Orientation::Min);
+        heap.insert(0, 1);
+        heap.insert(0, 2);
+    }
+}
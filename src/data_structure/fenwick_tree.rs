@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod unit_test;
+use crate::data_structure::Number;
+
+/// Implementation of a Fenwick tree (or binary indexed tree): it maintains
+/// the prefix sums of a sequence of numbers under point updates.
+/// # Examples
+/// ```
+/// use algods::data_structure::FenwickTree;
+/// let mut tree = FenwickTree::from_vec(vec![3, 2, -1, 6, 5]);
+/// assert_eq!(tree.prefix_sum(3), 4);
+/// assert_eq!(tree.range_sum(1, 4), 7);
+/// tree.add(2, 10);
+/// assert_eq!(tree.range_sum(1, 4), 17);
+/// tree.set(0, 0);
+/// assert_eq!(tree.prefix_sum(5), 22);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FenwickTree<T> {
+    // vec[k] holds the sum of the objects at positions (k - lowbit(k), k]
+    // where lowbit(k) is the lowest set bit of k, index 0 is not used
+    vec: Vec<T>,
+}
+impl<T: Number> FenwickTree<T> {
+    /// Creates a tree of `n` zeros.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let tree = FenwickTree::<isize>::new(10);
+    /// assert_eq!(tree.len(), 10);
+    /// assert_eq!(tree.prefix_sum(10), 0);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        Self {
+            vec: vec![T::zero(); n + 1],
+        }
+    }
+    /// Creates a tree from a `Vec` of numbers.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let tree = FenwickTree::from_vec(vec![0.5, 1.5, 2.]);
+    /// assert_eq!(tree.prefix_sum(2), 2.);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let mut tree = Vec::with_capacity(vec.len() + 1);
+        tree.push(T::zero());
+        tree.extend(vec);
+        // each node gives its partial sum to its parent
+        for k in 1..tree.len() {
+            let parent = k + (k & k.wrapping_neg());
+            if parent < tree.len() {
+                tree[parent] = tree[parent] + tree[k];
+            }
+        }
+        Self { vec: tree }
+    }
+    /// Gives the number of objects in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let tree = FenwickTree::from_vec(vec![1, 2]);
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vec.len() - 1
+    }
+    /// Tests whether or not the tree is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let tree = FenwickTree::<usize>::new(0);
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn check_index(&self, index: usize) {
+        if index >= self.len() {
+            panic!(
+                "index {index} is out of bounds, the tree has {} objects",
+                self.len()
+            );
+        }
+    }
+    /// Adds `delta` to the object at a given position.
+    /// # Panics
+    /// If `index >= self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let mut tree = FenwickTree::from_vec(vec![1, 2]);
+    /// tree.add(0, 5);
+    /// assert_eq!(tree.get(0), 6);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn add(&mut self, index: usize, delta: T) {
+        self.check_index(index);
+        let mut k = index + 1;
+        while k < self.vec.len() {
+            self.vec[k] = self.vec[k] + delta;
+            k += k & k.wrapping_neg();
+        }
+    }
+    fn sub(&mut self, index: usize, delta: T) {
+        // same as add with -delta, also valid for unsigned numbers
+        let mut k = index + 1;
+        while k < self.vec.len() {
+            self.vec[k] = self.vec[k] - delta;
+            k += k & k.wrapping_neg();
+        }
+    }
+    /// Returns the sum of the first `end` objects, i.e. those at positions `0..end`.
+    /// # Panics
+    /// If `end > self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let tree = FenwickTree::from_vec(vec![1, 2, 3]);
+    /// assert_eq!(tree.prefix_sum(0), 0);
+    /// assert_eq!(tree.prefix_sum(2), 3);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn prefix_sum(&self, end: usize) -> T {
+        if end > self.len() {
+            panic!(
+                "prefix end {end} is out of bounds, the tree has {} objects",
+                self.len()
+            );
+        }
+        let mut sum = T::zero();
+        let mut k = end;
+        while k > 0 {
+            sum = sum + self.vec[k];
+            k -= k & k.wrapping_neg();
+        }
+        sum
+    }
+    /// Returns the sum of the objects between two positions (low included, high excluded).
+    /// # Panics
+    /// If `high > self.len()` or `low > high`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let tree = FenwickTree::from_vec(vec![1, 2, 3, 4]);
+    /// assert_eq!(tree.range_sum(1, 3), 5);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn range_sum(&self, low: usize, high: usize) -> T {
+        if low > high {
+            panic!("range start {low} is larger than range end {high}");
+        }
+        self.prefix_sum(high) - self.prefix_sum(low)
+    }
+    /// Returns the object at a given position.
+    /// # Panics
+    /// If `index >= self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let tree = FenwickTree::from_vec(vec![1, 2, 3, 4]);
+    /// assert_eq!(tree.get(2), 3);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn get(&self, index: usize) -> T {
+        self.check_index(index);
+        self.range_sum(index, index + 1)
+    }
+    /// Replaces the object at a given position.
+    /// # Panics
+    /// If `index >= self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::FenwickTree;
+    /// let mut tree = FenwickTree::<usize>::from_vec(vec![1, 2, 3, 4]);
+    /// tree.set(2, 0);
+    /// assert_eq!(tree.prefix_sum(4), 7);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn set(&mut self, index: usize, value: T) {
+        let old = self.get(index);
+        if value >= old {
+            self.add(index, value - old);
+        } else {
+            self.sub(index, old - value);
+        }
+    }
+}
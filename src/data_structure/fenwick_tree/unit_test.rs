@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn test_fenwick_tree_new() {
+        let tree = FenwickTree::<usize>::new(5);
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.vec.len(), 6);
+        assert_eq!(tree.range_sum(0, 5), 0);
+        assert!(FenwickTree::<f64>::new(0).is_empty());
+    }
+
+    #[test]
+    fn test_fenwick_tree_against_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut values = (0..200)
+            .map(|_| rng.gen_range(-50..50))
+            .collect::<Vec<i64>>();
+        let mut tree = FenwickTree::from_vec(values.clone());
+        for _ in 0..2000 {
+            let index = rng.gen_range(0..values.len());
+            if rng.gen_bool(0.5) {
+                let delta = rng.gen_range(-50..50);
+                values[index] += delta;
+                tree.add(index, delta);
+            } else {
+                let value = rng.gen_range(-50..50);
+                values[index] = value;
+                tree.set(index, value);
+            }
+            let low = rng.gen_range(0..=values.len());
+            let high = rng.gen_range(low..=values.len());
+            assert_eq!(tree.range_sum(low, high), values[low..high].iter().sum());
+            assert_eq!(tree.get(index), values[index]);
+        }
+    }
+
+    #[test]
+    fn test_fenwick_tree_unsigned_set() {
+        let mut tree = FenwickTree::<u32>::from_vec(vec![5, 6, 7]);
+        tree.set(1, 1);
+        tree.set(2, 9);
+        assert_eq!(tree.prefix_sum(3), 15);
+        assert_eq!(tree.range_sum(1, 2), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_fenwick_tree_out_of_bounds() {
+        let mut tree = FenwickTree::<u32>::new(3);
+        tree.add(3, 1);
+    }
+}
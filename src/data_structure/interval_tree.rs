@@ -0,0 +1,470 @@
+#[cfg(test)]
+mod unit_test;
+use std::cmp::{max, Ordering};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node<K, V> {
+    low: K,
+    high: K,
+    value: V,
+    // largest high endpoint in the subtree rooted at this node
+    max: K,
+    // height of the subtree rooted at this node (1 for a leaf)
+    height: usize,
+    left: Option<Box<Node<K, V>>>,
+    right: Option<Box<Node<K, V>>>,
+}
+impl<K: Clone, V> Node<K, V> {
+    pub fn init(low: K, high: K, value: V) -> Self {
+        Self {
+            max: high.clone(),
+            low,
+            high,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+fn height<K, V>(node: &Link<K, V>) -> usize {
+    node.as_ref().map_or(0, |nod| nod.height)
+}
+
+fn overlaps<K: Ord>(low1: &K, high1: &K, low2: &K, high2: &K) -> bool {
+    low1 <= high2 && low2 <= high1
+}
+
+/// Implementation of an interval search tree: a balanced (AVL) binary search tree
+/// of closed intervals `[low, high]` ordered by their low endpoints
+/// (then by their high endpoints), where each node also stores the largest high endpoint of its subtree.
+/// It allows to efficiently find the intervals overlapping a given interval.
+/// # Example
+/// ```
+/// use algods::data_structure::IntervalTree;
+/// let mut tree = IntervalTree::new();
+/// tree.insert(17, 19, "a");
+/// tree.insert(5, 8, "b");
+/// tree.insert(21, 24, "c");
+/// tree.insert(4, 8, "d");
+/// tree.insert(15, 18, "e");
+/// tree.insert(7, 10, "f");
+/// assert_eq!(tree.len(), 6);
+/// assert_eq!(tree.all_overlaps(&9, &16), vec![(&7, &10, &"f"), (&15, &18, &"e")]);
+/// assert_eq!(tree.any_overlap(&11, &14), None);
+/// assert_eq!(tree.stabbing(&18).len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntervalTree<K, V> {
+    root: Link<K, V>,
+    len: usize,
+}
+impl<K, V> Default for IntervalTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K, V> IntervalTree<K, V> {
+    /// Creates an empty tree instance.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let tree = IntervalTree::<usize, isize>::new();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self { root: None, len: 0 }
+    }
+    /// Gives the number of intervals in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let tree = IntervalTree::<usize, usize>::new();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Tests whether or not the tree is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 2, "1-2");
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+impl<K: Ord + Clone, V> IntervalTree<K, V> {
+    fn update(node: &mut Box<Node<K, V>>) {
+        // recomputes the augmented data of a node from its children
+        node.height = 1 + max(height(&node.left), height(&node.right));
+        let mut largest = node.high.clone();
+        for child in [&node.left, &node.right].into_iter().flatten() {
+            if child.max > largest {
+                largest = child.max.clone();
+            }
+        }
+        node.max = largest;
+    }
+    fn rotate_right(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+        let mut left = node.left.take().expect("rotation needs a left child");
+        node.left = left.right.take();
+        Self::update(&mut node);
+        left.right = Some(node);
+        Self::update(&mut left);
+        left
+    }
+    fn rotate_left(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+        let mut right = node.right.take().expect("rotation needs a right child");
+        node.right = right.left.take();
+        Self::update(&mut node);
+        right.left = Some(node);
+        Self::update(&mut right);
+        right
+    }
+    fn balance(mut node: Box<Node<K, V>>) -> Box<Node<K, V>> {
+        // restores the AVL property: heights of the children differ by at most 1
+        Self::update(&mut node);
+        let (left_height, right_height) = (height(&node.left), height(&node.right));
+        if left_height > right_height + 1 {
+            let left = node.left.take().unwrap();
+            node.left = if height(&left.right) > height(&left.left) {
+                Some(Self::rotate_left(left))
+            } else {
+                Some(left)
+            };
+            Self::rotate_right(node)
+        } else if right_height > left_height + 1 {
+            let right = node.right.take().unwrap();
+            node.right = if height(&right.left) > height(&right.right) {
+                Some(Self::rotate_right(right))
+            } else {
+                Some(right)
+            };
+            Self::rotate_left(node)
+        } else {
+            node
+        }
+    }
+    fn compare(node: &Node<K, V>, low: &K, high: &K) -> Ordering {
+        low.cmp(&node.low).then_with(|| high.cmp(&node.high))
+    }
+    fn put(node: Link<K, V>, low: K, high: K, value: V) -> (Box<Node<K, V>>, Option<V>) {
+        match node {
+            None => (Box::new(Node::init(low, high, value)), None),
+            Some(mut nod) => {
+                let old = match Self::compare(&nod, &low, &high) {
+                    Ordering::Less => {
+                        let (child, old) = Self::put(nod.left.take(), low, high, value);
+                        nod.left = Some(child);
+                        old
+                    }
+                    Ordering::Greater => {
+                        let (child, old) = Self::put(nod.right.take(), low, high, value);
+                        nod.right = Some(child);
+                        old
+                    }
+                    Ordering::Equal => Some(std::mem::replace(&mut nod.value, value)),
+                };
+                (Self::balance(nod), old)
+            }
+        }
+    }
+    /// Inserts an interval `[low, high]` with its associated value in the tree.
+    /// When the interval is already in the tree, then it replaces the old value with the new one specified.
+    /// # Panics
+    /// If `low > high`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// tree.insert(1, 3, "b");
+    /// assert_eq!(tree.len(), 1);
+    /// assert_eq!(tree.get(&1, &3), Some(&"b"));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn insert(&mut self, low: K, high: K, value: V) {
+        if low > high {
+            panic!("the low endpoint of an interval should be <= its high endpoint");
+        }
+        let (root, old) = Self::put(self.root.take(), low, high, value);
+        self.root = Some(root);
+        if old.is_none() {
+            self.len += 1;
+        }
+    }
+    /// Returns a reference of the value associated to the interval `[low, high]`
+    /// if it is in the tree. Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// assert_eq!(tree.get(&1, &3), Some(&"a"));
+    /// assert_eq!(tree.get(&1, &2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn get(&self, low: &K, high: &K) -> Option<&V> {
+        let mut node = &self.root;
+        while let Some(nod) = node {
+            match Self::compare(nod, low, high) {
+                Ordering::Less => node = &nod.left,
+                Ordering::Greater => node = &nod.right,
+                Ordering::Equal => return Some(&nod.value),
+            }
+        }
+        None
+    }
+    /// Tests whether or not the tree contains the interval `[low, high]`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// assert!(tree.contains(&1, &3));
+    /// ```
+    pub fn contains(&self, low: &K, high: &K) -> bool {
+        self.get(low, high).is_some()
+    }
+    fn delete_min(mut node: Box<Node<K, V>>) -> (Link<K, V>, Box<Node<K, V>>) {
+        // removes the smallest interval of the subtree and returns it as a single node
+        match node.left.take() {
+            None => (node.right.take(), node),
+            Some(left) => {
+                let (child, min) = Self::delete_min(left);
+                node.left = child;
+                (Some(Self::balance(node)), min)
+            }
+        }
+    }
+    fn remove(node: Link<K, V>, low: &K, high: &K) -> (Link<K, V>, Option<V>) {
+        match node {
+            None => (None, None),
+            Some(mut nod) => match Self::compare(&nod, low, high) {
+                Ordering::Less => {
+                    let (child, old) = Self::remove(nod.left.take(), low, high);
+                    nod.left = child;
+                    (Some(Self::balance(nod)), old)
+                }
+                Ordering::Greater => {
+                    let (child, old) = Self::remove(nod.right.take(), low, high);
+                    nod.right = child;
+                    (Some(Self::balance(nod)), old)
+                }
+                Ordering::Equal => {
+                    let Node {
+                        value, left, right, ..
+                    } = *nod;
+                    match (left, right) {
+                        (None, child) | (child, None) => (child, Some(value)),
+                        (Some(left), Some(right)) => {
+                            // the successor takes the place of the deleted node
+                            let (right, mut successor) = Self::delete_min(right);
+                            successor.left = Some(left);
+                            successor.right = right;
+                            (Some(Self::balance(successor)), Some(value))
+                        }
+                    }
+                }
+            },
+        }
+    }
+    /// Removes the interval `[low, high]` from the tree, returning the value associated if any.
+    /// Otherwise it returns `None`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// assert_eq!(tree.delete(&1, &3), Some("a"));
+    /// assert_eq!(tree.delete(&1, &3), None);
+    /// assert!(tree.is_empty());
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn delete(&mut self, low: &K, high: &K) -> Option<V> {
+        let (root, old) = Self::remove(self.root.take(), low, high);
+        self.root = root;
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+    /// Returns one interval of the tree overlapping the interval `[low, high]`
+    /// with its value, if any. Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// tree.insert(6, 9, "b");
+    /// assert_eq!(tree.any_overlap(&3, &5), Some((&1, &3, &"a")));
+    /// assert_eq!(tree.any_overlap(&4, &5), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn any_overlap(&self, low: &K, high: &K) -> Option<(&K, &K, &V)> {
+        let mut node = &self.root;
+        while let Some(nod) = node {
+            if overlaps(&nod.low, &nod.high, low, high) {
+                return Some((&nod.low, &nod.high, &nod.value));
+            }
+            // when no interval of the left subtree ends after low, none of them overlaps,
+            // otherwise if none of them overlaps then no interval of the right subtree does either
+            node = match &nod.left {
+                Some(left) if &left.max >= low => &nod.left,
+                _ => &nod.right,
+            };
+        }
+        None
+    }
+    fn overlapping<'a>(
+        node: &'a Link<K, V>,
+        low: &K,
+        high: &K,
+        result: &mut Vec<(&'a K, &'a K, &'a V)>,
+    ) {
+        if let Some(nod) = node {
+            if &nod.max < low {
+                // no interval of this subtree ends after low
+                return;
+            }
+            Self::overlapping(&nod.left, low, high, result);
+            if overlaps(&nod.low, &nod.high, low, high) {
+                result.push((&nod.low, &nod.high, &nod.value));
+            }
+            // the intervals of the right subtree start after nod.low
+            if &nod.low <= high {
+                Self::overlapping(&nod.right, low, high, result);
+            }
+        }
+    }
+    /// Returns all the intervals of the tree overlapping the interval `[low, high]`
+    /// with their values, in increasing order.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// tree.insert(6, 9, "b");
+    /// tree.insert(2, 7, "c");
+    /// assert_eq!(tree.all_overlaps(&3, &6), vec![(&1, &3, &"a"), (&2, &7, &"c"), (&6, &9, &"b")]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(R log(N)) where R is the number of intervals returned.
+    pub fn all_overlaps(&self, low: &K, high: &K) -> Vec<(&K, &K, &V)> {
+        let mut result = Vec::new();
+        Self::overlapping(&self.root, low, high, &mut result);
+        result
+    }
+    /// Returns all the intervals of the tree containing a point with their values, in increasing order.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// tree.insert(3, 9, "b");
+    /// tree.insert(4, 7, "c");
+    /// assert_eq!(tree.stabbing(&3), vec![(&1, &3, &"a"), (&3, &9, &"b")]);
+    /// ```
+    pub fn stabbing(&self, point: &K) -> Vec<(&K, &K, &V)> {
+        self.all_overlaps(point, point)
+    }
+    /// Returns the interval with the smallest low endpoint in the tree (the smallest high
+    /// endpoint among them), if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// tree.insert(1, 2, "b");
+    /// assert_eq!(tree.min(), Some((&1, &2)));
+    /// ```
+    pub fn min(&self) -> Option<(&K, &K)> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some((&node.low, &node.high))
+    }
+    /// Returns the interval with the largest low endpoint in the tree (the largest high
+    /// endpoint among them), if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// tree.insert(2, 2, "b");
+    /// assert_eq!(tree.max(), Some((&2, &2)));
+    /// ```
+    pub fn max(&self) -> Option<(&K, &K)> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some((&node.low, &node.high))
+    }
+    /// Returns the interval with the largest low endpoint smaller or equal to the input key
+    /// (the largest high endpoint among them), if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// tree.insert(5, 6, "b");
+    /// assert_eq!(tree.floor(&4), Some((&1, &3)));
+    /// assert_eq!(tree.floor(&5), Some((&5, &6)));
+    /// assert_eq!(tree.floor(&0), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn floor(&self, key: &K) -> Option<(&K, &K)> {
+        let mut node = &self.root;
+        let mut result = None;
+        while let Some(nod) = node {
+            if &nod.low <= key {
+                result = Some((&nod.low, &nod.high));
+                node = &nod.right;
+            } else {
+                node = &nod.left;
+            }
+        }
+        result
+    }
+    /// Returns the interval with the smallest low endpoint larger or equal to the input key
+    /// (the smallest high endpoint among them), if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(1, 3, "a");
+    /// tree.insert(5, 6, "b");
+    /// assert_eq!(tree.ceil(&2), Some((&5, &6)));
+    /// assert_eq!(tree.ceil(&1), Some((&1, &3)));
+    /// assert_eq!(tree.ceil(&6), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn ceil(&self, key: &K) -> Option<(&K, &K)> {
+        let mut node = &self.root;
+        let mut result = None;
+        while let Some(nod) = node {
+            if &nod.low >= key {
+                result = Some((&nod.low, &nod.high));
+                node = &nod.left;
+            } else {
+                node = &nod.right;
+            }
+        }
+        result
+    }
+}
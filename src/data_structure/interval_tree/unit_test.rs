@@ -0,0 +1,118 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn check_invariants(node: &Link<usize, usize>) -> (usize, Option<usize>) {
+        // returns the height and the largest high endpoint of the subtree
+        match node {
+            None => (0, None),
+            Some(nod) => {
+                let (left_height, left_max) = check_invariants(&nod.left);
+                let (right_height, right_max) = check_invariants(&nod.right);
+                assert!(left_height.abs_diff(right_height) <= 1);
+                assert_eq!(nod.height, 1 + left_height.max(right_height));
+                let largest = [left_max, right_max, Some(nod.high)]
+                    .into_iter()
+                    .flatten()
+                    .max();
+                assert_eq!(Some(nod.max), largest);
+                (nod.height, largest)
+            }
+        }
+    }
+
+    #[test]
+    fn test_interval_tree_insert_delete() {
+        let mut tree = IntervalTree::<usize, &str>::new();
+        assert!(tree.is_empty());
+        assert_eq!(tree.min(), None);
+        tree.insert(5, 8, "a");
+        tree.insert(1, 2, "b");
+        tree.insert(5, 6, "c");
+        tree.insert(5, 8, "d");
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.get(&5, &8), Some(&"d"));
+        assert_eq!(tree.min(), Some((&1, &2)));
+        assert_eq!(tree.max(), Some((&5, &8)));
+        assert_eq!(tree.floor(&5), Some((&5, &8)));
+        assert_eq!(tree.ceil(&2), Some((&5, &6)));
+        assert_eq!(tree.delete(&5, &6), Some("c"));
+        assert_eq!(tree.delete(&5, &7), None);
+        assert_eq!(tree.len(), 2);
+        assert!(!tree.contains(&5, &6));
+        assert_eq!(tree.stabbing(&6), vec![(&5, &8, &"d")]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_interval_tree_bad_interval() {
+        IntervalTree::new().insert(2, 1, 0);
+    }
+
+    #[test]
+    fn test_interval_tree_against_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut tree = IntervalTree::new();
+        let mut brute: Vec<(usize, usize, usize)> = Vec::new();
+        for step in 0..3000 {
+            let low = rng.gen_range(0..500);
+            let high = low + rng.gen_range(0..30);
+            if rng.gen_bool(0.3) && !brute.is_empty() {
+                let (l, h, v) = brute.swap_remove(rng.gen_range(0..brute.len()));
+                assert_eq!(tree.delete(&l, &h), Some(v));
+            } else if let Some(pair) = brute.iter_mut().find(|e| e.0 == low && e.1 == high) {
+                pair.2 = step;
+                tree.insert(low, high, step);
+            } else {
+                brute.push((low, high, step));
+                tree.insert(low, high, step);
+            }
+            assert_eq!(tree.len(), brute.len());
+        }
+        check_invariants(&tree.root);
+        brute.sort();
+        for _ in 0..200 {
+            let low = rng.gen_range(0..550);
+            let high = low + rng.gen_range(0..20);
+            let expected = brute
+                .iter()
+                .filter(|e| e.0 <= high && low <= e.1)
+                .map(|e| (&e.0, &e.1, &e.2))
+                .collect::<Vec<_>>();
+            assert_eq!(tree.all_overlaps(&low, &high), expected);
+            match tree.any_overlap(&low, &high) {
+                Some(interval) => assert!(expected.contains(&interval)),
+                None => assert!(expected.is_empty()),
+            }
+            let floor = brute
+                .iter()
+                .filter(|e| e.0 <= low)
+                .map(|e| (&e.0, &e.1))
+                .max();
+            assert_eq!(tree.floor(&low), floor);
+            let ceil = brute
+                .iter()
+                .filter(|e| e.0 >= low)
+                .map(|e| (&e.0, &e.1))
+                .min();
+            assert_eq!(tree.ceil(&low), ceil);
+        }
+    }
+
+    #[test]
+    fn test_interval_tree_balanced() {
+        let mut tree = IntervalTree::new();
+        for k in 0..1024usize {
+            tree.insert(k, k + 1, k);
+        }
+        check_invariants(&tree.root);
+        assert!(height(&tree.root) <= 11);
+        for k in 0..1000usize {
+            tree.delete(&k, &(k + 1));
+        }
+        check_invariants(&tree.root);
+        assert_eq!(tree.len(), 24);
+        assert_eq!(tree.min(), Some((&1000, &1001)));
+    }
+}
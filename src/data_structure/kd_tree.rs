@@ -0,0 +1,492 @@
+#[cfg(test)]
+mod unit_test;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+fn squared_distance<const D: usize>(p: &[f64; D], q: &[f64; D]) -> f64 {
+    p.iter().zip(q.iter()).map(|(a, b)| (a - b) * (a - b)).sum()
+}
+
+fn in_rectangle<const D: usize>(point: &[f64; D], low: &[f64; D], high: &[f64; D]) -> bool {
+    (0..D).all(|axis| low[axis] <= point[axis] && point[axis] <= high[axis])
+}
+
+// Candidate neighbour ordered by its squared distance to the query point,
+// used in a max oriented heap keeping the k best candidates.
+struct Candidate<'a, const D: usize, V> {
+    distance: f64,
+    point: &'a [f64; D],
+    value: &'a V,
+}
+impl<const D: usize, V> Ord for Candidate<'_, D, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.total_cmp(&other.distance)
+    }
+}
+impl<const D: usize, V> PartialOrd for Candidate<'_, D, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<const D: usize, V> Eq for Candidate<'_, D, V> {}
+impl<const D: usize, V> PartialEq for Candidate<'_, D, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+fn into_sorted_neighbours<'a, const D: usize, V>(
+    heap: BinaryHeap<Candidate<'a, D, V>>,
+) -> Vec<(&'a [f64; D], &'a V)> {
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|candidate| (candidate.point, candidate.value))
+        .collect()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node<const D: usize, V> {
+    point: [f64; D],
+    value: V,
+    left: Option<Box<Node<D, V>>>,
+    right: Option<Box<Node<D, V>>>,
+}
+impl<const D: usize, V> Node<D, V> {
+    pub fn init(point: [f64; D], value: V) -> Self {
+        Self {
+            point,
+            value,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+/// Implementation of a k-d tree: a binary search tree of points in
+/// dimension `D`, where the nodes at depth `d` split the space along the axis `d % D`.
+/// # Example
+/// ```
+/// use algods::data_structure::KdTree;
+/// let mut tree = KdTree::<2, &str>::new();
+/// tree.insert([0.1, 0.2], "a");
+/// tree.insert([0.5, 0.5], "b");
+/// tree.insert([0.9, 0.3], "c");
+/// assert_eq!(tree.len(), 3);
+/// assert!(tree.contains(&[0.5, 0.5]));
+/// assert_eq!(tree.range_search(&[0.0, 0.0], &[0.6, 0.6]).len(), 2);
+/// assert_eq!(tree.nearest(&[0.8, 0.3]), Some((&[0.9, 0.3], &"c")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KdTree<const D: usize, V> {
+    root: Option<Box<Node<D, V>>>,
+    len: usize,
+}
+impl<const D: usize, V> Default for KdTree<D, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<const D: usize, V> KdTree<D, V> {
+    /// Creates an empty tree instance.
+    /// # Panics
+    /// If `D = 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let tree = KdTree::<3, usize>::new();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        if D == 0 {
+            panic!("dimension should be > 0");
+        }
+        Self { root: None, len: 0 }
+    }
+    /// Gives the number of points in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let tree = KdTree::<2, usize>::new();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Tests whether or not the tree is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let mut tree = KdTree::<2, usize>::new();
+    /// tree.insert([0., 1.], 1);
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn put(node: &mut Option<Box<Node<D, V>>>, point: [f64; D], value: V, depth: usize) -> bool {
+        // returns true when the point is a new one
+        match node {
+            None => {
+                *node = Some(Box::new(Node::init(point, value)));
+                true
+            }
+            Some(ref mut nod) => {
+                if nod.point == point {
+                    nod.value = value;
+                    return false;
+                }
+                let axis = depth % D;
+                if point[axis] < nod.point[axis] {
+                    Self::put(&mut nod.left, point, value, depth + 1)
+                } else {
+                    Self::put(&mut nod.right, point, value, depth + 1)
+                }
+            }
+        }
+    }
+    /// Inserts a point with its associated value in the tree. When the point is
+    /// already in the tree, then it replaces the old value with the new one specified.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let mut tree = KdTree::<2, usize>::new();
+    /// tree.insert([0., 1.], 1);
+    /// tree.insert([0., 1.], 2);
+    /// assert_eq!(tree.len(), 1);
+    /// assert_eq!(tree.get(&[0., 1.]), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average, O(N) in the worst case.
+    pub fn insert(&mut self, point: [f64; D], value: V) {
+        if Self::put(&mut self.root, point, value, 0) {
+            self.len += 1;
+        }
+    }
+    /// Returns a reference of the value associated to a point if any exists in the tree.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let mut tree = KdTree::<2, &str>::new();
+    /// tree.insert([1., 2.], "one");
+    /// assert_eq!(tree.get(&[1., 2.]), Some(&"one"));
+    /// assert_eq!(tree.get(&[2., 1.]), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average, O(N) in the worst case.
+    pub fn get(&self, point: &[f64; D]) -> Option<&V> {
+        let mut node = &self.root;
+        let mut depth = 0;
+        while let Some(nod) = node {
+            if &nod.point == point {
+                return Some(&nod.value);
+            }
+            let axis = depth % D;
+            if point[axis] < nod.point[axis] {
+                node = &nod.left;
+            } else {
+                node = &nod.right;
+            }
+            depth += 1;
+        }
+        None
+    }
+    /// Tests whether or not the tree contains a given point.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let mut tree = KdTree::<2, &str>::new();
+    /// tree.insert([1., 2.], "one");
+    /// assert!(tree.contains(&[1., 2.]));
+    /// ```
+    pub fn contains(&self, point: &[f64; D]) -> bool {
+        self.get(point).is_some()
+    }
+    fn range<'a>(
+        node: &'a Option<Box<Node<D, V>>>,
+        low: &[f64; D],
+        high: &[f64; D],
+        depth: usize,
+        result: &mut Vec<&'a [f64; D]>,
+    ) {
+        if let Some(nod) = node {
+            if in_rectangle(&nod.point, low, high) {
+                result.push(&nod.point);
+            }
+            let axis = depth % D;
+            // the left subtree only has points strictly below the splitting value
+            if low[axis] < nod.point[axis] {
+                Self::range(&nod.left, low, high, depth + 1, result);
+            }
+            if high[axis] >= nod.point[axis] {
+                Self::range(&nod.right, low, high, depth + 1, result);
+            }
+        }
+    }
+    /// Returns the points of the tree lying in the axis-aligned rectangle
+    /// defined by its lowest corner `low` and its highest corner `high` (both included).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let mut tree = KdTree::<2, usize>::new();
+    /// tree.insert([0., 0.], 0);
+    /// tree.insert([1., 1.], 1);
+    /// tree.insert([2., 2.], 2);
+    /// assert_eq!(tree.range_search(&[0.5, 0.5], &[2., 2.]), vec![&[1., 1.], &[2., 2.]]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(R + sqrt(N)) on average in dimension 2,
+    /// where R is the number of points returned.
+    pub fn range_search(&self, low: &[f64; D], high: &[f64; D]) -> Vec<&[f64; D]> {
+        let mut result = Vec::new();
+        Self::range(&self.root, low, high, 0, &mut result);
+        result
+    }
+    /// Returns the number of points of the tree lying in the axis-aligned rectangle
+    /// defined by its lowest corner `low` and its highest corner `high` (both included).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let mut tree = KdTree::<2, usize>::new();
+    /// tree.insert([0., 0.], 0);
+    /// tree.insert([1., 1.], 1);
+    /// assert_eq!(tree.range_count(&[-1., -1.], &[0.5, 0.5]), 1);
+    /// ```
+    pub fn range_count(&self, low: &[f64; D], high: &[f64; D]) -> usize {
+        self.range_search(low, high).len()
+    }
+    fn neighbours<'a>(
+        node: &'a Option<Box<Node<D, V>>>,
+        query: &[f64; D],
+        k: usize,
+        depth: usize,
+        heap: &mut BinaryHeap<Candidate<'a, D, V>>,
+    ) {
+        if let Some(nod) = node {
+            let distance = squared_distance(&nod.point, query);
+            if heap.len() < k {
+                heap.push(Candidate {
+                    distance,
+                    point: &nod.point,
+                    value: &nod.value,
+                });
+            } else if heap.peek().is_some_and(|worst| distance < worst.distance) {
+                heap.pop();
+                heap.push(Candidate {
+                    distance,
+                    point: &nod.point,
+                    value: &nod.value,
+                });
+            }
+            let axis = depth % D;
+            let gap = query[axis] - nod.point[axis];
+            // visit first the subtree on the same side as the query point
+            let (near, far) = if gap < 0. {
+                (&nod.left, &nod.right)
+            } else {
+                (&nod.right, &nod.left)
+            };
+            Self::neighbours(near, query, k, depth + 1, heap);
+            // the other subtree is visited only if it can contain a better candidate
+            if heap.len() < k || heap.peek().is_some_and(|worst| gap * gap < worst.distance) {
+                Self::neighbours(far, query, k, depth + 1, heap);
+            }
+        }
+    }
+    /// Returns the point of the tree closest to the query point (in euclidean distance)
+    /// with its value, if any. Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let mut tree = KdTree::<2, usize>::new();
+    /// tree.insert([0., 0.], 0);
+    /// tree.insert([1., 1.], 1);
+    /// assert_eq!(tree.nearest(&[0.9, 0.8]), Some((&[1., 1.], &1)));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average for well spread points.
+    pub fn nearest(&self, query: &[f64; D]) -> Option<(&[f64; D], &V)> {
+        self.k_nearest(query, 1).pop()
+    }
+    /// Returns the `k` points of the tree closest to the query point (in euclidean distance)
+    /// with their values, from the closest to the farthest. When the tree has less than `k` points,
+    /// all of them are returned.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::KdTree;
+    /// let mut tree = KdTree::<2, usize>::new();
+    /// tree.insert([0., 0.], 0);
+    /// tree.insert([1., 1.], 1);
+    /// tree.insert([5., 5.], 2);
+    /// let neighbours = tree.k_nearest(&[0.9, 0.8], 2);
+    /// assert_eq!(neighbours, vec![(&[1., 1.], &1), (&[0., 0.], &0)]);
+    /// ```
+    pub fn k_nearest(&self, query: &[f64; D], k: usize) -> Vec<(&[f64; D], &V)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap = BinaryHeap::with_capacity(k.min(self.len()) + 1);
+        Self::neighbours(&self.root, query, k, 0, &mut heap);
+        into_sorted_neighbours(heap)
+    }
+}
+
+/// Brute force implementation of a set of points in dimension `D` based on an unordered `Vec`,
+/// with the same operations as `KdTree`. Each operation runs in linear time:
+/// it is meant as a reference implementation for small sets or to check `KdTree`.
+/// # Example
+/// ```
+/// use algods::data_structure::PointSet;
+/// let mut set = PointSet::<2, &str>::new();
+/// set.insert([0.1, 0.2], "a");
+/// set.insert([0.5, 0.5], "b");
+/// assert_eq!(set.len(), 2);
+/// assert_eq!(set.nearest(&[0.4, 0.4]), Some((&[0.5, 0.5], &"b")));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PointSet<const D: usize, V> {
+    vec: Vec<([f64; D], V)>,
+}
+impl<const D: usize, V> PointSet<D, V> {
+    /// Creates an empty set instance.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let set = PointSet::<2, usize>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+    /// Gives the number of points in the set.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let set = PointSet::<2, usize>::new();
+    /// assert_eq!(set.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+    /// Tests whether or not the set is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let mut set = PointSet::<2, usize>::new();
+    /// set.insert([0., 1.], 1);
+    /// assert!(!set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Inserts a point with its associated value in the set. When the point is
+    /// already in the set, then it replaces the old value with the new one specified.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let mut set = PointSet::<2, usize>::new();
+    /// set.insert([0., 1.], 1);
+    /// set.insert([0., 1.], 2);
+    /// assert_eq!(set.len(), 1);
+    /// assert_eq!(set.get(&[0., 1.]), Some(&2));
+    /// ```
+    pub fn insert(&mut self, point: [f64; D], value: V) {
+        match self.vec.iter_mut().find(|(p, _)| p == &point) {
+            Some(pair) => pair.1 = value,
+            None => self.vec.push((point, value)),
+        }
+    }
+    /// Returns a reference of the value associated to a point if any exists in the set.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let mut set = PointSet::<2, &str>::new();
+    /// set.insert([1., 2.], "one");
+    /// assert_eq!(set.get(&[1., 2.]), Some(&"one"));
+    /// ```
+    pub fn get(&self, point: &[f64; D]) -> Option<&V> {
+        self.vec.iter().find(|(p, _)| p == point).map(|(_, v)| v)
+    }
+    /// Tests whether or not the set contains a given point.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let mut set = PointSet::<2, &str>::new();
+    /// set.insert([1., 2.], "one");
+    /// assert!(set.contains(&[1., 2.]));
+    /// ```
+    pub fn contains(&self, point: &[f64; D]) -> bool {
+        self.get(point).is_some()
+    }
+    /// Returns the points of the set lying in the axis-aligned rectangle
+    /// defined by its lowest corner `low` and its highest corner `high` (both included).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let mut set = PointSet::<2, usize>::new();
+    /// set.insert([0., 0.], 0);
+    /// set.insert([1., 1.], 1);
+    /// assert_eq!(set.range_search(&[0.5, 0.5], &[2., 2.]), vec![&[1., 1.]]);
+    /// ```
+    pub fn range_search(&self, low: &[f64; D], high: &[f64; D]) -> Vec<&[f64; D]> {
+        self.vec
+            .iter()
+            .filter(|(p, _)| in_rectangle(p, low, high))
+            .map(|(p, _)| p)
+            .collect()
+    }
+    /// Returns the number of points of the set lying in the axis-aligned rectangle
+    /// defined by its lowest corner `low` and its highest corner `high` (both included).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let mut set = PointSet::<2, usize>::new();
+    /// set.insert([0., 0.], 0);
+    /// assert_eq!(set.range_count(&[0., 0.], &[1., 1.]), 1);
+    /// ```
+    pub fn range_count(&self, low: &[f64; D], high: &[f64; D]) -> usize {
+        self.range_search(low, high).len()
+    }
+    /// Returns the point of the set closest to the query point (in euclidean distance)
+    /// with its value, if any. Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let mut set = PointSet::<2, usize>::new();
+    /// set.insert([0., 0.], 0);
+    /// set.insert([1., 1.], 1);
+    /// assert_eq!(set.nearest(&[0.2, 0.1]), Some((&[0., 0.], &0)));
+    /// ```
+    pub fn nearest(&self, query: &[f64; D]) -> Option<(&[f64; D], &V)> {
+        self.k_nearest(query, 1).pop()
+    }
+    /// Returns the `k` points of the set closest to the query point (in euclidean distance)
+    /// with their values, from the closest to the farthest.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PointSet;
+    /// let mut set = PointSet::<2, usize>::new();
+    /// set.insert([0., 0.], 0);
+    /// set.insert([1., 1.], 1);
+    /// set.insert([5., 5.], 2);
+    /// assert_eq!(set.k_nearest(&[4., 4.], 1), vec![(&[5., 5.], &2)]);
+    /// ```
+    pub fn k_nearest(&self, query: &[f64; D], k: usize) -> Vec<(&[f64; D], &V)> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut heap = BinaryHeap::with_capacity(k.min(self.len()) + 1);
+        for (point, value) in self.vec.iter() {
+            heap.push(Candidate {
+                distance: squared_distance(point, query),
+                point,
+                value,
+            });
+            if heap.len() > k {
+                heap.pop();
+            }
+        }
+        into_sorted_neighbours(heap)
+    }
+}
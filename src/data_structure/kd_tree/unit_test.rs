@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn random_point<const D: usize>(rng: &mut StdRng) -> [f64; D] {
+        let mut point = [0.; D];
+        for coordinate in point.iter_mut() {
+            *coordinate = rng.gen::<f64>();
+        }
+        point
+    }
+
+    fn sorted(mut points: Vec<&[f64; 3]>) -> Vec<[f64; 3]> {
+        points.sort_by(|p, q| p.partial_cmp(q).unwrap());
+        points.into_iter().copied().collect()
+    }
+
+    fn build(n: usize, seed: u64) -> (KdTree<3, usize>, PointSet<3, usize>) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut tree = KdTree::new();
+        let mut set = PointSet::new();
+        for k in 0..n {
+            let point = random_point::<3>(&mut rng);
+            tree.insert(point, k);
+            set.insert(point, k);
+        }
+        (tree, set)
+    }
+
+    #[test]
+    fn test_kd_tree_insert_contains() {
+        let mut tree = KdTree::<2, &str>::new();
+        assert!(tree.is_empty());
+        assert_eq!(tree.nearest(&[0., 0.]), None);
+        tree.insert([0.7, 0.2], "a");
+        tree.insert([0.5, 0.4], "b");
+        tree.insert([0.2, 0.3], "c");
+        tree.insert([0.4, 0.7], "d");
+        tree.insert([0.9, 0.6], "e");
+        tree.insert([0.5, 0.4], "f");
+        assert_eq!(tree.len(), 5);
+        assert_eq!(tree.get(&[0.5, 0.4]), Some(&"f"));
+        assert!(tree.contains(&[0.9, 0.6]));
+        // same x as a node but a different point
+        assert!(!tree.contains(&[0.7, 0.9]));
+        assert_eq!(tree.range_count(&[0., 0.], &[0.5, 0.5]), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_kd_tree_zero_dimension() {
+        KdTree::<0, usize>::new();
+    }
+
+    #[test]
+    fn test_kd_tree_range_search_against_brute_force() {
+        let (tree, set) = build(2000, 0);
+        let mut rng = StdRng::seed_from_u64(100);
+        for _ in 0..50 {
+            let a = random_point::<3>(&mut rng);
+            let b = random_point::<3>(&mut rng);
+            let low = [a[0].min(b[0]), a[1].min(b[1]), a[2].min(b[2])];
+            let high = [a[0].max(b[0]), a[1].max(b[1]), a[2].max(b[2])];
+            assert_eq!(
+                sorted(tree.range_search(&low, &high)),
+                sorted(set.range_search(&low, &high))
+            );
+        }
+        assert_eq!(tree.range_count(&[0.; 3], &[1.; 3]), 2000);
+    }
+
+    #[test]
+    fn test_kd_tree_nearest_against_brute_force() {
+        let (tree, set) = build(2000, 1);
+        let mut rng = StdRng::seed_from_u64(101);
+        for _ in 0..200 {
+            let query = random_point::<3>(&mut rng);
+            assert_eq!(tree.nearest(&query), set.nearest(&query));
+        }
+    }
+
+    #[test]
+    fn test_kd_tree_k_nearest_against_brute_force() {
+        let (tree, set) = build(2000, 2);
+        let mut rng = StdRng::seed_from_u64(102);
+        for k in [0, 1, 5, 30] {
+            let query = random_point::<3>(&mut rng);
+            let neighbours = tree.k_nearest(&query, k);
+            assert_eq!(neighbours.len(), k);
+            assert_eq!(neighbours, set.k_nearest(&query, k));
+        }
+        let (small_tree, _) = build(3, 3);
+        assert_eq!(small_tree.k_nearest(&[0.5; 3], 10).len(), 3);
+        assert_eq!(small_tree.k_nearest(&[0.5; 3], usize::MAX).len(), 3);
+    }
+
+    #[test]
+    fn test_point_set() {
+        let mut set = PointSet::<2, usize>::new();
+        assert!(set.is_empty());
+        assert_eq!(set.nearest(&[0., 0.]), None);
+        set.insert([0., 0.], 0);
+        set.insert([1., 0.], 1);
+        set.insert([0., 0.], 2);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get(&[0., 0.]), Some(&2));
+        assert!(!set.contains(&[0., 1.]));
+        assert_eq!(
+            set.k_nearest(&[0.9, 0.], 2),
+            vec![(&[1., 0.], &1), (&[0., 0.], &2)]
+        );
+        assert_eq!(set.k_nearest(&[0.9, 0.], usize::MAX).len(), 2);
+    }
+}
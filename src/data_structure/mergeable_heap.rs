@@ -0,0 +1,663 @@
+#[cfg(test)]
+mod unit_test;
+use crate::data_structure::Orientation;
+use std::cell::{Ref, RefCell};
+use std::fmt;
+use std::rc::{Rc, Weak};
+
+type Link<T> = Option<Rc<RefCell<Node<T>>>>;
+type WeakLink<T> = Option<Weak<RefCell<Node<T>>>>;
+
+struct Node<T> {
+    key: T,
+    // number of children and whether or not the node lost a child
+    // since it became a child itself (only used by the Fibonacci heap)
+    degree: usize,
+    marked: bool,
+    parent: WeakLink<T>,
+    children: List<T>,
+    // neighbours in the list of children of the parent (or in the list of roots)
+    next: Link<T>,
+    prev: WeakLink<T>,
+}
+fn new_node<T>(key: T) -> Rc<RefCell<Node<T>>> {
+    Rc::new(RefCell::new(Node {
+        key,
+        degree: 0,
+        marked: false,
+        parent: None,
+        children: List::new(),
+        next: None,
+        prev: None,
+    }))
+}
+
+// Doubly linked list of nodes, which owns the nodes through the next links
+struct List<T> {
+    head: Link<T>,
+    tail: WeakLink<T>,
+}
+impl<T> List<T> {
+    fn new() -> Self {
+        Self {
+            head: None,
+            tail: None,
+        }
+    }
+    fn push_front(&mut self, node: Rc<RefCell<Node<T>>>) {
+        match self.head.take() {
+            Some(head) => {
+                head.borrow_mut().prev = Some(Rc::downgrade(&node));
+                node.borrow_mut().next = Some(head);
+            }
+            None => self.tail = Some(Rc::downgrade(&node)),
+        }
+        node.borrow_mut().prev = None;
+        self.head = Some(node);
+    }
+    fn push_back(&mut self, node: Rc<RefCell<Node<T>>>) {
+        node.borrow_mut().next = None;
+        node.borrow_mut().prev = self.tail.clone();
+        let weak = Rc::downgrade(&node);
+        match self.tail.as_ref().and_then(Weak::upgrade) {
+            Some(tail) => tail.borrow_mut().next = Some(node),
+            None => self.head = Some(node),
+        }
+        self.tail = Some(weak);
+    }
+    fn remove(&mut self, node: &Rc<RefCell<Node<T>>>) {
+        let (prev, next) = {
+            let mut node = node.borrow_mut();
+            (
+                node.prev.take().and_then(|prev| prev.upgrade()),
+                node.next.take(),
+            )
+        };
+        match &next {
+            Some(next) => next.borrow_mut().prev = prev.as_ref().map(Rc::downgrade),
+            None => self.tail = prev.as_ref().map(Rc::downgrade),
+        }
+        match prev {
+            Some(prev) => prev.borrow_mut().next = next,
+            None => self.head = next,
+        }
+    }
+    fn pop_front(&mut self) -> Link<T> {
+        let head = self.head.clone()?;
+        self.remove(&head);
+        Some(head)
+    }
+    fn append(&mut self, other: &mut Self) {
+        // moves the nodes of the other list at the end of this list
+        if let Some(other_head) = other.head.take() {
+            match self.tail.as_ref().and_then(Weak::upgrade) {
+                Some(tail) => {
+                    other_head.borrow_mut().prev = Some(Rc::downgrade(&tail));
+                    tail.borrow_mut().next = Some(other_head);
+                }
+                None => self.head = Some(other_head),
+            }
+            self.tail = other.tail.take();
+        }
+    }
+}
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        // iterative drop, since lists and trees can be arbitrarily deep
+        let mut stack = self.head.take().into_iter().collect::<Vec<_>>();
+        while let Some(node) = stack.pop() {
+            let mut node = node.borrow_mut();
+            stack.extend(node.next.take());
+            stack.extend(node.children.head.take());
+        }
+    }
+}
+
+fn higher<T: Ord>(kind: &Orientation, a: &T, b: &T) -> bool {
+    // tests whether or not a has a strictly higher priority than b
+    match kind {
+        Orientation::Max => a > b,
+        Orientation::Min => a < b,
+    }
+}
+
+fn into_key<T>(node: Rc<RefCell<Node<T>>>) -> T {
+    match Rc::try_unwrap(node) {
+        Ok(node) => node.into_inner().key,
+        Err(_) => panic!("a deleted node should not be referenced by the heap"),
+    }
+}
+
+/// Handle on an object of a `PairingHeap` or a `FibonacciHeap`, returned when it is inserted
+/// and used to change its key. A handle remains valid when its heap is melded into another heap,
+/// and should only be used with the heap holding its object.
+///
+/// The objects live in nodes shared with their handles (`Rc<RefCell<_>>`), so that melding
+/// runs in O(1) and keeps the handles valid. As a consequence, unlike `PriorityQueue::extremum`,
+/// `extremum` returns a `Ref` guard instead of `&T`: it borrows the heap as a reference would,
+/// and `as_deref()` turns the result into an `Option<&T>`.
+pub struct HeapHandle<T>(Weak<RefCell<Node<T>>>);
+impl<T> Clone for HeapHandle<T> {
+    fn clone(&self) -> Self {
+        Self(Weak::clone(&self.0))
+    }
+}
+impl<T> fmt::Debug for HeapHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HeapHandle")
+            .field("deleted", &self.is_deleted())
+            .finish()
+    }
+}
+impl<T> HeapHandle<T> {
+    /// Tests whether or not the object of the handle was deleted from its heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let mut heap = PairingHeap::new(Orientation::Min);
+    /// let handle = heap.insert(1);
+    /// assert!(!handle.is_deleted());
+    /// heap.delete();
+    /// assert!(handle.is_deleted());
+    /// ```
+    pub fn is_deleted(&self) -> bool {
+        self.0.strong_count() == 0
+    }
+    fn node(&self) -> Rc<RefCell<Node<T>>> {
+        self.0
+            .upgrade()
+            .expect("the object of the handle was deleted")
+    }
+}
+
+/// Implementation of a pairing heap: a heap-ordered multiway tree, which supports
+/// melding two heaps and changing the key of an object through its handle.
+/// # Examples
+/// ```
+/// use algods::data_structure::{PairingHeap, Orientation};
+/// let mut heap = PairingHeap::new(Orientation::Min);
+/// heap.insert(5);
+/// let handle = heap.insert(8);
+/// let mut other = PairingHeap::new(Orientation::Min);
+/// other.insert(3);
+/// heap.meld(other);
+/// assert_eq!(heap.len(), 3);
+/// heap.decrease_key(&handle, 1);
+/// assert_eq!(heap.delete(), Some(1));
+/// assert_eq!(heap.delete(), Some(3));
+/// ```
+pub struct PairingHeap<T> {
+    root: Link<T>,
+    len: usize,
+    kind: Orientation,
+}
+impl<T> fmt::Debug for PairingHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PairingHeap")
+            .field("len", &self.len)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+impl<T: Ord> Default for PairingHeap<T> {
+    fn default() -> Self {
+        Self::new(Orientation::default())
+    }
+}
+impl<T: Ord> PairingHeap<T> {
+    /// Creates an empty heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let heap = PairingHeap::<usize>::new(Orientation::Max);
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new(k: Orientation) -> Self {
+        Self {
+            root: None,
+            len: 0,
+            kind: k,
+        }
+    }
+    /// Tests whether or not the heap is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let mut heap = PairingHeap::new(Orientation::Max);
+    /// heap.insert(1);
+    /// assert!(!heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Gives the number of objects in the heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let mut heap = PairingHeap::new(Orientation::Max);
+    /// heap.insert(1);
+    /// heap.insert(1);
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns the extremal (smallest in min oriented heap
+    /// and largest in max oriented heap) object in the heap, if any.
+    /// Returns `None` otherwise.
+    /// The object is borrowed through a `Ref` guard (see `HeapHandle`).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let mut heap = PairingHeap::new(Orientation::Max);
+    /// heap.insert(1);
+    /// heap.insert(2);
+    /// assert_eq!(heap.extremum().as_deref(), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn extremum(&self) -> Option<Ref<'_, T>> {
+        self.root
+            .as_ref()
+            .map(|root| Ref::map(root.borrow(), |node| &node.key))
+    }
+    fn link(&self, a: Rc<RefCell<Node<T>>>, b: Rc<RefCell<Node<T>>>) -> Rc<RefCell<Node<T>>> {
+        // the root with the lower priority becomes the first child of the other
+        let b_wins = higher(&self.kind, &b.borrow().key, &a.borrow().key);
+        let (winner, loser) = if b_wins { (b, a) } else { (a, b) };
+        loser.borrow_mut().parent = Some(Rc::downgrade(&winner));
+        winner.borrow_mut().children.push_front(loser);
+        winner
+    }
+    fn meld_root(&mut self, node: Rc<RefCell<Node<T>>>) {
+        self.root = Some(match self.root.take() {
+            Some(root) => self.link(root, node),
+            None => node,
+        });
+    }
+    /// Inserts an object into the heap and returns its handle.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let mut heap = PairingHeap::new(Orientation::Min);
+    /// heap.insert(2);
+    /// heap.insert(1);
+    /// assert_eq!(heap.extremum().as_deref(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn insert(&mut self, key: T) -> HeapHandle<T> {
+        let node = new_node(key);
+        let handle = HeapHandle(Rc::downgrade(&node));
+        self.meld_root(node);
+        self.len += 1;
+        handle
+    }
+    /// Deletes and returns the extremal (smallest in min oriented heap
+    /// and largest in max oriented heap) object in the heap, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let mut heap = PairingHeap::new(Orientation::Min);
+    /// heap.insert(2);
+    /// heap.insert(1);
+    /// assert_eq!(heap.delete(), Some(1));
+    /// assert_eq!(heap.delete(), Some(2));
+    /// assert_eq!(heap.delete(), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn delete(&mut self) -> Option<T> {
+        let root = self.root.take()?;
+        let mut subtrees = Vec::new();
+        while let Some(child) = root.borrow_mut().children.pop_front() {
+            child.borrow_mut().parent = None;
+            subtrees.push(child);
+        }
+        // two pass pairing: link the subtrees by pairs from left to right,
+        // then link the pairs from right to left
+        let mut pairs = Vec::with_capacity(subtrees.len() / 2 + 1);
+        let mut subtrees = subtrees.into_iter();
+        while let Some(first) = subtrees.next() {
+            pairs.push(match subtrees.next() {
+                Some(second) => self.link(first, second),
+                None => first,
+            });
+        }
+        self.root = pairs
+            .into_iter()
+            .rev()
+            .reduce(|acc, tree| self.link(tree, acc));
+        self.len -= 1;
+        Some(into_key(root))
+    }
+    /// Moves all the objects of another heap with the same orientation into this one.
+    /// The handles of the objects of the other heap remain valid in this heap.
+    /// # Panics
+    /// If the heaps do not have the same orientation, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let mut heap = PairingHeap::new(Orientation::Max);
+    /// heap.insert(1);
+    /// let mut other = PairingHeap::new(Orientation::Max);
+    /// other.insert(2);
+    /// heap.meld(other);
+    /// assert_eq!(heap.delete(), Some(2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn meld(&mut self, mut other: Self) {
+        check_orientations(&self.kind, &other.kind);
+        if let Some(root) = other.root.take() {
+            self.meld_root(root);
+        }
+        self.len += other.len;
+    }
+    /// Gives a higher priority to an object: its key becomes smaller in a min oriented heap
+    /// (larger in a max oriented heap).
+    /// # Panics
+    /// If the object of the handle was deleted or if the new key has a lower priority
+    /// than the current key, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PairingHeap, Orientation};
+    /// let mut heap = PairingHeap::new(Orientation::Max);
+    /// heap.insert(5);
+    /// let handle = heap.insert(1);
+    /// heap.decrease_key(&handle, 10);
+    /// assert_eq!(heap.extremum().as_deref(), Some(&10));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized o(log(N))
+    pub fn decrease_key(&mut self, handle: &HeapHandle<T>, key: T) {
+        let node = handle.node();
+        check_key(&self.kind, &node.borrow().key, &key);
+        node.borrow_mut().key = key;
+        let parent = node.borrow_mut().parent.take().and_then(|p| p.upgrade());
+        if let Some(parent) = parent {
+            // the subtree of the node is cut from its parent and melded with the root
+            parent.borrow_mut().children.remove(&node);
+            self.meld_root(node);
+        }
+    }
+}
+
+fn check_orientations(kind: &Orientation, other: &Orientation) {
+    match (kind, other) {
+        (Orientation::Max, Orientation::Max) | (Orientation::Min, Orientation::Min) => (),
+        _ => panic!("heaps should have the same orientation"),
+    }
+}
+
+fn check_key<T: Ord>(kind: &Orientation, key: &T, new_key: &T) {
+    if higher(kind, key, new_key) {
+        panic!("the new key should not have a lower priority than the current key");
+    }
+}
+
+/// Implementation of a Fibonacci heap: a collection of heap-ordered trees, which supports
+/// melding two heaps and changing the key of an object through its handle in amortized O(1).
+/// # Examples
+/// ```
+/// use algods::data_structure::{FibonacciHeap, Orientation};
+/// let mut heap = FibonacciHeap::new(Orientation::Min);
+/// heap.insert(5);
+/// let handle = heap.insert(8);
+/// let mut other = FibonacciHeap::new(Orientation::Min);
+/// other.insert(3);
+/// heap.meld(other);
+/// assert_eq!(heap.len(), 3);
+/// heap.decrease_key(&handle, 1);
+/// assert_eq!(heap.delete(), Some(1));
+/// assert_eq!(heap.delete(), Some(3));
+/// ```
+pub struct FibonacciHeap<T> {
+    roots: List<T>,
+    // root with the highest priority
+    best: Link<T>,
+    len: usize,
+    kind: Orientation,
+}
+impl<T> fmt::Debug for FibonacciHeap<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FibonacciHeap")
+            .field("len", &self.len)
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+impl<T: Ord> Default for FibonacciHeap<T> {
+    fn default() -> Self {
+        Self::new(Orientation::default())
+    }
+}
+impl<T: Ord> FibonacciHeap<T> {
+    /// Creates an empty heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FibonacciHeap, Orientation};
+    /// let heap = FibonacciHeap::<usize>::new(Orientation::Max);
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new(k: Orientation) -> Self {
+        Self {
+            roots: List::new(),
+            best: None,
+            len: 0,
+            kind: k,
+        }
+    }
+    /// Tests whether or not the heap is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FibonacciHeap, Orientation};
+    /// let mut heap = FibonacciHeap::new(Orientation::Max);
+    /// heap.insert(1);
+    /// assert!(!heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Gives the number of objects in the heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FibonacciHeap, Orientation};
+    /// let mut heap = FibonacciHeap::new(Orientation::Max);
+    /// heap.insert(1);
+    /// heap.insert(1);
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns the extremal (smallest in min oriented heap
+    /// and largest in max oriented heap) object in the heap, if any.
+    /// Returns `None` otherwise.
+    /// The object is borrowed through a `Ref` guard (see `HeapHandle`).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FibonacciHeap, Orientation};
+    /// let mut heap = FibonacciHeap::new(Orientation::Max);
+    /// heap.insert(1);
+    /// heap.insert(2);
+    /// assert_eq!(heap.extremum().as_deref(), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn extremum(&self) -> Option<Ref<'_, T>> {
+        self.best
+            .as_ref()
+            .map(|best| Ref::map(best.borrow(), |node| &node.key))
+    }
+    fn update_best(&mut self, node: &Rc<RefCell<Node<T>>>) {
+        let better = match &self.best {
+            Some(best) => higher(&self.kind, &node.borrow().key, &best.borrow().key),
+            None => true,
+        };
+        if better {
+            self.best = Some(Rc::clone(node));
+        }
+    }
+    fn add_root(&mut self, node: Rc<RefCell<Node<T>>>) {
+        {
+            let mut node = node.borrow_mut();
+            node.parent = None;
+            node.marked = false;
+        }
+        self.update_best(&node);
+        self.roots.push_back(node);
+    }
+    /// Inserts an object into the heap and returns its handle.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FibonacciHeap, Orientation};
+    /// let mut heap = FibonacciHeap::new(Orientation::Min);
+    /// heap.insert(2);
+    /// heap.insert(1);
+    /// assert_eq!(heap.extremum().as_deref(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn insert(&mut self, key: T) -> HeapHandle<T> {
+        let node = new_node(key);
+        let handle = HeapHandle(Rc::downgrade(&node));
+        self.add_root(node);
+        self.len += 1;
+        handle
+    }
+    fn link(&self, a: Rc<RefCell<Node<T>>>, b: Rc<RefCell<Node<T>>>) -> Rc<RefCell<Node<T>>> {
+        // the root with the lower priority becomes a child of the other
+        let b_wins = higher(&self.kind, &b.borrow().key, &a.borrow().key);
+        let (winner, loser) = if b_wins { (b, a) } else { (a, b) };
+        loser.borrow_mut().parent = Some(Rc::downgrade(&winner));
+        let mut winner_node = winner.borrow_mut();
+        winner_node.children.push_back(loser);
+        winner_node.degree += 1;
+        drop(winner_node);
+        winner
+    }
+    fn consolidate(&mut self) {
+        // links roots of the same degree until all roots have distinct degrees
+        let mut by_degree: Vec<Link<T>> = Vec::new();
+        while let Some(mut tree) = self.roots.pop_front() {
+            loop {
+                let degree = tree.borrow().degree;
+                if by_degree.len() <= degree {
+                    by_degree.resize(degree + 1, None);
+                }
+                match by_degree[degree].take() {
+                    Some(other) => tree = self.link(tree, other),
+                    None => {
+                        by_degree[degree] = Some(tree);
+                        break;
+                    }
+                }
+            }
+        }
+        for tree in by_degree.into_iter().flatten() {
+            self.add_root(tree);
+        }
+    }
+    /// Deletes and returns the extremal (smallest in min oriented heap
+    /// and largest in max oriented heap) object in the heap, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FibonacciHeap, Orientation};
+    /// let mut heap = FibonacciHeap::new(Orientation::Min);
+    /// heap.insert(2);
+    /// heap.insert(1);
+    /// assert_eq!(heap.delete(), Some(1));
+    /// assert_eq!(heap.delete(), Some(2));
+    /// assert_eq!(heap.delete(), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn delete(&mut self) -> Option<T> {
+        let best = self.best.take()?;
+        self.roots.remove(&best);
+        while let Some(child) = best.borrow_mut().children.pop_front() {
+            self.add_root(child);
+        }
+        self.best = None;
+        self.consolidate();
+        self.len -= 1;
+        Some(into_key(best))
+    }
+    /// Moves all the objects of another heap with the same orientation into this one.
+    /// The handles of the objects of the other heap remain valid in this heap.
+    /// # Panics
+    /// If the heaps do not have the same orientation, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FibonacciHeap, Orientation};
+    /// let mut heap = FibonacciHeap::new(Orientation::Max);
+    /// heap.insert(1);
+    /// let mut other = FibonacciHeap::new(Orientation::Max);
+    /// other.insert(2);
+    /// heap.meld(other);
+    /// assert_eq!(heap.delete(), Some(2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn meld(&mut self, mut other: Self) {
+        check_orientations(&self.kind, &other.kind);
+        self.roots.append(&mut other.roots);
+        if let Some(best) = other.best.take() {
+            self.update_best(&best);
+        }
+        self.len += other.len;
+    }
+    fn cut(&mut self, node: &Rc<RefCell<Node<T>>>, parent: &Rc<RefCell<Node<T>>>) {
+        // moves the subtree of the node to the list of roots
+        let mut parent_node = parent.borrow_mut();
+        parent_node.children.remove(node);
+        parent_node.degree -= 1;
+        drop(parent_node);
+        self.add_root(Rc::clone(node));
+    }
+    /// Gives a higher priority to an object: its key becomes smaller in a min oriented heap
+    /// (larger in a max oriented heap).
+    /// # Panics
+    /// If the object of the handle was deleted or if the new key has a lower priority
+    /// than the current key, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{FibonacciHeap, Orientation};
+    /// let mut heap = FibonacciHeap::new(Orientation::Max);
+    /// heap.insert(5);
+    /// let handle = heap.insert(1);
+    /// heap.decrease_key(&handle, 10);
+    /// assert_eq!(heap.extremum().as_deref(), Some(&10));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(1)
+    pub fn decrease_key(&mut self, handle: &HeapHandle<T>, key: T) {
+        let node = handle.node();
+        check_key(&self.kind, &node.borrow().key, &key);
+        node.borrow_mut().key = key;
+        let parent = node.borrow().parent.as_ref().and_then(Weak::upgrade);
+        match parent {
+            Some(parent) => {
+                if higher(&self.kind, &node.borrow().key, &parent.borrow().key) {
+                    self.cut(&node, &parent);
+                    // cascading cut: marked ancestors, which already lost a child, are cut too
+                    let mut node = parent;
+                    loop {
+                        let parent = node.borrow().parent.as_ref().and_then(Weak::upgrade);
+                        let Some(parent) = parent else { break };
+                        if !node.borrow().marked {
+                            node.borrow_mut().marked = true;
+                            break;
+                        }
+                        self.cut(&node, &parent);
+                        node = parent;
+                    }
+                }
+            }
+            None => self.update_best(&node),
+        }
+    }
+}
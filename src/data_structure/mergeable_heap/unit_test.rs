@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    // common surface of the mergeable heaps, to test them with the same scenarios
+    trait Mergeable: Sized {
+        fn new(k: Orientation) -> Self;
+        fn len(&self) -> usize;
+        fn extremum(&self) -> Option<isize>;
+        fn insert(&mut self, key: isize) -> HeapHandle<isize>;
+        fn delete(&mut self) -> Option<isize>;
+        fn meld(&mut self, other: Self);
+        fn decrease_key(&mut self, handle: &HeapHandle<isize>, key: isize);
+    }
+    macro_rules! impl_mergeable {
+        ($heap:ident) => {
+            impl Mergeable for $heap<isize> {
+                fn new(k: Orientation) -> Self {
+                    $heap::new(k)
+                }
+                fn len(&self) -> usize {
+                    $heap::len(self)
+                }
+                fn extremum(&self) -> Option<isize> {
+                    $heap::extremum(self).map(|key| *key)
+                }
+                fn insert(&mut self, key: isize) -> HeapHandle<isize> {
+                    $heap::insert(self, key)
+                }
+                fn delete(&mut self) -> Option<isize> {
+                    $heap::delete(self)
+                }
+                fn meld(&mut self, other: Self) {
+                    $heap::meld(self, other)
+                }
+                fn decrease_key(&mut self, handle: &HeapHandle<isize>, key: isize) {
+                    $heap::decrease_key(self, handle, key)
+                }
+            }
+        };
+    }
+    impl_mergeable!(PairingHeap);
+    impl_mergeable!(FibonacciHeap);
+
+    fn remove(reference: &mut BTreeMap<isize, usize>, key: isize) {
+        let count = reference.get_mut(&key).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            reference.remove(&key);
+        }
+    }
+
+    fn check_against_brute_force<H: Mergeable>(seed: u64) {
+        // min oriented heap against a multiset of keys
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut heap = H::new(Orientation::Min);
+        let mut reference = BTreeMap::<isize, usize>::new();
+        let mut handles = Vec::new();
+        for _ in 0..5000 {
+            match rng.gen_range(0..5) {
+                0 | 1 => {
+                    let key = rng.gen_range(-1000..1000);
+                    handles.push((heap.insert(key), key));
+                    *reference.entry(key).or_insert(0) += 1;
+                }
+                2 => {
+                    let expected = reference.keys().next().copied();
+                    assert_eq!(heap.delete(), expected);
+                    if let Some(key) = expected {
+                        remove(&mut reference, key);
+                    }
+                }
+                3 => {
+                    handles.retain(|(handle, _)| !handle.is_deleted());
+                    if !handles.is_empty() {
+                        let index = rng.gen_range(0..handles.len());
+                        let (handle, key) = handles[index].clone();
+                        let new_key = key - rng.gen_range(0..500);
+                        heap.decrease_key(&handle, new_key);
+                        handles[index].1 = new_key;
+                        remove(&mut reference, key);
+                        *reference.entry(new_key).or_insert(0) += 1;
+                    }
+                }
+                _ => {
+                    let mut other = H::new(Orientation::Min);
+                    for _ in 0..rng.gen_range(0..5) {
+                        let key = rng.gen_range(-1000..1000);
+                        handles.push((other.insert(key), key));
+                        *reference.entry(key).or_insert(0) += 1;
+                    }
+                    heap.meld(other);
+                }
+            }
+            assert_eq!(heap.len(), reference.values().sum::<usize>());
+            assert_eq!(heap.extremum(), reference.keys().next().copied());
+        }
+        let mut sorted = Vec::new();
+        while let Some(key) = heap.delete() {
+            sorted.push(key);
+        }
+        let expected = reference
+            .into_iter()
+            .flat_map(|(key, count)| std::iter::repeat_n(key, count))
+            .collect::<Vec<isize>>();
+        assert_eq!(sorted, expected);
+        assert!(handles.iter().all(|(handle, _)| handle.is_deleted()));
+    }
+
+    #[test]
+    fn test_pairing_heap_against_brute_force() {
+        check_against_brute_force::<PairingHeap<isize>>(0);
+    }
+
+    #[test]
+    fn test_fibonacci_heap_against_brute_force() {
+        check_against_brute_force::<FibonacciHeap<isize>>(1);
+    }
+
+    fn check_max_oriented<H: Mergeable>() {
+        let mut heap = H::new(Orientation::Max);
+        let handles = (0..10).map(|k| heap.insert(k)).collect::<Vec<_>>();
+        heap.decrease_key(&handles[3], 20);
+        assert_eq!(heap.delete(), Some(20));
+        assert_eq!(heap.delete(), Some(9));
+        // a larger key is a higher priority in a max oriented heap
+        heap.decrease_key(&handles[0], 15);
+        assert_eq!(heap.extremum(), Some(15));
+        assert_eq!(heap.len(), 8);
+    }
+
+    #[test]
+    fn test_max_oriented_heaps() {
+        check_max_oriented::<PairingHeap<isize>>();
+        check_max_oriented::<FibonacciHeap<isize>>();
+    }
+
+    fn check_meld_many<H: Mergeable>() {
+        let mut heaps = (0..100)
+            .map(|worker| {
+                let mut heap = H::new(Orientation::Min);
+                for k in 0..10 {
+                    heap.insert(100 * k + worker);
+                }
+                heap
+            })
+            .collect::<Vec<H>>();
+        let mut heap = heaps.pop().unwrap();
+        for other in heaps {
+            heap.meld(other);
+        }
+        assert_eq!(heap.len(), 1000);
+        for k in 0..1000 {
+            assert_eq!(heap.delete(), Some(k));
+        }
+        assert_eq!(heap.delete(), None);
+    }
+
+    #[test]
+    fn test_meld_many_heaps() {
+        check_meld_many::<PairingHeap<isize>>();
+        check_meld_many::<FibonacciHeap<isize>>();
+    }
+
+    #[test]
+    fn test_drop_deep_heaps() {
+        // each insertion makes the previous root a child of the new one
+        let mut pairing = PairingHeap::new(Orientation::Min);
+        for k in (0..1_000_000).rev() {
+            pairing.insert(k);
+        }
+        drop(pairing);
+        let mut fibonacci = FibonacciHeap::new(Orientation::Min);
+        for k in 0..100_000 {
+            fibonacci.insert(k);
+        }
+        fibonacci.delete();
+        drop(fibonacci);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decrease_key_wrong_direction() {
+        let mut heap = FibonacciHeap::new(Orientation::Min);
+        let handle = heap.insert(1);
+        heap.decrease_key(&handle, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_decrease_key_deleted() {
+        let mut heap = PairingHeap::new(Orientation::Min);
+        let handle = heap.insert(1);
+        heap.delete();
+        heap.decrease_key(&handle, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_meld_orientations() {
+        let mut heap = PairingHeap::<usize>::new(Orientation::Min);
+        heap.meld(PairingHeap::new(Orientation::Max));
+    }
+}
@@ -0,0 +1,309 @@
+#[cfg(test)]
+mod unit_test;
+use crate::data_structure::Orientation;
+
+/// Implementation of a min-max heap: a double ended priority queue giving access
+/// to both its smallest and its largest objects.
+/// # Examples
+/// ```
+/// use algods::data_structure::{MinMaxHeap, Orientation};
+/// let mut heap = MinMaxHeap::new();
+/// for k in [5, 1, 8, 3, 9] {
+///     heap.insert(k);
+/// }
+/// assert_eq!(heap.min(), Some(&1));
+/// assert_eq!(heap.max(), Some(&9));
+/// assert_eq!(heap.delete_max(), Some(9));
+/// assert_eq!(heap.delete_min(), Some(1));
+/// // the orientation is chosen for each operation
+/// assert_eq!(heap.extremum(Orientation::Max), Some(&8));
+/// assert_eq!(heap.delete(Orientation::Min), Some(3));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MinMaxHeap<T> {
+    // vector of objects
+    vec: Vec<T>,
+    // Remarks:
+    // - objects are stored as in a binary heap, from index = 0 so that:
+    //     - each node k's parent is at position (k-1)/2
+    //     - each node k's children are at positions 2k+1 and 2k+2
+    // - nodes at even depth (min levels, the root is at depth 0) are smaller
+    //   than their descendants and nodes at odd depth (max levels) are larger
+    //   than their descendants
+}
+impl<T: Ord> Default for MinMaxHeap<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Ord> MinMaxHeap<T> {
+    /// Creates an empty heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let heap = MinMaxHeap::<usize>::new();
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+    /// Creates an empty heap with an initial capacity.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let heap = MinMaxHeap::<usize>::with_capacity(10);
+    /// assert!(heap.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: Vec::with_capacity(capacity),
+        }
+    }
+    /// Creates a heap from a `Vec` of objects.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let heap = MinMaxHeap::from_vec(vec![3, 1, 4, 1, 5]);
+    /// assert_eq!(heap.min(), Some(&1));
+    /// assert_eq!(heap.max(), Some(&5));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let mut heap = Self { vec };
+        for node in (0..heap.vec.len() / 2).rev() {
+            heap.trickle_down(node);
+        }
+        heap
+    }
+    /// Tests whether or not the heap is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// heap.insert(1);
+    /// assert!(!heap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+    /// Gives the number of objects in the heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let heap = MinMaxHeap::from_vec(vec![1, 2]);
+    /// assert_eq!(heap.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+    /// Returns the smallest object in the heap, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let heap = MinMaxHeap::from_vec(vec![2, 1, 3]);
+    /// assert_eq!(heap.min(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn min(&self) -> Option<&T> {
+        self.vec.first()
+    }
+    fn max_index(&self) -> Option<usize> {
+        // the largest object is the root or one of its children
+        match self.vec.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ => Some(if self.vec[1] >= self.vec[2] { 1 } else { 2 }),
+        }
+    }
+    /// Returns the largest object in the heap, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let heap = MinMaxHeap::from_vec(vec![2, 1, 3]);
+    /// assert_eq!(heap.max(), Some(&3));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn max(&self) -> Option<&T> {
+        self.max_index().map(|index| &self.vec[index])
+    }
+    /// Returns the smallest (with `Orientation::Min`) or the largest (with `Orientation::Max`)
+    /// object in the heap, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{MinMaxHeap, Orientation};
+    /// let heap = MinMaxHeap::from_vec(vec![2, 1, 3]);
+    /// assert_eq!(heap.extremum(Orientation::Min), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn extremum(&self, kind: Orientation) -> Option<&T> {
+        match kind {
+            Orientation::Min => self.min(),
+            Orientation::Max => self.max(),
+        }
+    }
+    fn is_min_level(index: usize) -> bool {
+        // the depth of node k is floor(log2(k + 1))
+        (index + 1).ilog2() & 1 == 0
+    }
+    fn better(&self, i: usize, j: usize, min: bool) -> bool {
+        // tests whether or not the object at position i is strictly
+        // smaller (on min levels) or larger (on max levels) than the object at position j
+        if min {
+            self.vec[i] < self.vec[j]
+        } else {
+            self.vec[i] > self.vec[j]
+        }
+    }
+    fn bubble_up(&mut self, index: usize) {
+        if index == 0 {
+            return;
+        }
+        let parent = (index - 1) / 2;
+        let min = Self::is_min_level(index);
+        if self.better(parent, index, min) {
+            // the object belongs to the levels of the other kind
+            self.vec.swap(index, parent);
+            self.bubble_up_grandparents(parent, !min);
+        } else {
+            self.bubble_up_grandparents(index, min);
+        }
+    }
+    fn bubble_up_grandparents(&mut self, mut index: usize, min: bool) {
+        while index > 2 {
+            let grandparent = ((index - 1) / 2 - 1) / 2;
+            if !self.better(index, grandparent, min) {
+                break;
+            }
+            self.vec.swap(index, grandparent);
+            index = grandparent;
+        }
+    }
+    fn trickle_down(&mut self, mut index: usize) {
+        let min = Self::is_min_level(index);
+        let n = self.vec.len();
+        loop {
+            // find the best object among the children and grandchildren
+            let first_child = 2 * index + 1;
+            if first_child >= n {
+                break;
+            }
+            let mut best = first_child;
+            let candidates = [
+                first_child + 1,
+                2 * first_child + 1,
+                2 * first_child + 2,
+                2 * first_child + 3,
+                2 * first_child + 4,
+            ];
+            for candidate in candidates.into_iter().filter(|c| *c < n) {
+                if self.better(candidate, best, min) {
+                    best = candidate;
+                }
+            }
+            if !self.better(best, index, min) {
+                break;
+            }
+            self.vec.swap(best, index);
+            if best <= first_child + 1 {
+                // a child has no descendant of the same kind below the index
+                break;
+            }
+            // a grandchild may now be in the wrong order with its parent
+            let parent = (best - 1) / 2;
+            if self.better(parent, best, min) {
+                self.vec.swap(best, parent);
+            }
+            index = best;
+        }
+    }
+    /// Inserts an object into the heap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::new();
+    /// heap.insert(2);
+    /// heap.insert(1);
+    /// assert_eq!(heap.min(), Some(&1));
+    /// assert_eq!(heap.max(), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn insert(&mut self, key: T) {
+        self.vec.push(key);
+        self.bubble_up(self.vec.len() - 1);
+    }
+    fn delete_at(&mut self, index: usize) -> T {
+        let res = self.vec.swap_remove(index);
+        if index < self.vec.len() {
+            self.trickle_down(index);
+        }
+        res
+    }
+    /// Deletes and returns the smallest object in the heap, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::from_vec(vec![2, 1, 3]);
+    /// assert_eq!(heap.delete_min(), Some(1));
+    /// assert_eq!(heap.min(), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn delete_min(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.delete_at(0))
+        }
+    }
+    /// Deletes and returns the largest object in the heap, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let mut heap = MinMaxHeap::from_vec(vec![2, 1, 3]);
+    /// assert_eq!(heap.delete_max(), Some(3));
+    /// assert_eq!(heap.max(), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn delete_max(&mut self) -> Option<T> {
+        self.max_index().map(|index| self.delete_at(index))
+    }
+    /// Deletes and returns the smallest (with `Orientation::Min`) or the largest
+    /// (with `Orientation::Max`) object in the heap, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{MinMaxHeap, Orientation};
+    /// let mut heap = MinMaxHeap::from_vec(vec![2, 1, 3]);
+    /// assert_eq!(heap.delete(Orientation::Max), Some(3));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn delete(&mut self, kind: Orientation) -> Option<T> {
+        match kind {
+            Orientation::Min => self.delete_min(),
+            Orientation::Max => self.delete_max(),
+        }
+    }
+    /// Returns the objects of the heap in increasing order.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MinMaxHeap;
+    /// let heap = MinMaxHeap::from_vec(vec![2, 3, 1]);
+    /// assert_eq!(heap.into_sorted_vec(), vec![1, 2, 3]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N log(N))
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        let mut vec = Vec::with_capacity(self.len());
+        while let Some(key) = self.delete_min() {
+            vec.push(key);
+        }
+        vec
+    }
+}
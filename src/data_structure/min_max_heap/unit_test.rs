@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    fn check_heap(heap: &MinMaxHeap<isize>) {
+        // every node is smaller (min level) or larger (max level) than its descendants
+        for k in 1..heap.len() {
+            let mut ancestor = (k - 1) / 2;
+            loop {
+                if MinMaxHeap::<isize>::is_min_level(ancestor) {
+                    assert!(heap.vec[ancestor] <= heap.vec[k]);
+                } else {
+                    assert!(heap.vec[ancestor] >= heap.vec[k]);
+                }
+                if ancestor == 0 {
+                    break;
+                }
+                ancestor = (ancestor - 1) / 2;
+            }
+        }
+    }
+
+    #[test]
+    fn test_min_max_heap_levels() {
+        let levels = (0..8)
+            .map(MinMaxHeap::<isize>::is_min_level)
+            .collect::<Vec<bool>>();
+        assert_eq!(
+            levels,
+            vec![true, false, false, true, true, true, true, false]
+        );
+    }
+
+    #[test]
+    fn test_min_max_heap_from_vec() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for len in 0..50 {
+            let vec = (0..len)
+                .map(|_| rng.gen_range(-20..20))
+                .collect::<Vec<isize>>();
+            let mut sorted = vec.clone();
+            sorted.sort();
+            let heap = MinMaxHeap::from_vec(vec);
+            check_heap(&heap);
+            assert_eq!(heap.min(), sorted.first());
+            assert_eq!(heap.max(), sorted.last());
+            assert_eq!(heap.into_sorted_vec(), sorted);
+        }
+    }
+
+    #[test]
+    fn test_min_max_heap_against_brute_force() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut heap = MinMaxHeap::new();
+        // multiset of the objects with their number of occurrences
+        let mut reference = BTreeMap::<isize, usize>::new();
+        let mut len = 0;
+        for _ in 0..10_000 {
+            match rng.gen_range(0..4) {
+                0 | 1 => {
+                    let key = rng.gen_range(-200..200);
+                    heap.insert(key);
+                    *reference.entry(key).or_insert(0) += 1;
+                    len += 1;
+                }
+                2 => {
+                    let expected = reference.keys().next().copied();
+                    assert_eq!(heap.delete(Orientation::Min), expected);
+                    if let Some(key) = expected {
+                        remove(&mut reference, key);
+                        len -= 1;
+                    }
+                }
+                _ => {
+                    let expected = reference.keys().next_back().copied();
+                    assert_eq!(heap.delete(Orientation::Max), expected);
+                    if let Some(key) = expected {
+                        remove(&mut reference, key);
+                        len -= 1;
+                    }
+                }
+            }
+            assert_eq!(heap.len(), len);
+            assert_eq!(heap.extremum(Orientation::Min), reference.keys().next());
+            assert_eq!(
+                heap.extremum(Orientation::Max),
+                reference.keys().next_back()
+            );
+        }
+        check_heap(&heap);
+    }
+
+    fn remove(reference: &mut BTreeMap<isize, usize>, key: isize) {
+        let count = reference.get_mut(&key).unwrap();
+        *count -= 1;
+        if *count == 0 {
+            reference.remove(&key);
+        }
+    }
+
+    #[test]
+    fn test_min_max_heap_empty() {
+        let mut heap = MinMaxHeap::<usize>::default();
+        assert_eq!(heap.min(), None);
+        assert_eq!(heap.max(), None);
+        assert_eq!(heap.delete_min(), None);
+        assert_eq!(heap.delete_max(), None);
+    }
+}
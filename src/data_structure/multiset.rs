@@ -0,0 +1,409 @@
+#[cfg(test)]
+mod unit_test;
+use std::cmp::Reverse;
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+
+/// Implementation of a bag: a collection to which objects can only be added,
+/// and whose objects can be iterated over (in insertion order).
+/// # Examples
+/// ```
+/// use algods::data_structure::Bag;
+/// let mut bag = Bag::new();
+/// bag.add("a");
+/// bag.add("b");
+/// bag.add("a");
+/// assert_eq!(bag.len(), 3);
+/// assert_eq!(bag.iter().filter(|word| **word == "a").count(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bag<T> {
+    vec: Vec<T>,
+}
+impl<T> Default for Bag<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> Bag<T> {
+    /// Creates an empty bag.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Bag;
+    /// let bag = Bag::<usize>::new();
+    /// assert!(bag.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { vec: Vec::new() }
+    }
+    /// Creates an empty bag with room for `capacity` objects.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Bag;
+    /// let bag = Bag::<usize>::with_capacity(10);
+    /// assert!(bag.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            vec: Vec::with_capacity(capacity),
+        }
+    }
+    /// Tests whether or not the bag is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Bag;
+    /// let mut bag = Bag::new();
+    /// bag.add(1);
+    /// assert!(!bag.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+    /// Gives the number of objects in the bag.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Bag;
+    /// let mut bag = Bag::new();
+    /// bag.add(1);
+    /// bag.add(1);
+    /// assert_eq!(bag.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+    /// Adds an object to the bag.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Bag;
+    /// let mut bag = Bag::new();
+    /// bag.add(1);
+    /// assert_eq!(bag.len(), 1);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) (amortized)
+    pub fn add(&mut self, item: T) {
+        self.vec.push(item);
+    }
+    /// Returns an iterator over the objects of the bag, in insertion order.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Bag;
+    /// let bag = Bag::from_iter([1, 2, 1]);
+    /// assert_eq!(bag.iter().sum::<usize>(), 4);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.vec.iter()
+    }
+}
+impl<T> FromIterator<T> for Bag<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            vec: Vec::from_iter(iter),
+        }
+    }
+}
+impl<T> Extend<T> for Bag<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.vec.extend(iter);
+    }
+}
+impl<'a, T> IntoIterator for &'a Bag<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+impl<T> IntoIterator for Bag<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+/// Implementation of a multiset: a set in which each object may occur several times,
+/// represented by the number of occurrences (count) of each distinct object.
+/// # Examples
+/// ```
+/// use algods::data_structure::MultiSet;
+/// let words = MultiSet::from_iter("the cat and the dog and the bird".split(' '));
+/// assert_eq!(words.len(), 8);
+/// assert_eq!(words.count(&"the"), 3);
+/// assert_eq!(words.most_common(2), vec![(&"the", 3), (&"and", 2)]);
+/// let other = MultiSet::from_iter(["the", "cat", "cat"]);
+/// assert_eq!(words.intersection(&other).len(), 2);
+/// assert_eq!(words.difference(&other).count(&"the"), 2);
+/// assert_eq!(words.sum(&other).count(&"cat"), 3);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiSet<T: Hash + Eq> {
+    // number of occurrences of each object, always > 0
+    counts: HashMap<T, usize>,
+    // total number of occurrences
+    len: usize,
+}
+impl<T: Hash + Eq> Default for MultiSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Hash + Eq> MultiSet<T> {
+    /// Creates an empty multiset.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::<usize>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            counts: HashMap::new(),
+            len: 0,
+        }
+    }
+    /// Tests whether or not the multiset is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let mut set = MultiSet::new();
+    /// set.insert(1);
+    /// assert!(!set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Gives the number of objects in the multiset, counted with their multiplicity.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter([1, 1, 2]);
+    /// assert_eq!(set.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Gives the number of distinct objects in the multiset.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter([1, 1, 2]);
+    /// assert_eq!(set.nb_distinct(), 2);
+    /// ```
+    pub fn nb_distinct(&self) -> usize {
+        self.counts.len()
+    }
+    /// Adds `count` occurrences of an object to the multiset.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let mut set = MultiSet::new();
+    /// set.add("a", 3);
+    /// assert_eq!(set.count(&"a"), 3);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn add(&mut self, item: T, count: usize) {
+        if count > 0 {
+            *self.counts.entry(item).or_insert(0) += count;
+            self.len += count;
+        }
+    }
+    /// Adds one occurrence of an object to the multiset.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let mut set = MultiSet::new();
+    /// set.insert("a");
+    /// set.insert("a");
+    /// assert_eq!(set.count(&"a"), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn insert(&mut self, item: T) {
+        self.add(item, 1);
+    }
+    /// Removes one occurrence of an object from the multiset
+    /// and tells whether or not there was one.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let mut set = MultiSet::from_iter(["a", "a"]);
+    /// assert!(set.remove_one(&"a"));
+    /// assert_eq!(set.count(&"a"), 1);
+    /// assert!(!set.remove_one(&"b"));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn remove_one(&mut self, item: &T) -> bool {
+        match self.counts.get_mut(item) {
+            Some(count) => {
+                *count -= 1;
+                if *count == 0 {
+                    self.counts.remove(item);
+                }
+                self.len -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+    /// Removes all the occurrences of an object from the multiset and returns their number.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let mut set = MultiSet::from_iter(["a", "a", "b"]);
+    /// assert_eq!(set.remove_all(&"a"), 2);
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn remove_all(&mut self, item: &T) -> usize {
+        let count = self.counts.remove(item).unwrap_or(0);
+        self.len -= count;
+        count
+    }
+    /// Gives the number of occurrences of an object in the multiset.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter(["a", "a", "b"]);
+    /// assert_eq!(set.count(&"a"), 2);
+    /// assert_eq!(set.count(&"c"), 0);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn count(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+    /// Tests whether or not the multiset contains an object.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter(["a"]);
+    /// assert!(set.contains(&"a"));
+    /// ```
+    pub fn contains(&self, item: &T) -> bool {
+        self.counts.contains_key(item)
+    }
+    /// Returns an iterator over the distinct objects of the multiset and their counts,
+    /// in arbitrary order.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter(["a", "a"]);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), vec![(&"a", 2)]);
+    /// ```
+    pub fn iter(&self) -> MultiSetIter<'_, T> {
+        MultiSetIter {
+            iter: self.counts.iter(),
+        }
+    }
+    /// Returns the (at most) `k` most common objects and their counts,
+    /// by decreasing count (ties are in arbitrary order).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter("a b a c a b".split(' '));
+    /// assert_eq!(set.most_common(2), vec![(&"a", 3), (&"b", 2)]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D log(D)) where D is the number of distinct objects
+    pub fn most_common(&self, k: usize) -> Vec<(&T, usize)> {
+        let mut counts = self.iter().collect::<Vec<_>>();
+        counts.sort_by_key(|(_, count)| Reverse(*count));
+        counts.truncate(k);
+        counts
+    }
+}
+impl<T: Hash + Eq + Clone> MultiSet<T> {
+    fn combine<F: Fn(usize, usize) -> usize>(&self, other: &Self, op: F) -> Self {
+        // applies op to the counts of each object of either multiset
+        let mut set = Self::new();
+        for item in self.counts.keys().chain(other.counts.keys()) {
+            if !set.contains(item) {
+                set.add(item.clone(), op(self.count(item), other.count(item)));
+            }
+        }
+        set
+    }
+    /// Returns the multiset whose counts are the sums of the counts of both multisets.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter(["a", "a", "b"]);
+    /// let other = MultiSet::from_iter(["a", "c"]);
+    /// let sum = set.sum(&other);
+    /// assert_eq!(sum.count(&"a"), 3);
+    /// assert_eq!(sum.len(), 5);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D) where D is the number of distinct objects
+    pub fn sum(&self, other: &Self) -> Self {
+        self.combine(other, |a, b| a + b)
+    }
+    /// Returns the multiset whose counts are the minimum of the counts of both multisets.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter(["a", "a", "b"]);
+    /// let other = MultiSet::from_iter(["a", "c"]);
+    /// let intersection = set.intersection(&other);
+    /// assert_eq!(intersection.count(&"a"), 1);
+    /// assert_eq!(intersection.len(), 1);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D) where D is the number of distinct objects
+    pub fn intersection(&self, other: &Self) -> Self {
+        self.combine(other, usize::min)
+    }
+    /// Returns the multiset whose counts are the counts of the multiset
+    /// minus those of the other one (or 0 if negative).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::MultiSet;
+    /// let set = MultiSet::from_iter(["a", "a", "b"]);
+    /// let other = MultiSet::from_iter(["a", "c"]);
+    /// let difference = set.difference(&other);
+    /// assert_eq!(difference.count(&"a"), 1);
+    /// assert_eq!(difference.len(), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(D) where D is the number of distinct objects
+    pub fn difference(&self, other: &Self) -> Self {
+        self.combine(other, usize::saturating_sub)
+    }
+}
+impl<T: Hash + Eq> FromIterator<T> for MultiSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+impl<T: Hash + Eq> Extend<T> for MultiSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+impl<'a, T: Hash + Eq> IntoIterator for &'a MultiSet<T> {
+    type Item = (&'a T, usize);
+    type IntoIter = MultiSetIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the distinct objects of a `MultiSet` and their counts.
+#[derive(Debug)]
+pub struct MultiSetIter<'a, T> {
+    iter: hash_map::Iter<'a, T, usize>,
+}
+impl<'a, T> Iterator for MultiSetIter<'a, T> {
+    type Item = (&'a T, usize);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(item, count)| (item, *count))
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_bag() {
+        let mut bag = Bag::with_capacity(2);
+        assert!(bag.is_empty());
+        bag.extend([3, 1]);
+        bag.add(3);
+        assert_eq!(bag.len(), 3);
+        assert_eq!(bag.iter().collect::<Vec<_>>(), vec![&3, &1, &3]);
+        assert_eq!(bag.into_iter().collect::<Vec<_>>(), vec![3, 1, 3]);
+    }
+
+    #[test]
+    fn test_multiset_random_operations() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut set = MultiSet::new();
+        let mut counts = [0usize; 10];
+        for _ in 0..2000 {
+            let item = rng.gen_range(0..10);
+            if rng.gen_bool(0.6) {
+                set.insert(item);
+                counts[item] += 1;
+            } else {
+                assert_eq!(set.remove_one(&item), counts[item] > 0);
+                counts[item] = counts[item].saturating_sub(1);
+            }
+            assert_eq!(set.len(), counts.iter().sum::<usize>());
+            assert_eq!(set.nb_distinct(), counts.iter().filter(|c| **c > 0).count());
+        }
+        for (item, count) in counts.iter().enumerate() {
+            assert_eq!(set.count(&item), *count);
+            assert_eq!(set.contains(&item), *count > 0);
+        }
+        let most_common = set.most_common(3);
+        let mut sorted = counts.to_vec();
+        sorted.sort_by_key(|c| std::cmp::Reverse(*c));
+        assert_eq!(
+            most_common.iter().map(|(_, c)| *c).collect::<Vec<_>>(),
+            sorted[..3]
+        );
+        assert!(most_common.iter().all(|(item, c)| counts[**item] == *c));
+        assert_eq!(set.remove_all(&0), counts[0]);
+        assert_eq!(set.count(&0), 0);
+    }
+
+    #[test]
+    fn test_multiset_algebra() {
+        let set = MultiSet::from_iter([1, 1, 1, 2, 3, 3]);
+        let other = MultiSet::from_iter([1, 2, 2, 4]);
+        let sum = set.sum(&other);
+        let intersection = set.intersection(&other);
+        let difference = set.difference(&other);
+        for (item, expected) in [
+            (1, (4, 1, 2)),
+            (2, (3, 1, 0)),
+            (3, (2, 0, 2)),
+            (4, (1, 0, 0)),
+        ] {
+            assert_eq!(sum.count(&item), expected.0);
+            assert_eq!(intersection.count(&item), expected.1);
+            assert_eq!(difference.count(&item), expected.2);
+        }
+        assert_eq!(sum.len(), 10);
+        assert_eq!(intersection.len(), 2);
+        assert_eq!(difference.len(), 4);
+        // objects whose count drops to 0 are not kept
+        assert!(!difference.contains(&2));
+        assert_eq!(difference.nb_distinct(), 2);
+        assert!(set.difference(&set).is_empty());
+    }
+}
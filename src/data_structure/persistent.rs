@@ -0,0 +1,461 @@
+#[cfg(test)]
+mod unit_test;
+use std::cell::{OnceCell, RefCell};
+use std::fmt;
+use std::rc::Rc;
+
+#[derive(Debug)]
+struct StackNode<T> {
+    item: T,
+    next: Option<Rc<StackNode<T>>>,
+}
+
+/// Implementation of a persistent (immutable) stack: a cons list whose nodes are shared
+/// between versions. `push` and `pop` return a new version in O(1) and leave the
+/// previous versions valid, so that keeping a snapshot is as cheap as a `clone`.
+/// # Examples
+/// ```
+/// use algods::data_structure::PersistentStack;
+/// let empty = PersistentStack::new();
+/// let one = empty.push(1);
+/// let two = one.push(2);
+/// let other = one.push(3);
+/// assert_eq!(two.iter().collect::<Vec<_>>(), vec![&2, &1]);
+/// assert_eq!(other.iter().collect::<Vec<_>>(), vec![&3, &1]);
+/// assert_eq!(two.pop().unwrap().peek(), Some(&1));
+/// // old versions are left untouched
+/// assert_eq!(one.len(), 1);
+/// assert!(empty.is_empty());
+/// ```
+pub struct PersistentStack<T> {
+    head: Option<Rc<StackNode<T>>>,
+    len: usize,
+}
+impl<T> Default for PersistentStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> Clone for PersistentStack<T> {
+    fn clone(&self) -> Self {
+        Self {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+impl<T: fmt::Debug> fmt::Debug for PersistentStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T> Drop for PersistentStack<T> {
+    fn drop(&mut self) {
+        // drops the nodes owned by this version only, iteratively
+        // to avoid overflowing the call stack on long lists
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            match Rc::try_unwrap(node) {
+                Ok(mut node) => head = node.next.take(),
+                Err(_) => break,
+            }
+        }
+    }
+}
+impl<T> PersistentStack<T> {
+    /// Creates an empty stack.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentStack;
+    /// let stack = PersistentStack::<usize>::new();
+    /// assert!(stack.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self { head: None, len: 0 }
+    }
+    /// Tests whether or not the stack is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentStack;
+    /// let stack = PersistentStack::new().push(1);
+    /// assert!(!stack.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+    /// Gives the number of objects in the stack.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentStack;
+    /// let stack = PersistentStack::new().push(1).push(2);
+    /// assert_eq!(stack.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns the last object pushed onto the stack, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentStack;
+    /// let stack = PersistentStack::new().push(1).push(2);
+    /// assert_eq!(stack.peek(), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.item)
+    }
+    /// Returns a new version of the stack with an object pushed onto it.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentStack;
+    /// let stack = PersistentStack::new();
+    /// let pushed = stack.push(1);
+    /// assert_eq!(pushed.peek(), Some(&1));
+    /// assert!(stack.is_empty());
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn push(&self, item: T) -> Self {
+        Self {
+            head: Some(Rc::new(StackNode {
+                item,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+    /// Returns a new version of the stack without its last pushed object,
+    /// or `None` if the stack is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentStack;
+    /// let stack = PersistentStack::new().push(1).push(2);
+    /// let popped = stack.pop().unwrap();
+    /// assert_eq!(popped.peek(), Some(&1));
+    /// assert_eq!(stack.peek(), Some(&2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn pop(&self) -> Option<Self> {
+        self.head.as_ref().map(|node| Self {
+            head: node.next.clone(),
+            len: self.len - 1,
+        })
+    }
+    /// Returns an iterator over the objects of the stack, from the last pushed one.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentStack;
+    /// let stack = PersistentStack::new().push(1).push(2);
+    /// assert_eq!(stack.iter().collect::<Vec<_>>(), vec![&2, &1]);
+    /// ```
+    pub fn iter(&self) -> PersistentStackIter<'_, T> {
+        PersistentStackIter {
+            current: self.head.as_deref(),
+        }
+    }
+}
+
+/// Iterator over the objects of a `PersistentStack`, from the last pushed one.
+#[derive(Debug)]
+pub struct PersistentStackIter<'a, T> {
+    current: Option<&'a StackNode<T>>,
+}
+impl<'a, T> Iterator for PersistentStackIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current?;
+        self.current = node.next.as_deref();
+        Some(&node.item)
+    }
+}
+impl<'a, T> IntoIterator for &'a PersistentStack<T> {
+    type Item = &'a T;
+    type IntoIter = PersistentStackIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Suspended rotation of a real-time queue: rotate(front, rear, acc) = front ++ reverse(rear) ++ acc,
+// where rear has exactly one more object than front
+type Rotation<T> = (Stream<T>, PersistentStack<T>, Stream<T>);
+
+struct StreamNode<T> {
+    // first object and rest of the stream, once computed
+    cell: OnceCell<Option<(T, Stream<T>)>>,
+    // rotation to compute, if not done yet
+    pending: RefCell<Option<Rotation<T>>>,
+}
+impl<T> Drop for StreamNode<T> {
+    fn drop(&mut self) {
+        // drops the nodes owned by this stream only, iteratively
+        // to avoid overflowing the call stack on long streams
+        fn release<T>(node: &mut StreamNode<T>, streams: &mut Vec<Stream<T>>) {
+            if let Some(Some((_, rest))) = node.cell.take() {
+                streams.push(rest);
+            }
+            if let Some((front, _, acc)) = node.pending.get_mut().take() {
+                streams.push(front);
+                streams.push(acc);
+            }
+        }
+        let mut streams = Vec::new();
+        release(self, &mut streams);
+        while let Some(stream) = streams.pop() {
+            if let Ok(mut node) = Rc::try_unwrap(stream.0) {
+                release(&mut node, &mut streams);
+            }
+        }
+    }
+}
+
+// Lazy list whose objects are computed at most once
+struct Stream<T>(Rc<StreamNode<T>>);
+impl<T> Clone for Stream<T> {
+    fn clone(&self) -> Self {
+        Self(Rc::clone(&self.0))
+    }
+}
+impl<T> Stream<T> {
+    fn forced(cell: Option<(T, Stream<T>)>) -> Self {
+        Self(Rc::new(StreamNode {
+            cell: OnceCell::from(cell),
+            pending: RefCell::new(None),
+        }))
+    }
+    fn empty() -> Self {
+        Self::forced(None)
+    }
+}
+impl<T: Clone> Stream<T> {
+    fn rotate(front: Self, rear: PersistentStack<T>, acc: Self) -> Self {
+        Self(Rc::new(StreamNode {
+            cell: OnceCell::new(),
+            pending: RefCell::new(Some((front, rear, acc))),
+        }))
+    }
+    fn force(&self) -> Option<&(T, Stream<T>)> {
+        self.0
+            .cell
+            .get_or_init(|| {
+                let (front, rear, acc) = self.0.pending.borrow_mut().take().unwrap();
+                let last = rear.peek().unwrap().clone();
+                match front.force() {
+                    None => Some((last, acc)),
+                    Some((first, rest)) => Some((
+                        first.clone(),
+                        Self::rotate(
+                            rest.clone(),
+                            rear.pop().unwrap(),
+                            Self::forced(Some((last, acc))),
+                        ),
+                    )),
+                }
+            })
+            .as_ref()
+    }
+}
+
+/// Implementation of a persistent (immutable) real-time queue (Okasaki): `push` and `pop`
+/// return a new version in worst case O(1) and leave the previous versions valid.
+///
+/// The queue is made of a lazy front list and a rear stack which is lazily reversed onto
+/// the front when it gets longer; a schedule forces one object of the front at each
+/// operation so that no single operation pays for a whole reversal, whichever
+/// version it is applied to. Objects are cloned when they move from the rear to the front.
+/// # Examples
+/// ```
+/// use algods::data_structure::PersistentQueue;
+/// let empty = PersistentQueue::new();
+/// let queue = empty.push(1).push(2).push(3);
+/// let popped = queue.pop().unwrap();
+/// assert_eq!(popped.peek(), Some(&2));
+/// assert_eq!(popped.push(4).iter().collect::<Vec<_>>(), vec![&2, &3, &4]);
+/// // old versions are left untouched
+/// assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+/// assert!(empty.is_empty());
+/// ```
+pub struct PersistentQueue<T> {
+    front: Stream<T>,
+    rear: PersistentStack<T>,
+    // suffix of the front whose objects are not computed yet
+    schedule: Stream<T>,
+    len: usize,
+    // Remarks:
+    // - the queue is front ++ reverse(rear)
+    // - the schedule is as long as the front minus the rear
+}
+impl<T> Clone for PersistentQueue<T> {
+    fn clone(&self) -> Self {
+        Self {
+            front: self.front.clone(),
+            rear: self.rear.clone(),
+            schedule: self.schedule.clone(),
+            len: self.len,
+        }
+    }
+}
+impl<T: Clone + fmt::Debug> fmt::Debug for PersistentQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+impl<T: Clone> Default for PersistentQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Clone> PersistentQueue<T> {
+    /// Creates an empty queue.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentQueue;
+    /// let queue = PersistentQueue::<usize>::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            front: Stream::empty(),
+            rear: PersistentStack::new(),
+            schedule: Stream::empty(),
+            len: 0,
+        }
+    }
+    /// Tests whether or not the queue is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentQueue;
+    /// let queue = PersistentQueue::new().push(1);
+    /// assert!(!queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Gives the number of objects in the queue.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentQueue;
+    /// let queue = PersistentQueue::new().push(1).push(2);
+    /// assert_eq!(queue.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Returns the first object of the queue, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentQueue;
+    /// let queue = PersistentQueue::new().push(1).push(2);
+    /// assert_eq!(queue.peek(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn peek(&self) -> Option<&T> {
+        self.front.force().map(|(first, _)| first)
+    }
+    fn exec(front: Stream<T>, rear: PersistentStack<T>, schedule: Stream<T>, len: usize) -> Self {
+        // computes one more object of the front, or starts a new rotation
+        // once the front is fully computed and the rear is one object longer
+        match schedule.force() {
+            Some((_, rest)) => Self {
+                front,
+                rear,
+                schedule: rest.clone(),
+                len,
+            },
+            None => {
+                let front = Stream::rotate(front, rear, Stream::empty());
+                Self {
+                    schedule: front.clone(),
+                    front,
+                    rear: PersistentStack::new(),
+                    len,
+                }
+            }
+        }
+    }
+    /// Returns a new version of the queue with an object added at its end.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentQueue;
+    /// let queue = PersistentQueue::new().push(1);
+    /// let pushed = queue.push(2);
+    /// assert_eq!(pushed.len(), 2);
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn push(&self, item: T) -> Self {
+        Self::exec(
+            self.front.clone(),
+            self.rear.push(item),
+            self.schedule.clone(),
+            self.len + 1,
+        )
+    }
+    /// Returns a new version of the queue without its first object,
+    /// or `None` if the queue is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentQueue;
+    /// let queue = PersistentQueue::new().push(1).push(2);
+    /// let popped = queue.pop().unwrap();
+    /// assert_eq!(popped.peek(), Some(&2));
+    /// assert_eq!(queue.peek(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn pop(&self) -> Option<Self> {
+        let (_, rest) = self.front.force()?;
+        Some(Self::exec(
+            rest.clone(),
+            self.rear.clone(),
+            self.schedule.clone(),
+            self.len - 1,
+        ))
+    }
+    /// Returns an iterator over the objects of the queue, from the first one.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PersistentQueue;
+    /// let queue = PersistentQueue::new().push(1).push(2);
+    /// assert_eq!(queue.iter().collect::<Vec<_>>(), vec![&1, &2]);
+    /// ```
+    pub fn iter(&self) -> PersistentQueueIter<'_, T> {
+        PersistentQueueIter {
+            front: Some(&self.front),
+            rear: self.rear.iter().collect(),
+        }
+    }
+}
+
+/// Iterator over the objects of a `PersistentQueue`, from the first one.
+pub struct PersistentQueueIter<'a, T> {
+    front: Option<&'a Stream<T>>,
+    // objects of the rear, the first one last
+    rear: Vec<&'a T>,
+}
+impl<'a, T: Clone> Iterator for PersistentQueueIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.front.and_then(|stream| stream.force()) {
+            Some((first, rest)) => {
+                self.front = Some(rest);
+                Some(first)
+            }
+            None => {
+                self.front = None;
+                self.rear.pop()
+            }
+        }
+    }
+}
+impl<'a, T: Clone> IntoIterator for &'a PersistentQueue<T> {
+    type Item = &'a T;
+    type IntoIter = PersistentQueueIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_persistent_stack_versions() {
+        let mut versions = vec![PersistentStack::new()];
+        let mut expected = vec![Vec::<usize>::new()];
+        let mut rng = StdRng::seed_from_u64(0);
+        for step in 0..2000 {
+            // applies an operation to a random previous version
+            let k = rng.gen_range(0..versions.len());
+            let mut vec = expected[k].clone();
+            let stack = if rng.gen_bool(0.6) {
+                vec.push(step);
+                versions[k].push(step)
+            } else {
+                match versions[k].pop() {
+                    Some(stack) => {
+                        vec.pop();
+                        stack
+                    }
+                    None => continue,
+                }
+            };
+            versions.push(stack);
+            expected.push(vec);
+        }
+        for (stack, vec) in versions.iter().zip(expected.iter()) {
+            assert_eq!(stack.len(), vec.len());
+            assert_eq!(stack.peek(), vec.last());
+            assert!(stack.iter().eq(vec.iter().rev()));
+        }
+    }
+
+    #[test]
+    fn test_persistent_queue_versions() {
+        let mut versions = vec![PersistentQueue::new()];
+        let mut expected = vec![VecDeque::<usize>::new()];
+        let mut rng = StdRng::seed_from_u64(1);
+        for step in 0..3000 {
+            // mostly extends the last versions, sometimes branches from an old one
+            let k = if rng.gen_bool(0.8) {
+                versions.len() - 1
+            } else {
+                rng.gen_range(0..versions.len())
+            };
+            let mut deque = expected[k].clone();
+            let queue = if rng.gen_bool(0.55) {
+                deque.push_back(step);
+                versions[k].push(step)
+            } else {
+                match versions[k].pop() {
+                    Some(queue) => {
+                        deque.pop_front();
+                        queue
+                    }
+                    None => continue,
+                }
+            };
+            assert_eq!(queue.peek(), deque.front());
+            versions.push(queue);
+            expected.push(deque);
+        }
+        for (queue, deque) in versions.iter().zip(expected.iter()) {
+            assert_eq!(queue.len(), deque.len());
+            assert_eq!(queue.peek(), deque.front());
+            assert!(queue.iter().eq(deque.iter()));
+        }
+    }
+
+    #[test]
+    fn test_persistent_queue_fifo() {
+        let mut queue = PersistentQueue::new();
+        for k in 0..1000 {
+            queue = queue.push(k);
+        }
+        let snapshot = queue.clone();
+        for k in 0..1000 {
+            assert_eq!(queue.peek(), Some(&k));
+            queue = queue.pop().unwrap();
+        }
+        assert!(queue.is_empty());
+        assert!(queue.pop().is_none());
+        assert_eq!(snapshot.len(), 1000);
+        assert!(snapshot.iter().copied().eq(0..1000));
+    }
+
+    #[test]
+    fn test_persistent_deep_drop() {
+        let mut stack = PersistentStack::new();
+        let mut queue = PersistentQueue::new();
+        for k in 0..1_000_000 {
+            stack = stack.push(k);
+            queue = queue.push(k);
+        }
+        let half = stack.pop().unwrap();
+        drop(stack);
+        assert_eq!(half.len(), 999_999);
+        drop(half);
+        drop(queue);
+    }
+}
@@ -1,11 +1,15 @@
+mod comparator;
 mod orientation;
 #[cfg(test)]
 mod unit_test;
+use comparator::Comparator;
 pub use orientation::Orientation;
-use std::collections::BinaryHeap;
-use std::mem::replace;
+use std::cmp::Ordering;
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
 
-/// Implementation of priority queues with the standard library
+/// Implementation of priority queues following the standard library `BinaryHeap`,
+/// with a comparator kept by the queue
 /// # Examples
 /// ```
 /// use algods::data_structure::BinaryHeapQueue;
@@ -19,11 +23,21 @@ use std::mem::replace;
 /// assert_eq!(bhqueue.delete(), Some(1));
 /// assert_eq!(bhqueue.len(), 1);
 /// ```
-/// By default the priority queue is **max oriented**, the user should adapt
-/// the `Ord` trait to implement a min oriented priority queue.
-#[derive(Debug)]
+/// By default the priority queue is **max oriented**, other orders are given
+/// with `with_comparator` or `by_key`.
+/// ```
+/// use algods::data_structure::BinaryHeapQueue;
+/// use std::cmp::Reverse;
+/// let mut jobs = BinaryHeapQueue::by_key(|job: &(&str, usize)| Reverse(job.1));
+/// jobs.insert(("backup", 3));
+/// jobs.insert(("deploy", 1));
+/// assert_eq!(jobs.delete(), Some(("deploy", 1)));
+/// ```
+#[derive(Debug, Clone)]
 pub struct BinaryHeapQueue<T> {
-    heap: BinaryHeap<T>,
+    // objects laid out as a binary heap, as in PriorityQueue
+    heap: Vec<T>,
+    cmp: Comparator<T>,
 }
 impl<T: Ord> Default for BinaryHeapQueue<T> {
     fn default() -> Self {
@@ -39,9 +53,7 @@ impl<T: Ord> BinaryHeapQueue<T> {
     /// assert_eq!(bhqueue.len(), 0);
     /// ```
     pub fn new() -> Self {
-        Self {
-            heap: BinaryHeap::<T>::new(),
-        }
+        Self::with_capacity(0)
     }
 
     /// Creates a new empty priority queue with an initial size.
@@ -53,10 +65,67 @@ impl<T: Ord> BinaryHeapQueue<T> {
     /// ```
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            heap: BinaryHeap::<T>::with_capacity(capacity),
+            heap: Vec::with_capacity(capacity),
+            cmp: Comparator::from_orientation(Orientation::Max),
         }
     }
 
+    /// Creates a priority queue from a `Vec` of objects.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BinaryHeapQueue;
+    /// let mut bhqueue = BinaryHeapQueue::from_vec(vec![3, 1, 4, 1, 5]);
+    /// assert_eq!(bhqueue.delete(), Some(5));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_vec(mut vec: Vec<T>) -> Self {
+        let cmp = Comparator::from_orientation(Orientation::Max);
+        cmp.heapify(&mut vec);
+        Self { heap: vec, cmp }
+    }
+}
+
+impl<T> BinaryHeapQueue<T> {
+    /// Creates an empty priority queue whose extremum is the largest object
+    /// according to a comparator.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BinaryHeapQueue;
+    /// let mut bhqueue = BinaryHeapQueue::with_comparator(|a: &f64, b: &f64| b.total_cmp(a));
+    /// bhqueue.insert(1.5);
+    /// bhqueue.insert(-0.5);
+    /// assert_eq!(bhqueue.extremum(), Some(&-0.5));
+    /// ```
+    pub fn with_comparator<F>(cmp: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        Self {
+            heap: Vec::new(),
+            cmp: Comparator::Closure(Arc::new(cmp)),
+        }
+    }
+
+    /// Creates an empty priority queue whose extremum is the object with the largest key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BinaryHeapQueue;
+    /// let mut bhqueue = BinaryHeapQueue::by_key(|word: &&str| word.len());
+    /// bhqueue.insert("a");
+    /// bhqueue.insert("abc");
+    /// bhqueue.insert("ab");
+    /// assert_eq!(bhqueue.delete(), Some("abc"));
+    /// ```
+    pub fn by_key<K, F>(key: F) -> Self
+    where
+        T: 'static,
+        K: Ord + 'static,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        Self::with_comparator(move |a, b| key(a).cmp(&key(b)))
+    }
+
     /// Tests whether or not the priority queue is empty.
     /// # Example
     /// ```
@@ -93,7 +162,9 @@ impl<T: Ord> BinaryHeapQueue<T> {
     /// assert_eq!(bhqueue.len(), 2);
     /// ```
     pub fn insert(&mut self, key: T) {
-        self.heap.push(key)
+        self.heap.push(key);
+        let last = self.heap.len() - 1;
+        self.cmp.swim(&mut self.heap, last);
     }
 
     /// Deletes and returns the extremal (smallest in min oriented heap
@@ -108,7 +179,13 @@ impl<T: Ord> BinaryHeapQueue<T> {
     /// assert_eq!(bhqueue.delete(), Some(1));
     /// ```
     pub fn delete(&mut self) -> Option<T> {
-        self.heap.pop()
+        if self.heap.is_empty() {
+            return None;
+        }
+        let res = self.heap.swap_remove(0);
+        let n = self.heap.len();
+        self.cmp.sink(&mut self.heap, 0, n);
+        Some(res)
     }
 
     /// Returns the extremal (smallest in min oriented heap
@@ -125,7 +202,89 @@ impl<T: Ord> BinaryHeapQueue<T> {
     /// # Time complexity
     /// This is expected to run in O(1)
     pub fn extremum(&self) -> Option<&T> {
-        self.heap.peek()
+        self.heap.first()
+    }
+
+    /// Returns a mutable reference to the extremal object in the priority queue, if any.
+    /// The queue is reordered when the reference is dropped.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BinaryHeapQueue;
+    /// let mut bhqueue = BinaryHeapQueue::from_vec(vec![1, 5, 3]);
+    /// if let Some(mut top) = bhqueue.peek_mut() {
+    ///     *top = 0;
+    /// }
+    /// assert_eq!(bhqueue.extremum(), Some(&3));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        PeekMut::new(&mut self.heap, &self.cmp)
+    }
+
+    /// Returns the objects of the priority queue in the order they would be deleted.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BinaryHeapQueue;
+    /// let bhqueue = BinaryHeapQueue::from_vec(vec![1, 5, 3]);
+    /// assert_eq!(bhqueue.into_sorted_vec(), vec![5, 3, 1]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N log(N))
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        self.cmp.sort(&mut self.heap);
+        self.heap
+    }
+
+    /// Moves all the objects of another priority queue into this one, leaving the other empty.
+    /// The objects are then ordered by the comparator of this queue.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BinaryHeapQueue;
+    /// let mut q1 = BinaryHeapQueue::from_vec(vec![1, 5]);
+    /// let mut q2 = BinaryHeapQueue::from_vec(vec![3, 7]);
+    /// q1.append(&mut q2);
+    /// assert!(q2.is_empty());
+    /// assert_eq!(q1.into_sorted_vec(), vec![7, 5, 3, 1]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N + M)
+    pub fn append(&mut self, other: &mut Self) {
+        self.heap.append(&mut other.heap);
+        self.cmp.heapify(&mut self.heap);
+    }
+}
+
+/// Mutable reference to the extremal object of a priority queue, returned by `peek_mut`.
+/// The queue is reordered when it is dropped.
+pub struct PeekMut<'a, T> {
+    heap: &'a mut [T],
+    cmp: &'a Comparator<T>,
+}
+impl<'a, T> PeekMut<'a, T> {
+    fn new(heap: &'a mut [T], cmp: &'a Comparator<T>) -> Option<Self> {
+        if heap.is_empty() {
+            None
+        } else {
+            Some(Self { heap, cmp })
+        }
+    }
+}
+impl<T> Deref for PeekMut<'_, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        &self.heap[0]
+    }
+}
+impl<T> DerefMut for PeekMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.heap[0]
+    }
+}
+impl<T> Drop for PeekMut<'_, T> {
+    fn drop(&mut self) {
+        let n = self.heap.len();
+        self.cmp.sink(self.heap, 0, n);
     }
 }
 
@@ -143,25 +302,51 @@ impl<T: Ord> BinaryHeapQueue<T> {
 /// assert_eq!(bhqueue.delete(), Some(1));
 /// assert_eq!(bhqueue.len(), 1);
 /// ```
-#[derive(Debug, Default, Clone)]
+/// Besides `Orientation`, objects can be ordered by a comparator or a key.
+/// ```
+/// use algods::data_structure::PriorityQueue;
+/// let mut jobs = PriorityQueue::by_key(|job: &(&str, usize)| job.1);
+/// jobs.insert(("backup", 3));
+/// jobs.insert(("deploy", 1));
+/// assert_eq!(jobs.delete(), Some(("backup", 3)));
+/// ```
+#[derive(Debug, Clone)]
 pub struct PriorityQueue<T> {
     // vector of objects
-    vec: Vec<Option<T>>,
-    // type of priority queue
-    kind: Orientation,
-    // position of the next object in the queue (or 1 + number of objects)
-    n: usize,
+    vec: Vec<T>,
+    // ordering of the objects
+    cmp: Comparator<T>,
     // Remarks:
     // - objects are nodes of the tree
-    // - in the implementation objects are stored in self.vec from index = 1 to index = capacity
-    //   so that index = 0 is always None object and:
-    //     - each node k's parent is at position k/2
-    //     - each node k's children are at positions 2k and 2k+1
-    // - in the max oriented binary heap (with kind = Orientation::Max),
-    //   parents are larger than their children (smaller for min oriented heap)
+    // - in the implementation objects are stored in self.vec from index = 0 to index = len - 1
+    //   so that:
+    //     - each node k's parent is at position (k-1)/2
+    //     - each node k's children are at positions 2k+1 and 2k+2
+    // - parents are larger than their children according to the comparator,
+    //   which is reversed for a min oriented heap (with kind = Orientation::Min)
+    // - objects are only moved with swaps, so that they need not be cloned
 }
 
-impl<T> PriorityQueue<T> {
+impl<T: Ord> Default for PriorityQueue<T> {
+    fn default() -> Self {
+        Self::new(Orientation::default())
+    }
+}
+
+impl<T: Ord> PriorityQueue<T> {
+    /// Creates a new empty priority queue, which grows as objects are inserted.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PriorityQueue, Orientation};
+    /// let mut bhqueue = PriorityQueue::new(Orientation::Max);
+    /// bhqueue.insert(1);
+    /// bhqueue.insert(2);
+    /// assert_eq!(bhqueue.extremum(), Some(&2));
+    /// ```
+    pub fn new(k: Orientation) -> Self {
+        Self::from_vec(Vec::new(), k)
+    }
+
     /// Creates a new empty priority queue with an initial size.
     /// # Panics
     /// If `capacity = 0`, then it panics.
@@ -172,23 +357,81 @@ impl<T> PriorityQueue<T> {
     /// assert_eq!(bhqueue.len(), 0);
     /// ```
     pub fn with_capacity(capacity: usize, k: Orientation) -> Self {
-        // running time complexity: O(N)
-        if capacity > 0 {
-            let mut vector = Vec::with_capacity(capacity + 1);
-            for _ in 0..capacity + 1 {
-                vector.push(None);
-            }
+        Self::init(capacity, Comparator::from_orientation(k))
+    }
+
+    /// Creates a priority queue from a `Vec` of objects.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PriorityQueue, Orientation};
+    /// let mut bhqueue = PriorityQueue::from_vec(vec![3, 1, 4, 1, 5], Orientation::Min);
+    /// assert_eq!(bhqueue.len(), 5);
+    /// assert_eq!(bhqueue.delete(), Some(1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_vec(vec: Vec<T>, k: Orientation) -> Self {
+        let mut queue = Self {
+            vec,
+            cmp: Comparator::from_orientation(k),
+        };
+        queue.cmp.heapify(&mut queue.vec);
+        queue
+    }
+}
 
+impl<T> PriorityQueue<T> {
+    fn init(capacity: usize, cmp: Comparator<T>) -> Self {
+        if capacity > 0 {
             Self {
-                vec: vector,
-                kind: k,
-                n: 1,
+                vec: Vec::with_capacity(capacity),
+                cmp,
             }
         } else {
             panic!("capacity shoul be > 0");
         }
     }
 
+    /// Creates a new empty priority queue, which grows as objects are inserted, whose extremum
+    /// is the largest object according to a comparator.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PriorityQueue;
+    /// let mut bhqueue = PriorityQueue::with_comparator(|a: &f64, b: &f64| b.total_cmp(a));
+    /// bhqueue.insert(1.5);
+    /// bhqueue.insert(-0.5);
+    /// assert_eq!(bhqueue.extremum(), Some(&-0.5));
+    /// ```
+    pub fn with_comparator<F>(cmp: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        Self {
+            vec: Vec::new(),
+            cmp: Comparator::Closure(Arc::new(cmp)),
+        }
+    }
+
+    /// Creates a new empty priority queue, which grows as objects are inserted, whose extremum
+    /// is the object with the largest key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::PriorityQueue;
+    /// use std::cmp::Reverse;
+    /// let mut bhqueue = PriorityQueue::by_key(|word: &&str| Reverse(word.len()));
+    /// bhqueue.insert("abc");
+    /// bhqueue.insert("a");
+    /// assert_eq!(bhqueue.extremum(), Some(&"a"));
+    /// ```
+    pub fn by_key<K, F>(key: F) -> Self
+    where
+        T: 'static,
+        K: Ord + 'static,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        Self::with_comparator(move |a, b| key(a).cmp(&key(b)))
+    }
+
     /// Tests whether or not the priority queue is empty.
     /// # Example
     /// ```
@@ -198,7 +441,7 @@ impl<T> PriorityQueue<T> {
     /// assert!(!bhqueue.is_empty());
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.n == 1
+        self.vec.is_empty()
     }
 
     /// Gives the number of objects in the priority queue.
@@ -214,7 +457,7 @@ impl<T> PriorityQueue<T> {
     pub fn len(&self) -> usize {
         // number of objects in the queue
         // run time complexity O(1)
-        self.n - 1
+        self.vec.len()
     }
 
     /// Returns the extremal (smallest in min oriented heap
@@ -232,47 +475,30 @@ impl<T> PriorityQueue<T> {
     /// This is expected to run in O(1)
     pub fn extremum(&self) -> Option<&T> {
         // run time complexity O(1)
-        self.vec[1].as_ref()
+        self.vec.first()
     }
 
-    fn double(&mut self) {
-        // run time complexity O(N)
-        // doubling the size of the priority queue
-        let mut vector = Vec::with_capacity(self.vec.len());
-        for _ in 0..self.vec.len() {
-            vector.push(None);
-        }
-        self.vec.append(&mut vector);
+    /// Returns a mutable reference to the extremal object in the priority queue, if any.
+    /// The queue is reordered when the reference is dropped.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PriorityQueue, Orientation};
+    /// let mut bhqueue = PriorityQueue::from_vec(vec![1, 5, 3], Orientation::Max);
+    /// if let Some(mut top) = bhqueue.peek_mut() {
+    ///     *top = 0;
+    /// }
+    /// assert_eq!(bhqueue.extremum(), Some(&3));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T>> {
+        PeekMut::new(&mut self.vec, &self.cmp)
     }
 
     fn halve(&mut self) {
         // run time complexity O(N)
         // halving the size of the priority queue
-        self.vec.truncate(self.vec.len() / 2);
-    }
-}
-
-impl<T: Ord + Clone> PriorityQueue<T> {
-    fn swim(&mut self, mut k: usize) {
-        // moves data at position k up in the "tree" following the
-        // Peter principle: Nodes are promoted to their level of incompetence
-        // run time complexity O(log(N))
-        match self.kind {
-            Orientation::Max => {
-                while k > 1 && self.vec[k] > self.vec[k / 2] {
-                    let val = self.vec[k].clone();
-                    self.vec[k] = replace(&mut self.vec[k / 2], val);
-                    k /= 2;
-                }
-            }
-            Orientation::Min => {
-                while k > 1 && self.vec[k] < self.vec[k / 2] {
-                    let val = self.vec[k].clone();
-                    self.vec[k] = replace(&mut self.vec[k / 2], val);
-                    k /= 2;
-                }
-            }
-        }
+        self.vec.shrink_to(self.vec.capacity() / 2);
     }
 
     /// Inserts an object into the priority queue.
@@ -289,64 +515,9 @@ impl<T: Ord + Clone> PriorityQueue<T> {
     pub fn insert(&mut self, key: T) {
         // run time complexity O(log(N)) (without resizing)
         // and O(N) with resizing
-        if self.n < self.vec.len() {
-            self.vec[self.n] = Some(key);
-            self.swim(self.n);
-            self.n += 1;
-            if self.n == self.vec.len() {
-                // resize the stack to allow more capacity
-                self.double();
-            }
-        } else {
-            panic!("cannot push, stack is full or has capacity 0");
-        }
-    }
-
-    fn sink(&mut self, mut k: usize, n: usize) {
-        // moves data at position k down in the "tree" following the
-        // Power struggle principle: Better nodes are promoted
-        // Nodes beyond node n are untouched.
-        // run time complexity O(log(N))
-        if self.is_empty() {
-            panic!("cannot sink data, queue is empty.")
-        } else {
-            match self.kind {
-                Orientation::Max => {
-                    while 2 * k < n {
-                        let mut j = 2 * k;
-                        // find the largest child of node k
-                        if j < n - 1 && self.vec[j] < self.vec[j + 1] {
-                            j += 1;
-                        }
-                        // compare it to node k
-                        if self.vec[k] >= self.vec[j] {
-                            break;
-                        }
-                        // exchange them if it is larger than node k
-                        let val = self.vec[k].clone();
-                        self.vec[k] = replace(&mut self.vec[j], val);
-                        k = j;
-                    }
-                }
-                Orientation::Min => {
-                    while 2 * k < n {
-                        let mut j = 2 * k;
-                        // find the smallest child of node k
-                        if j < n - 1 && self.vec[j] > self.vec[j + 1] {
-                            j += 1;
-                        }
-                        // compare it to node k
-                        if self.vec[k] <= self.vec[j] {
-                            break;
-                        }
-                        // exchange them if it is smaller than node k
-                        let val = self.vec[k].clone();
-                        self.vec[k] = replace(&mut self.vec[j], val);
-                        k = j;
-                    }
-                }
-            }
-        }
+        self.vec.push(key);
+        let last = self.vec.len() - 1;
+        self.cmp.swim(&mut self.vec, last);
     }
 
     /// Deletes and returns the extremal (smallest in min oriented heap
@@ -368,16 +539,47 @@ impl<T: Ord + Clone> PriorityQueue<T> {
         if self.is_empty() {
             panic!("cannot delete, queue is empty");
         } else {
-            let res = self.vec[1].clone();
-            // Put the last object at the beginning of the root of the tree
-            self.vec[1] = replace(&mut self.vec[self.n - 1], None);
+            // Put the last object at the root of the tree
+            let res = self.vec.swap_remove(0);
             // sink the root object
-            self.sink(1, self.n);
-            self.n -= 1;
-            if self.n <= self.vec.len() / 4 {
+            let n = self.vec.len();
+            self.cmp.sink(&mut self.vec, 0, n);
+            if self.vec.len() <= self.vec.capacity() / 4 {
                 self.halve();
             }
-            res
+            Some(res)
         }
     }
+
+    /// Returns the objects of the priority queue in the order they would be deleted.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PriorityQueue, Orientation};
+    /// let bhqueue = PriorityQueue::from_vec(vec![1, 5, 3], Orientation::Min);
+    /// assert_eq!(bhqueue.into_sorted_vec(), vec![1, 3, 5]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N log(N))
+    pub fn into_sorted_vec(mut self) -> Vec<T> {
+        self.cmp.sort(&mut self.vec);
+        self.vec
+    }
+
+    /// Moves all the objects of another priority queue into this one, leaving the other empty.
+    /// The objects are then ordered by the comparator (or orientation) of this queue.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{PriorityQueue, Orientation};
+    /// let mut q1 = PriorityQueue::from_vec(vec![1, 5], Orientation::Max);
+    /// let mut q2 = PriorityQueue::from_vec(vec![3, 7], Orientation::Max);
+    /// q1.append(&mut q2);
+    /// assert!(q2.is_empty());
+    /// assert_eq!(q1.into_sorted_vec(), vec![7, 5, 3, 1]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N + M)
+    pub fn append(&mut self, other: &mut Self) {
+        self.vec.append(&mut other.vec);
+        self.cmp.heapify(&mut self.vec);
+    }
 }
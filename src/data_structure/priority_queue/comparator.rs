@@ -0,0 +1,98 @@
+use super::Orientation;
+use std::cmp::Ordering;
+use std::fmt;
+use std::sync::Arc;
+
+// User given ordering of two objects, shareable between threads like the objects themselves
+type CompareFn<T> = dyn Fn(&T, &T) -> Ordering + Send + Sync;
+
+/// Ordering of the objects of a priority queue: the extremum is the largest object.
+pub(super) enum Comparator<T> {
+    // ordering derived from `Ord`, as a function pointer so that no lifetime bound is needed
+    Fn(fn(&T, &T) -> Ordering),
+    // user given ordering
+    Closure(Arc<CompareFn<T>>),
+}
+impl<T> Comparator<T> {
+    pub(super) fn compare(&self, a: &T, b: &T) -> Ordering {
+        match self {
+            Self::Fn(cmp) => cmp(a, b),
+            Self::Closure(cmp) => cmp(a, b),
+        }
+    }
+    fn less(&self, vec: &[T], i: usize, j: usize) -> bool {
+        // tests whether or not the object at position i has a lower priority
+        // than the object at position j
+        self.compare(&vec[i], &vec[j]) == Ordering::Less
+    }
+    pub(super) fn swim(&self, vec: &mut [T], mut k: usize) {
+        // moves data at position k up in the "tree" following the
+        // Peter principle: Nodes are promoted to their level of incompetence
+        // run time complexity O(log(N))
+        while k > 0 && self.less(vec, (k - 1) / 2, k) {
+            vec.swap(k, (k - 1) / 2);
+            k = (k - 1) / 2;
+        }
+    }
+    pub(super) fn sink(&self, vec: &mut [T], mut k: usize, n: usize) {
+        // moves data at position k down in the "tree" following the
+        // Power struggle principle: Better nodes are promoted
+        // Nodes from position n are untouched.
+        // run time complexity O(log(N))
+        while 2 * k + 1 < n {
+            let mut j = 2 * k + 1;
+            // find the child of node k with the highest priority
+            if j + 1 < n && self.less(vec, j, j + 1) {
+                j += 1;
+            }
+            // compare it to node k
+            if !self.less(vec, k, j) {
+                break;
+            }
+            // exchange them if it has a higher priority than node k
+            vec.swap(k, j);
+            k = j;
+        }
+    }
+    pub(super) fn heapify(&self, vec: &mut [T]) {
+        // sinks every parent node, from the bottom of the tree
+        // run time complexity O(N)
+        let n = vec.len();
+        for k in (0..n / 2).rev() {
+            self.sink(vec, k, n);
+        }
+    }
+    pub(super) fn sort(&self, vec: &mut [T]) {
+        // heap sort of a heap: the extremum is repeatedly moved after the remaining objects,
+        // then the objects are put in the order they would be deleted
+        for end in (1..vec.len()).rev() {
+            vec.swap(0, end);
+            self.sink(vec, 0, end);
+        }
+        vec.reverse();
+    }
+}
+impl<T: Ord> Comparator<T> {
+    pub(super) fn from_orientation(kind: Orientation) -> Self {
+        match kind {
+            Orientation::Max => Self::Fn(T::cmp),
+            Orientation::Min => Self::Fn(|a, b| b.cmp(a)),
+        }
+    }
+}
+impl<T> Clone for Comparator<T> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Fn(cmp) => Self::Fn(*cmp),
+            Self::Closure(cmp) => Self::Closure(Arc::clone(cmp)),
+        }
+    }
+}
+impl<T> fmt::Debug for Comparator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fn(_) => f.write_str("Comparator::Fn"),
+            Self::Closure(_) => f.write_str("Comparator::Closure"),
+        }
+    }
+}
@@ -13,10 +13,7 @@ mod tests {
             queue.insert(rng.gen::<isize>());
         }
         assert_eq!(queue.len(), len);
-        assert_eq!(
-            queue.heap.peek().unwrap().clone(),
-            queue.heap.pop().unwrap()
-        );
+        assert_eq!(queue.extremum().unwrap().clone(), queue.delete().unwrap());
     }
 
     #[test]
@@ -34,15 +31,16 @@ mod tests {
         queue.insert(rng.gen::<isize>());
         assert_eq!(queue.len(), 1);
         queue.insert(rng.gen::<isize>());
-        assert_eq!(queue.vec.len(), 6);
+        assert_eq!(queue.vec.len(), 2);
+        assert!(queue.vec.capacity() >= 2);
         for _ in 0..len - 2 {
             queue.insert(rng.gen::<isize>());
         }
         queue.delete();
         assert_eq!(queue.len(), len - 1);
-        assert_eq!(queue.extremum(), queue.vec[1].as_ref());
-        for k in 1..(queue.len() - 1) / 2 {
-            assert!(queue.vec[k] <= queue.vec[2 * k] && queue.vec[k] <= queue.vec[2 * k + 1]);
+        assert_eq!(queue.extremum(), queue.vec.first());
+        for k in 0..(queue.len() - 1) / 2 {
+            assert!(queue.vec[k] <= queue.vec[2 * k + 1] && queue.vec[k] <= queue.vec[2 * k + 2]);
         }
     }
 
@@ -55,15 +53,157 @@ mod tests {
         queue.insert(rng.gen::<isize>());
         assert_eq!(queue.len(), 1);
         queue.insert(rng.gen::<isize>());
-        assert_eq!(queue.vec.len(), 6);
+        assert_eq!(queue.vec.len(), 2);
+        assert!(queue.vec.capacity() >= 2);
         for _ in 0..len - 2 {
             queue.insert(rng.gen::<isize>());
         }
         queue.delete();
         assert_eq!(queue.len(), len - 1);
-        assert_eq!(queue.extremum(), queue.vec[1].as_ref());
-        for k in 1..(queue.len() - 1) / 2 {
-            assert!(queue.vec[k] >= queue.vec[2 * k] && queue.vec[k] >= queue.vec[2 * k + 1]);
+        assert_eq!(queue.extremum(), queue.vec.first());
+        for k in 0..(queue.len() - 1) / 2 {
+            assert!(queue.vec[k] >= queue.vec[2 * k + 1] && queue.vec[k] >= queue.vec[2 * k + 2]);
         }
     }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Job {
+        name: String,
+        deadline: usize,
+    }
+
+    fn jobs() -> Vec<Job> {
+        [("backup", 5), ("deploy", 1), ("report", 3), ("cleanup", 4)]
+            .into_iter()
+            .map(|(name, deadline)| Job {
+                name: name.to_string(),
+                deadline,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_priority_queue_comparator() {
+        // earliest deadline first, without Ord on the objects
+        let mut queue =
+            PriorityQueue::with_comparator(|a: &Job, b: &Job| b.deadline.cmp(&a.deadline));
+        let mut bhqueue = BinaryHeapQueue::by_key(|job: &Job| std::cmp::Reverse(job.deadline));
+        for job in jobs() {
+            bhqueue.insert(job.clone());
+            queue.insert(job);
+        }
+        assert_eq!(queue.extremum().unwrap().name, "deploy");
+        let order = bhqueue
+            .into_sorted_vec()
+            .into_iter()
+            .map(|job| job.deadline)
+            .collect::<Vec<usize>>();
+        assert_eq!(order, vec![1, 3, 4, 5]);
+        let order = queue
+            .into_sorted_vec()
+            .into_iter()
+            .map(|job| job.name)
+            .collect::<Vec<String>>();
+        assert_eq!(order, vec!["deploy", "report", "cleanup", "backup"]);
+    }
+
+    #[test]
+    fn test_priority_queue_from_vec() {
+        let mut rng = rand::thread_rng();
+        let vec = (0..1000)
+            .map(|_| rng.gen_range(-100..100))
+            .collect::<Vec<isize>>();
+        let mut sorted = vec.clone();
+        sorted.sort();
+        let queue = PriorityQueue::from_vec(vec.clone(), Orientation::Min);
+        assert_eq!(queue.len(), 1000);
+        for k in 1..queue.len() {
+            assert!(queue.vec[(k - 1) / 2] <= queue.vec[k]);
+        }
+        assert_eq!(queue.into_sorted_vec(), sorted);
+        sorted.reverse();
+        assert_eq!(BinaryHeapQueue::from_vec(vec).into_sorted_vec(), sorted);
+        assert!(PriorityQueue::<usize>::from_vec(vec![], Orientation::Max).is_empty());
+    }
+
+    #[test]
+    fn test_priority_queue_peek_mut() {
+        let mut queue = PriorityQueue::from_vec(vec![4, 8, 2, 6], Orientation::Max);
+        let mut bhqueue = BinaryHeapQueue::from_vec(vec![4, 8, 2, 6]);
+        for _ in 0..3 {
+            *queue.peek_mut().unwrap() -= 5;
+            *bhqueue.peek_mut().unwrap() -= 5;
+        }
+        assert_eq!(queue.into_sorted_vec(), vec![3, 2, 1, -1]);
+        assert_eq!(bhqueue.into_sorted_vec(), vec![3, 2, 1, -1]);
+        assert!(PriorityQueue::<usize>::from_vec(vec![], Orientation::Max)
+            .peek_mut()
+            .is_none());
+    }
+
+    #[test]
+    fn test_priority_queue_append() {
+        let mut q1 = PriorityQueue::from_vec(vec![1, 9, 5], Orientation::Min);
+        let mut q2 = PriorityQueue::with_capacity(1, Orientation::Max);
+        for k in [2, 8, 4, 6] {
+            q2.insert(k);
+        }
+        q1.append(&mut q2);
+        assert!(q2.is_empty());
+        assert_eq!(q1.len(), 7);
+        assert_eq!(q1.into_sorted_vec(), vec![1, 2, 4, 5, 6, 8, 9]);
+
+        let mut b1 = BinaryHeapQueue::by_key(|k: &isize| -k);
+        b1.insert(3);
+        let mut b2 = BinaryHeapQueue::from_vec(vec![1, 2]);
+        b1.append(&mut b2);
+        assert!(b2.is_empty());
+        assert_eq!(b1.into_sorted_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_priority_queue_new() {
+        let mut queue = PriorityQueue::new(Orientation::Min);
+        assert!(queue.is_empty());
+        assert_eq!(queue.extremum(), None);
+        for k in (0..100).rev() {
+            queue.insert(k);
+        }
+        assert_eq!(queue.len(), 100);
+        for k in 0..100 {
+            assert_eq!(queue.delete(), Some(k));
+        }
+        assert!(queue.is_empty());
+        assert_eq!(queue.extremum(), None);
+        assert!(PriorityQueue::<usize>::default().is_empty());
+    }
+
+    #[test]
+    fn test_priority_queue_non_clone_objects() {
+        // boxed tasks ordered by priority
+        type Task = (usize, Box<dyn Fn() -> String>);
+        let mut queue = PriorityQueue::with_comparator(|a: &Task, b: &Task| a.0.cmp(&b.0));
+        for priority in [2, 7, 4] {
+            queue.insert((priority, Box::new(move || format!("task {priority}"))));
+        }
+        let mut done = Vec::new();
+        while !queue.is_empty() {
+            let (_, task) = queue.delete().unwrap();
+            done.push(task());
+        }
+        assert_eq!(done, vec!["task 7", "task 4", "task 2"]);
+    }
+
+    #[test]
+    fn test_priority_queue_send_sync() {
+        fn is_send_sync<T: Send + Sync>() {}
+        is_send_sync::<PriorityQueue<usize>>();
+        is_send_sync::<BinaryHeapQueue<usize>>();
+        // a queue ordered by a comparator can be moved to another thread
+        let mut queue = BinaryHeapQueue::by_key(|k: &usize| std::cmp::Reverse(*k));
+        queue.insert(3);
+        queue.insert(1);
+        let handle = std::thread::spawn(move || queue.delete());
+        assert_eq!(handle.join().unwrap(), Some(1));
+    }
 }
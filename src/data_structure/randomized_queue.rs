@@ -0,0 +1,198 @@
+#[cfg(test)]
+mod unit_test;
+use crate::utils::knuth_shuffle;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cell::RefCell;
+
+/// Implementation of a randomized queue: the object removed is chosen
+/// uniformly at random among the objects in the queue.
+/// # Examples
+/// ```
+/// use algods::data_structure::RandomizedQueue;
+/// let mut queue = RandomizedQueue::with_seed(42);
+/// assert_eq!(queue.len(), 0);
+/// queue.enqueue(0);
+/// queue.enqueue(1);
+/// queue.enqueue(2);
+/// assert_eq!(queue.len(), 3);
+/// let item = queue.dequeue().unwrap();
+/// assert!(item < 3);
+/// assert_eq!(queue.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RandomizedQueue<T> {
+    // Contains the objects in insertion order, except when objects are removed
+    // (the last object takes the place of the removed one)
+    vec: Vec<T>,
+    // Random number generator, it is in a RefCell so that independent
+    // iterators can be built from a shared reference to the queue
+    rng: RefCell<StdRng>,
+}
+impl<T> Default for RandomizedQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> RandomizedQueue<T> {
+    /// Creates an empty randomized queue instance, whose random number generator
+    /// is seeded from the operating system.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RandomizedQueue;
+    /// let queue = RandomizedQueue::<usize>::new();
+    /// assert_eq!(queue.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            vec: Vec::new(),
+            rng: RefCell::new(StdRng::from_entropy()),
+        }
+    }
+
+    /// Creates an empty randomized queue instance, whose random number generator
+    /// is seeded with `seed`: two queues with the same seed and the same
+    /// operations produce the same results.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RandomizedQueue;
+    /// let mut q1 = RandomizedQueue::with_seed(1);
+    /// let mut q2 = RandomizedQueue::with_seed(1);
+    /// for k in 0..10 {
+    ///     q1.enqueue(k);
+    ///     q2.enqueue(k);
+    /// }
+    /// assert_eq!(q1.dequeue(), q2.dequeue());
+    /// ```
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            vec: Vec::new(),
+            rng: RefCell::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+
+    /// Tests whether or not the queue is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RandomizedQueue;
+    /// let queue = RandomizedQueue::<usize>::new();
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Gives the number of objects in the queue.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RandomizedQueue;
+    /// let queue = RandomizedQueue::<isize>::new();
+    /// assert_eq!(queue.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Inserts an object into the queue.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RandomizedQueue;
+    /// let mut queue = RandomizedQueue::<isize>::new();
+    /// queue.enqueue(-1);
+    /// assert_eq!(queue.dequeue(), Some(-1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) amortized
+    pub fn enqueue(&mut self, element: T) {
+        self.vec.push(element)
+    }
+
+    /// Deletes and returns an object chosen uniformly at random in the queue, if any.
+    /// Otherwise, it returns `None`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RandomizedQueue;
+    /// let mut queue = RandomizedQueue::new();
+    /// queue.enqueue(1);
+    /// assert_eq!(queue.dequeue(), Some(1));
+    /// assert_eq!(queue.dequeue(), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = self.rng.get_mut().gen_range(0..self.vec.len());
+        Some(self.vec.swap_remove(index))
+    }
+
+    /// Returns a reference to an object chosen uniformly at random in the queue,
+    /// without removing it, if any. Otherwise, it returns `None`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RandomizedQueue;
+    /// let mut queue = RandomizedQueue::new();
+    /// queue.enqueue(1);
+    /// assert_eq!(queue.sample(), Some(&1));
+    /// assert_eq!(queue.len(), 1);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn sample(&self) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = self.rng.borrow_mut().gen_range(0..self.vec.len());
+        self.vec.get(index)
+    }
+
+    /// Returns an iterator over the objects of the queue in a uniformly random order.
+    /// Each call gives an iterator whose order is independent of the others.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RandomizedQueue;
+    /// let mut queue = RandomizedQueue::new();
+    /// for k in 0..5 {
+    ///     queue.enqueue(k);
+    /// }
+    /// let mut items = queue.iter().copied().collect::<Vec<usize>>();
+    /// items.sort();
+    /// assert_eq!(items, vec![0, 1, 2, 3, 4]);
+    /// ```
+    /// # Time complexity
+    /// Building the iterator is expected to run in O(N), and each
+    /// step in O(1).
+    pub fn iter(&self) -> RandomizedIter<'_, T> {
+        let mut order = (0..self.vec.len()).collect::<Vec<usize>>();
+        knuth_shuffle(&mut order, &mut *self.rng.borrow_mut());
+        RandomizedIter {
+            vec: &self.vec,
+            order,
+        }
+    }
+}
+
+/// Iterator over the objects of a `RandomizedQueue` in a random order.
+#[derive(Debug)]
+pub struct RandomizedIter<'a, T> {
+    vec: &'a [T],
+    // positions in vec of the objects that are not visited yet
+    order: Vec<usize>,
+}
+impl<'a, T> Iterator for RandomizedIter<'a, T> {
+    type Item = &'a T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.order.pop().map(|index| &self.vec[index])
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.order.len(), Some(self.order.len()))
+    }
+}
+impl<'a, T> IntoIterator for &'a RandomizedQueue<T> {
+    type Item = &'a T;
+    type IntoIter = RandomizedIter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_randomized_queue_new() {
+        let mut queue = RandomizedQueue::<usize>::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.sample(), None);
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.iter().next(), None);
+    }
+
+    #[test]
+    fn test_randomized_queue_dequeue() {
+        let mut queue = RandomizedQueue::with_seed(0);
+        for k in 0..100 {
+            queue.enqueue(k);
+        }
+        assert_eq!(queue.len(), 100);
+        let mut items = Vec::new();
+        while let Some(item) = queue.dequeue() {
+            items.push(item);
+        }
+        assert!(queue.is_empty());
+        assert_ne!(items, (0..100).collect::<Vec<usize>>());
+        items.sort();
+        assert_eq!(items, (0..100).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_randomized_queue_seed() {
+        let mut q1 = RandomizedQueue::with_seed(3);
+        let mut q2 = RandomizedQueue::with_seed(3);
+        for k in 0..50 {
+            q1.enqueue(k);
+            q2.enqueue(k);
+        }
+        assert!(q1.iter().eq(q2.iter()));
+        assert_eq!(q1.sample(), q2.sample());
+        for _ in 0..50 {
+            assert_eq!(q1.dequeue(), q2.dequeue());
+        }
+    }
+
+    #[test]
+    fn test_randomized_queue_independent_iterators() {
+        let mut queue = RandomizedQueue::with_seed(5);
+        for k in 0..50 {
+            queue.enqueue(k);
+        }
+        let first = queue.iter().copied().collect::<Vec<usize>>();
+        let second = (&queue).into_iter().copied().collect::<Vec<usize>>();
+        assert_eq!(first.len(), 50);
+        assert_ne!(first, second);
+        assert_eq!(queue.iter().size_hint(), (50, Some(50)));
+    }
+
+    #[test]
+    fn test_randomized_queue_uniformity() {
+        let mut queue = RandomizedQueue::with_seed(11);
+        let trials = 30000;
+        let mut counts = [0usize; 4];
+        for _ in 0..trials {
+            for k in 0..4 {
+                queue.enqueue(k);
+            }
+            counts[queue.dequeue().unwrap()] += 1;
+            while queue.dequeue().is_some() {}
+        }
+        for count in counts.iter() {
+            assert!((*count as f64 / trials as f64 - 0.25).abs() < 0.02);
+        }
+    }
+}
@@ -0,0 +1,309 @@
+#[cfg(test)]
+mod unit_test;
+use crate::compression::{BitSlice, BitVec, Lsb0};
+use bitvec::{order::BitOrder, store::BitStore};
+
+// Number of bits covered by an entry of the rank directory (8 words)
+const BLOCK_BITS: usize = 512;
+const BLOCK_WORDS: usize = BLOCK_BITS / 64;
+// One every SAMPLE_RATE ones (resp. zeros) has the index of its block sampled
+const SAMPLE_RATE: usize = 4096;
+
+/// Implementation of a static bit vector answering rank and select queries.
+/// It wraps a `BitVec` along with a rank directory holding the number of ones
+/// before each block of 512 bits (12.5% of extra space), and select directories
+/// sampling the blocks of one every 4096 ones (resp. zeros).
+/// # Examples
+/// ```
+/// use algods::compression::*;
+/// use algods::data_structure::RankSelectBitVec;
+/// let bits = RankSelectBitVec::from_bitslice(bits![0, 1, 1, 0, 1, 0, 0, 1]);
+/// assert_eq!(bits.rank1(4), 2);
+/// assert_eq!(bits.rank0(4), 2);
+/// assert_eq!(bits.select1(2), Some(4));
+/// assert_eq!(bits.select0(3), Some(6));
+/// assert_eq!(bits.select1(4), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RankSelectBitVec {
+    bits: BitVec<u64, Lsb0>,
+    // ranks[b] is the number of ones before the block b (with a last entry for all the bits)
+    ranks: Vec<usize>,
+    // samples of the blocks containing the (k * SAMPLE_RATE)-th one (resp. zero)
+    ones: Vec<usize>,
+    zeros: Vec<usize>,
+}
+impl RankSelectBitVec {
+    /// Builds the rank and select directories over a `BitVec`.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::init(BitVec::from_bitslice(bits![u64, Lsb0; 1, 0, 1]));
+    /// assert_eq!(bits.len(), 3);
+    /// assert_eq!(bits.count_ones(), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn init(mut bits: BitVec<u64, Lsb0>) -> Self {
+        // bit i is then bit i % 64 of word i / 64, and unused bits are zeros
+        bits.force_align();
+        bits.set_uninitialized(false);
+        let words = bits.as_raw_slice();
+        let mut ranks = Vec::with_capacity(words.len() / BLOCK_WORDS + 2);
+        let (mut ones, mut zeros) = (Vec::new(), Vec::new());
+        let mut rank = 0;
+        for (block, chunk) in words.chunks(BLOCK_WORDS).enumerate() {
+            ranks.push(rank);
+            let count = chunk.iter().map(|w| w.count_ones() as usize).sum::<usize>();
+            let zero_rank = block * BLOCK_BITS - rank;
+            let zero_count = (bits.len() - block * BLOCK_BITS).min(BLOCK_BITS) - count;
+            // samples whose one (resp. zero) lies in this block
+            while ones.len() * SAMPLE_RATE < rank + count {
+                ones.push(block);
+            }
+            while zeros.len() * SAMPLE_RATE < zero_rank + zero_count {
+                zeros.push(block);
+            }
+            rank += count;
+        }
+        ranks.push(rank);
+        Self {
+            bits,
+            ranks,
+            ones,
+            zeros,
+        }
+    }
+    /// Builds the rank and select directories over a copy of a slice of bits.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![u8, Msb0; 1, 1, 0]);
+    /// assert_eq!(bits.rank1(2), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_bitslice<T: BitStore, O: BitOrder>(bits: &BitSlice<T, O>) -> Self {
+        Self::init(bits.iter().by_vals().collect())
+    }
+    /// Gives the number of bits.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![1, 0]);
+    /// assert_eq!(bits.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.bits.len()
+    }
+    /// Tests whether or not there is no bit.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::init(BitVec::new());
+    /// assert!(bits.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.bits.is_empty()
+    }
+    /// Returns a reference to the underlying bits.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![1, 0]);
+    /// assert_eq!(bits.bits(), bits![1, 0]);
+    /// ```
+    pub fn bits(&self) -> &BitVec<u64, Lsb0> {
+        &self.bits
+    }
+    /// Gives the bit at a given position.
+    /// # Panics
+    /// If the position is out of bounds, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![1, 0]);
+    /// assert!(bits.get(0));
+    /// assert!(!bits.get(1));
+    /// ```
+    pub fn get(&self, position: usize) -> bool {
+        if position >= self.len() {
+            panic!(
+                "position {position} is out of bounds, there are {} bits",
+                self.len()
+            );
+        }
+        self.bits[position]
+    }
+    /// Gives the number of ones.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![1, 0, 1]);
+    /// assert_eq!(bits.count_ones(), 2);
+    /// ```
+    pub fn count_ones(&self) -> usize {
+        self.ranks[self.ranks.len() - 1]
+    }
+    /// Gives the number of zeros.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![1, 0, 1]);
+    /// assert_eq!(bits.count_zeros(), 1);
+    /// ```
+    pub fn count_zeros(&self) -> usize {
+        self.len() - self.count_ones()
+    }
+    /// Gives the number of ones before a position (excluded).
+    /// # Panics
+    /// If `position > self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![1, 0, 1]);
+    /// assert_eq!(bits.rank1(0), 0);
+    /// assert_eq!(bits.rank1(3), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn rank1(&self, position: usize) -> usize {
+        if position > self.len() {
+            panic!(
+                "position {position} is out of bounds, there are {} bits",
+                self.len()
+            );
+        }
+        let words = self.bits.as_raw_slice();
+        let (word, offset) = (position / 64, position % 64);
+        let mut rank = self.ranks[position / BLOCK_BITS];
+        for w in &words[word - word % BLOCK_WORDS..word] {
+            rank += w.count_ones() as usize;
+        }
+        if offset > 0 {
+            rank += (words[word] & ((1 << offset) - 1)).count_ones() as usize;
+        }
+        rank
+    }
+    /// Gives the number of zeros before a position (excluded).
+    /// # Panics
+    /// If `position > self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![1, 0, 1]);
+    /// assert_eq!(bits.rank0(3), 1);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn rank0(&self, position: usize) -> usize {
+        position - self.rank1(position)
+    }
+    /// Gives the position of the k-th one (counting from 0), if any.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![0, 1, 1]);
+    /// assert_eq!(bits.select1(0), Some(1));
+    /// assert_eq!(bits.select1(2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(B)) where B is the number of blocks between two samples,
+    /// that is in O(1) when the ones are evenly spread.
+    pub fn select1(&self, k: usize) -> Option<usize> {
+        if k >= self.count_ones() {
+            return None;
+        }
+        let block = self.select_block(k, &self.ones, |b| self.ranks[b]);
+        Some(self.select_in_block(block, k - self.ranks[block], |w| w))
+    }
+    /// Gives the position of the k-th zero (counting from 0), if any.
+    /// # Example
+    /// ```
+    /// use algods::compression::*;
+    /// use algods::data_structure::RankSelectBitVec;
+    /// let bits = RankSelectBitVec::from_bitslice(bits![0, 1, 1, 0]);
+    /// assert_eq!(bits.select0(1), Some(3));
+    /// assert_eq!(bits.select0(2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(B)) where B is the number of blocks between two samples,
+    /// that is in O(1) when the zeros are evenly spread.
+    pub fn select0(&self, k: usize) -> Option<usize> {
+        if k >= self.count_zeros() {
+            return None;
+        }
+        let zero_rank = |b: usize| b * BLOCK_BITS - self.ranks[b];
+        let block = self.select_block(k, &self.zeros, zero_rank);
+        // unused bits of the last word are zeros, but are never selected
+        // since there are fewer than k zeros before them
+        Some(self.select_in_block(block, k - zero_rank(block), |w| !w))
+    }
+    // Finds the last block whose rank is at most k, among the blocks between two samples
+    fn select_block(&self, k: usize, samples: &[usize], rank: impl Fn(usize) -> usize) -> usize {
+        let sample = k / SAMPLE_RATE;
+        let (mut low, mut high) = (
+            samples[sample],
+            samples
+                .get(sample + 1)
+                .map_or(self.ranks.len() - 1, |b| b + 1),
+        );
+        // invariant: rank(low) <= k < rank(high)
+        while high - low > 1 {
+            let mid = low + (high - low) / 2;
+            if rank(mid) <= k {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+    // Finds the position of the k-th set bit of the block, after applying `map` to its words
+    fn select_in_block(&self, block: usize, mut k: usize, map: impl Fn(u64) -> u64) -> usize {
+        let words = self.bits.as_raw_slice();
+        let mut index = block * BLOCK_WORDS;
+        loop {
+            let word = map(words[index]);
+            let count = word.count_ones() as usize;
+            if k < count {
+                return index * 64 + select_in_word(word, k);
+            }
+            k -= count;
+            index += 1;
+        }
+    }
+}
+// Finds the position of the k-th set bit of a word, byte per byte
+fn select_in_word(word: u64, mut k: usize) -> usize {
+    let mut shift = 0;
+    loop {
+        let count = (word >> shift & 0xff).count_ones() as usize;
+        if k < count {
+            break;
+        }
+        k -= count;
+        shift += 8;
+    }
+    let mut byte = word >> shift & 0xff;
+    for _ in 0..k {
+        byte &= byte - 1;
+    }
+    shift + byte.trailing_zeros() as usize
+}
+impl FromIterator<bool> for RankSelectBitVec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        Self::init(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn check_against_naive(bits: &[bool]) {
+        let rs = bits.iter().copied().collect::<RankSelectBitVec>();
+        assert_eq!(rs.len(), bits.len());
+        let mut rank = 0;
+        let (mut ones, mut zeros) = (Vec::new(), Vec::new());
+        for (i, bit) in bits.iter().enumerate() {
+            assert_eq!(rs.rank1(i), rank);
+            assert_eq!(rs.rank0(i), i - rank);
+            assert_eq!(rs.get(i), *bit);
+            if *bit {
+                rank += 1;
+                ones.push(i);
+            } else {
+                zeros.push(i);
+            }
+        }
+        assert_eq!(rs.rank1(bits.len()), rank);
+        assert_eq!(rs.count_ones(), ones.len());
+        for (k, position) in ones.iter().enumerate() {
+            assert_eq!(rs.select1(k), Some(*position));
+        }
+        for (k, position) in zeros.iter().enumerate() {
+            assert_eq!(rs.select0(k), Some(*position));
+        }
+        assert_eq!(rs.select1(ones.len()), None);
+        assert_eq!(rs.select0(zeros.len()), None);
+    }
+
+    #[test]
+    fn test_rank_select_random_densities() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for density in [0.0, 0.001, 0.1, 0.5, 0.9, 0.999, 1.0] {
+            for len in [0, 1, 63, 64, 65, 511, 512, 513, 20_000] {
+                let bits = (0..len)
+                    .map(|_| rng.gen_bool(density))
+                    .collect::<Vec<bool>>();
+                check_against_naive(&bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rank_select_clustered_bits() {
+        // long runs make the blocks between two samples uneven
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut bits = Vec::new();
+        while bits.len() < 100_000 {
+            let run = rng.gen_range(1..10_000);
+            let bit = rng.gen_bool(0.5);
+            bits.extend(std::iter::repeat_n(bit, run));
+        }
+        check_against_naive(&bits);
+    }
+
+    #[test]
+    fn test_rank_select_unaligned_bitslice() {
+        let bits = crate::compression::bits![u8, Lsb0; 1, 1, 0, 1, 0, 0, 1, 1, 1, 0];
+        let rs = RankSelectBitVec::from_bitslice(&bits[3..]);
+        assert_eq!(rs.len(), 7);
+        assert_eq!(rs.count_ones(), 4);
+        assert_eq!(rs.select1(1), Some(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rank_select_rank_out_of_bounds() {
+        let rs = RankSelectBitVec::from_iter([true, false]);
+        rs.rank1(3);
+    }
+}
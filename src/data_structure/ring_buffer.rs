@@ -0,0 +1,473 @@
+#[cfg(test)]
+mod unit_test;
+
+/// Implementation of deques using a circular `Vec` with
+/// capacity doubling when full and size halving when 25% full
+/// # Examples
+/// ```
+/// use algods::data_structure::RingDeque;
+/// let mut deque = RingDeque::new();
+/// assert_eq!(deque.len(), 0);
+/// deque.add_first(0);
+/// deque.add_last(1);
+/// deque.add_first(2);
+/// assert_eq!(deque.len(), 3);
+/// assert_eq!(deque.get(1), Some(&0));
+/// assert_eq!(deque.remove_last(), Some(1));
+/// assert_eq!(deque.remove_first(), Some(2));
+/// assert_eq!(deque.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingDeque<T> {
+    // Contains the objects, the slots not in use are None
+    vec: Vec<Option<T>>,
+    // position of the first object in vec
+    head: usize,
+    // number of objects in the deque
+    n: usize,
+    // Remarks:
+    // - the objects are stored in vec from index head to index (head + n - 1) % vec.len()
+    //   wrapping around the end of vec when needed
+    // - vec.len() is never 0 so that the modulo operations are always defined
+}
+impl<T> Default for RingDeque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> RingDeque<T> {
+    /// Creates an empty deque instance.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let deque = RingDeque::<usize>::new();
+    /// assert_eq!(deque.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            vec: vec![None],
+            head: 0,
+            n: 0,
+        }
+    }
+
+    /// Creates an empty deque with an initial capacity.
+    /// # Panics
+    /// If `capacity = 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let deque = RingDeque::<usize>::with_capacity(4);
+    /// assert_eq!(deque.len(), 0);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        // run time complexity O(capacity)
+        if capacity > 0 {
+            Self {
+                vec: Self::empty_slots(capacity),
+                head: 0,
+                n: 0,
+            }
+        } else {
+            panic!("capacity should be > 0");
+        }
+    }
+
+    /// Creates a new deque with an initial object.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let deque = RingDeque::init("deque");
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    pub fn init(s: T) -> Self {
+        let mut res = Self::new();
+        res.add_last(s);
+        res
+    }
+
+    /// Tests whether or not the deque is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let deque = RingDeque::<usize>::new();
+    /// assert!(deque.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Gives the number of objects in the deque.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let deque = RingDeque::<isize>::new();
+    /// assert_eq!(deque.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Inserts an object at the beginning of the deque.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::<isize>::new();
+    /// deque.add_first(-1);
+    /// deque.add_first(-2);
+    /// assert_eq!(deque.remove_first(), Some(-2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) amortized
+    pub fn add_first(&mut self, elt: T) {
+        if self.n == self.vec.len() {
+            self.resize(2 * self.vec.len());
+        }
+        self.head = (self.head + self.vec.len() - 1) % self.vec.len();
+        self.vec[self.head] = Some(elt);
+        self.n += 1;
+    }
+
+    /// Inserts an object at the end of the deque.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::<isize>::new();
+    /// deque.add_last(-1);
+    /// deque.add_last(-2);
+    /// assert_eq!(deque.remove_first(), Some(-1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) amortized
+    pub fn add_last(&mut self, elt: T) {
+        if self.n == self.vec.len() {
+            self.resize(2 * self.vec.len());
+        }
+        let tail = self.slot(self.n);
+        self.vec[tail] = Some(elt);
+        self.n += 1;
+    }
+
+    /// Deletes and returns the first object in the deque, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::init(1);
+    /// deque.add_last(0);
+    /// deque.add_last(1);
+    /// assert_eq!(deque.remove_first(), Some(1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) amortized
+    pub fn remove_first(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let elt = self.vec[self.head].take();
+        self.head = (self.head + 1) % self.vec.len();
+        self.n -= 1;
+        self.shrink();
+        elt
+    }
+
+    /// Deletes and returns the last object in the deque, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::init(1);
+    /// deque.add_last(0);
+    /// deque.add_last(1);
+    /// assert_eq!(deque.remove_last(), Some(1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) amortized
+    pub fn remove_last(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let tail = self.slot(self.n - 1);
+        let elt = self.vec[tail].take();
+        self.n -= 1;
+        self.shrink();
+        elt
+    }
+
+    /// Returns a reference to the first object in the deque, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::init(1);
+    /// deque.add_last(0);
+    /// assert_eq!(deque.first(), Some(&1));
+    /// ```
+    pub fn first(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the last object in the deque, if any.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::init(1);
+    /// deque.add_last(0);
+    /// assert_eq!(deque.last(), Some(&0));
+    /// ```
+    pub fn last(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            self.get(self.n - 1)
+        }
+    }
+
+    /// Returns a reference to the object at position `index` from
+    /// the beginning of the deque, if any. Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::new();
+    /// deque.add_last("b");
+    /// deque.add_first("a");
+    /// assert_eq!(deque.get(1), Some(&"b"));
+    /// assert_eq!(deque.get(2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index < self.n {
+            self.vec[self.slot(index)].as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the object at position `index` from
+    /// the beginning of the deque, if any. Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::init(0);
+    /// if let Some(elt) = deque.get_mut(0) {
+    ///     *elt = 5;
+    /// }
+    /// assert_eq!(deque.get(0), Some(&5));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index < self.n {
+            let slot = self.slot(index);
+            self.vec[slot].as_mut()
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the objects of the deque, from the first to the last.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::RingDeque;
+    /// let mut deque = RingDeque::new();
+    /// deque.add_last(1);
+    /// deque.add_last(2);
+    /// deque.add_first(0);
+    /// assert_eq!(deque.iter().collect::<Vec<&usize>>(), vec![&0, &1, &2]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        (0..self.n).filter_map(move |index| self.get(index))
+    }
+
+    fn slot(&self, index: usize) -> usize {
+        // position in self.vec of the object at position index in the deque
+        (self.head + index) % self.vec.len()
+    }
+
+    fn empty_slots(capacity: usize) -> Vec<Option<T>> {
+        let mut vector = Vec::with_capacity(capacity);
+        for _ in 0..capacity {
+            vector.push(None);
+        }
+        vector
+    }
+
+    fn shrink(&mut self) {
+        // halving the size of the deque when it is 25% full
+        if self.vec.len() > 1 && self.n <= self.vec.len() / 4 {
+            self.resize(self.vec.len() / 2);
+        }
+    }
+
+    fn resize(&mut self, capacity: usize) {
+        // run time complexity O(N)
+        // moves the objects to a new vec, the first one being at index 0
+        let mut vector = Self::empty_slots(capacity);
+        for (index, slot) in vector.iter_mut().enumerate().take(self.n) {
+            let old_slot = (self.head + index) % self.vec.len();
+            *slot = self.vec[old_slot].take();
+        }
+        self.vec = vector;
+        self.head = 0;
+    }
+}
+
+/// Implementation of a First In First Out queue with a fixed capacity,
+/// using a circular `Vec`.
+///
+/// Inserting an object in a full queue is rejected and the object is
+/// given back to the caller, so that producers can detect overflows.
+/// # Examples
+/// ```
+/// use algods::data_structure::BoundedQueue;
+/// let mut queue = BoundedQueue::with_capacity(2);
+/// assert_eq!(queue.enqueue(0), Ok(()));
+/// assert_eq!(queue.enqueue(1), Ok(()));
+/// assert!(queue.is_full());
+/// assert_eq!(queue.enqueue(2), Err(2));
+/// assert_eq!(queue.dequeue(), Some(0));
+/// assert_eq!(queue.enqueue(2), Ok(()));
+/// assert_eq!(queue.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BoundedQueue<T> {
+    // Contains the objects, the slots not in use are None
+    vec: Vec<Option<T>>,
+    // position of the first object in vec
+    head: usize,
+    // number of objects in the queue
+    n: usize,
+}
+impl<T> BoundedQueue<T> {
+    /// Creates an empty queue able to hold at most `capacity` objects.
+    /// # Panics
+    /// If `capacity = 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BoundedQueue;
+    /// let queue = BoundedQueue::<usize>::with_capacity(3);
+    /// assert_eq!(queue.capacity(), 3);
+    /// assert_eq!(queue.len(), 0);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        // run time complexity O(capacity)
+        if capacity > 0 {
+            let mut vector = Vec::with_capacity(capacity);
+            for _ in 0..capacity {
+                vector.push(None);
+            }
+            Self {
+                vec: vector,
+                head: 0,
+                n: 0,
+            }
+        } else {
+            panic!("capacity should be > 0");
+        }
+    }
+
+    /// Gives the maximum number of objects the queue can hold.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BoundedQueue;
+    /// let queue = BoundedQueue::<usize>::with_capacity(1);
+    /// assert_eq!(queue.capacity(), 1);
+    /// ```
+    pub fn capacity(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Tests whether or not the queue is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BoundedQueue;
+    /// let queue = BoundedQueue::<usize>::with_capacity(1);
+    /// assert!(queue.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Tests whether or not the queue is full.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BoundedQueue;
+    /// let mut queue = BoundedQueue::with_capacity(1);
+    /// queue.enqueue("full").unwrap();
+    /// assert!(queue.is_full());
+    /// ```
+    pub fn is_full(&self) -> bool {
+        self.n == self.vec.len()
+    }
+
+    /// Gives the number of objects in the queue.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BoundedQueue;
+    /// let queue = BoundedQueue::<isize>::with_capacity(1);
+    /// assert_eq!(queue.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    /// Inserts an object at the end of the queue. When the queue is full,
+    /// the object is not inserted and it is returned in the `Err` variant.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BoundedQueue;
+    /// let mut queue = BoundedQueue::<isize>::with_capacity(1);
+    /// assert_eq!(queue.enqueue(-1), Ok(()));
+    /// assert_eq!(queue.enqueue(-2), Err(-2));
+    /// assert_eq!(queue.dequeue(), Some(-1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn enqueue(&mut self, element: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(element);
+        }
+        let tail = (self.head + self.n) % self.vec.len();
+        self.vec[tail] = Some(element);
+        self.n += 1;
+        Ok(())
+    }
+
+    /// Deletes and returns the first object in the queue, if any.
+    /// Otherwise, it returns `None`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BoundedQueue;
+    /// let mut queue = BoundedQueue::with_capacity(1);
+    /// queue.enqueue(1).unwrap();
+    /// assert_eq!(queue.dequeue(), Some(1));
+    /// assert_eq!(queue.dequeue(), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn dequeue(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let elt = self.vec[self.head].take();
+        self.head = (self.head + 1) % self.vec.len();
+        self.n -= 1;
+        elt
+    }
+
+    /// Returns a reference to the first object in the queue, if any.
+    /// Otherwise, it returns `None`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::BoundedQueue;
+    /// let mut queue = BoundedQueue::with_capacity(2);
+    /// queue.enqueue(1).unwrap();
+    /// queue.enqueue(2).unwrap();
+    /// assert_eq!(queue.peek(), Some(&1));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        self.vec[self.head].as_ref()
+    }
+}
@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+
+    #[test]
+    fn test_ring_deque_new() {
+        let deque = RingDeque::<usize>::new();
+        assert!(deque.is_empty());
+        assert_eq!(deque.vec.len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ring_deque_with_capacity_panic() {
+        RingDeque::<isize>::with_capacity(0);
+    }
+
+    #[test]
+    fn test_ring_deque_mutations() {
+        let mut deque = RingDeque::new();
+        deque.add_first(-1isize);
+        deque.add_last(-2);
+        deque.add_first(-3);
+        deque.add_last(-4);
+        assert_eq!(
+            deque.iter().copied().collect::<Vec<isize>>(),
+            vec![-3, -1, -2, -4]
+        );
+        assert_eq!(Some(-3), deque.remove_first());
+        assert_eq!(Some(-4), deque.remove_last());
+        assert_eq!(Some(-2), deque.remove_last());
+        assert_eq!(Some(-1), deque.remove_first());
+        assert_eq!(None, deque.remove_first());
+        assert_eq!(None, deque.remove_last());
+    }
+
+    #[test]
+    fn test_ring_deque_wrap_around() {
+        let mut deque = RingDeque::with_capacity(4);
+        for k in 0..3 {
+            deque.add_last(k);
+        }
+        deque.remove_first();
+        deque.add_last(3);
+        deque.add_last(4);
+        // the objects now wrap around the end of the vec
+        assert_eq!(deque.vec.len(), 4);
+        assert_eq!(deque.head, 1);
+        for k in 0..4 {
+            assert_eq!(deque.get(k), Some(&(k + 1)));
+        }
+        assert_eq!(deque.get(4), None);
+    }
+
+    #[test]
+    fn test_ring_deque_resize() {
+        let mut deque = RingDeque::new();
+        deque.add_first(1);
+        assert_eq!(deque.vec.len(), 1);
+        deque.add_first(2);
+        assert_eq!(deque.vec.len(), 2);
+        deque.add_last(3);
+        assert_eq!(deque.vec.len(), 4);
+        deque.add_last(4);
+        deque.add_last(5);
+        assert_eq!(deque.vec.len(), 8);
+        deque.remove_first();
+        deque.remove_first();
+        deque.remove_last();
+        assert_eq!(deque.vec.len(), 4);
+        assert_eq!(deque.first(), Some(&3));
+        assert_eq!(deque.last(), Some(&4));
+        deque.remove_last();
+        deque.remove_last();
+        assert_eq!(deque.vec.len(), 1);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_ring_deque_against_std() {
+        let mut deque = RingDeque::new();
+        let mut reference = std::collections::VecDeque::new();
+        for k in 0..1000usize {
+            match k % 5 {
+                0 | 1 => {
+                    deque.add_last(k);
+                    reference.push_back(k);
+                }
+                2 => {
+                    deque.add_first(k);
+                    reference.push_front(k);
+                }
+                3 => assert_eq!(deque.remove_first(), reference.pop_front()),
+                _ => assert_eq!(deque.remove_last(), reference.pop_back()),
+            }
+            assert_eq!(deque.len(), reference.len());
+        }
+        assert!(deque.iter().eq(reference.iter()));
+    }
+
+    #[test]
+    fn test_bounded_queue() {
+        let mut queue = BoundedQueue::with_capacity(3);
+        assert!(queue.is_empty());
+        assert_eq!(queue.peek(), None);
+        for k in 0..3 {
+            assert_eq!(queue.enqueue(k), Ok(()));
+        }
+        assert!(queue.is_full());
+        assert_eq!(queue.enqueue(3), Err(3));
+        assert_eq!(queue.dequeue(), Some(0));
+        assert_eq!(queue.enqueue(3), Ok(()));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.capacity(), 3);
+        for k in 1..4 {
+            assert_eq!(queue.dequeue(), Some(k));
+        }
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_bounded_queue_with_capacity_panic() {
+        BoundedQueue::<usize>::with_capacity(0);
+    }
+}
@@ -0,0 +1,186 @@
+mod lazy;
+mod monoid;
+#[cfg(test)]
+mod unit_test;
+pub use lazy::LazySegmentTree;
+pub use monoid::{Gcd, Idempotent, LazyMonoid, Max, Min, Monoid, Number, Selective, Sum};
+use std::marker::PhantomData;
+
+fn check_range(n: usize, low: usize, high: usize) {
+    if high > n {
+        panic!("range end {high} is out of bounds, the tree has {n} objects");
+    }
+    if low > high {
+        panic!("range start {low} is larger than range end {high}");
+    }
+}
+
+fn check_index(n: usize, index: usize) {
+    if index >= n {
+        panic!("index {index} is out of bounds, the tree has {n} objects");
+    }
+}
+
+/// Implementation of a segment tree: a binary tree whose nodes hold the aggregate,
+/// by the monoid `Op`, of a contiguous range of objects. It supports point updates,
+/// see `LazySegmentTree` for range updates.
+/// # Example
+/// ```
+/// use algods::data_structure::{SegmentTree, Min};
+/// let mut tree = SegmentTree::<isize, Min<isize>>::from_vec(vec![5, 3, 8, 6, 1, 4]);
+/// assert_eq!(tree.query(0, 3), 3);
+/// tree.set(1, 9);
+/// assert_eq!(tree.query(0, 3), 5);
+/// assert_eq!(tree.query(0, 6), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegmentTree<T, Op> {
+    // number of objects
+    n: usize,
+    // aggregates of the nodes
+    tree: Vec<T>,
+    op: PhantomData<Op>,
+    // Remarks:
+    // - the root is node 1 and covers the range [0, n)
+    // - each node k covering [low, high) has children 2k covering [low, mid)
+    //   and 2k+1 covering [mid, high) where mid = (low + high) / 2
+}
+impl<T: Clone, Op: Monoid<T>> SegmentTree<T, Op> {
+    /// Creates a segment tree of `n` objects all equal to the identity of the monoid.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SegmentTree, Sum};
+    /// let tree = SegmentTree::<isize, Sum<isize>>::new(10);
+    /// assert_eq!(tree.len(), 10);
+    /// assert_eq!(tree.query(0, 10), 0);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        Self::from_vec(vec![Op::identity(); n])
+    }
+    /// Creates a segment tree from a `Vec` of objects.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SegmentTree, Max};
+    /// let tree = SegmentTree::<f64, Max<f64>>::from_vec(vec![0.5, 2.5, -1.]);
+    /// assert_eq!(tree.query(0, 3), 2.5);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let n = vec.len();
+        let mut tree = Self {
+            n,
+            tree: vec![Op::identity(); 4 * n.max(1)],
+            op: PhantomData,
+        };
+        if n > 0 {
+            tree.build(1, 0, n, &vec);
+        }
+        tree
+    }
+    /// Gives the number of objects in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SegmentTree, Sum};
+    /// let tree = SegmentTree::<isize, Sum<isize>>::from_vec(vec![1, 2]);
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.n
+    }
+    /// Tests whether or not the tree is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SegmentTree, Sum};
+    /// let tree = SegmentTree::<isize, Sum<isize>>::new(0);
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+    fn build(&mut self, node: usize, low: usize, high: usize, vec: &[T]) {
+        if high - low == 1 {
+            self.tree[node] = vec[low].clone();
+            return;
+        }
+        let mid = (low + high) / 2;
+        self.build(2 * node, low, mid, vec);
+        self.build(2 * node + 1, mid, high, vec);
+        self.pull(node);
+    }
+    fn pull(&mut self, node: usize) {
+        self.tree[node] = Op::combine(&self.tree[2 * node], &self.tree[2 * node + 1]);
+    }
+    /// Returns the aggregate of the objects between two positions (low included, high excluded).
+    /// It is the identity of the monoid when `low = high`.
+    /// # Panics
+    /// If `high > self.len()` or `low > high`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SegmentTree, Sum};
+    /// let tree = SegmentTree::<isize, Sum<isize>>::from_vec(vec![1, -2, 3, 4]);
+    /// assert_eq!(tree.query(1, 3), 1);
+    /// assert_eq!(tree.query(2, 2), 0);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn query(&self, low: usize, high: usize) -> T {
+        check_range(self.n, low, high);
+        self.aggregate(1, 0, self.n, low, high)
+    }
+    fn aggregate(&self, node: usize, low: usize, high: usize, qlow: usize, qhigh: usize) -> T {
+        if qhigh <= low || high <= qlow {
+            return Op::identity();
+        }
+        if qlow <= low && high <= qhigh {
+            return self.tree[node].clone();
+        }
+        let mid = (low + high) / 2;
+        let left = self.aggregate(2 * node, low, mid, qlow, qhigh);
+        let right = self.aggregate(2 * node + 1, mid, high, qlow, qhigh);
+        Op::combine(&left, &right)
+    }
+    /// Returns the object at a given position.
+    /// # Panics
+    /// If `index >= self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SegmentTree, Sum};
+    /// let tree = SegmentTree::<isize, Sum<isize>>::from_vec(vec![1, -2, 3, 4]);
+    /// assert_eq!(tree.get(1), -2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn get(&self, index: usize) -> T {
+        self.query(index, index + 1)
+    }
+    fn update(&mut self, node: usize, low: usize, high: usize, index: usize, value: T) {
+        if high - low == 1 {
+            self.tree[node] = value;
+            return;
+        }
+        let mid = (low + high) / 2;
+        if index < mid {
+            self.update(2 * node, low, mid, index, value);
+        } else {
+            self.update(2 * node + 1, mid, high, index, value);
+        }
+        self.pull(node);
+    }
+    /// Replaces the object at a given position.
+    /// # Panics
+    /// If `index >= self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SegmentTree, Max};
+    /// let mut tree = SegmentTree::<isize, Max<isize>>::from_vec(vec![1, -2, 3, 4]);
+    /// tree.set(3, -5);
+    /// assert_eq!(tree.query(0, 4), 3);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn set(&mut self, index: usize, value: T) {
+        check_index(self.n, index);
+        self.update(1, 0, self.n, index, value);
+    }
+}
@@ -0,0 +1,250 @@
+use super::{check_index, check_range, LazyMonoid};
+use std::marker::PhantomData;
+
+/// Implementation of a segment tree with lazy propagated range updates:
+/// adding the same value to every object of a range is done in O(log(N)),
+/// the update being pushed down to the children of a node only when they are visited.
+/// # Examples
+/// ```
+/// use algods::data_structure::{LazySegmentTree, Min, Sum};
+/// let mut tree = LazySegmentTree::<isize, Min<isize>>::from_vec(vec![5, 3, 8, 6, 1, 4]);
+/// assert_eq!(tree.query(0, 3), 3);
+/// tree.set(1, 9);
+/// assert_eq!(tree.query(0, 3), 5);
+/// tree.range_add(0, 2, -10);
+/// assert_eq!(tree.query(0, 6), -5);
+///
+/// let mut tree = LazySegmentTree::<usize, Sum<usize>>::from_vec(vec![1, 2, 3, 4]);
+/// tree.range_add(1, 3, 10);
+/// assert_eq!(tree.query(0, 4), 30);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LazySegmentTree<T, Op> {
+    // number of objects
+    n: usize,
+    // aggregates of the nodes
+    tree: Vec<T>,
+    // pending updates that are not yet propagated to the children of the nodes
+    lazy: Vec<Option<T>>,
+    op: PhantomData<Op>,
+    // Remarks:
+    // - the nodes are laid out as in SegmentTree
+    // - the aggregate of a node always takes into account its own pending update
+}
+impl<T: Clone, Op: LazyMonoid<T>> LazySegmentTree<T, Op> {
+    /// Creates a segment tree of `n` objects all equal to the identity of the monoid.
+    /// For `Min` and `Max`, range updates leave the identity unchanged, as an infinite value.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{LazySegmentTree, Min, Sum};
+    /// let tree = LazySegmentTree::<isize, Sum<isize>>::new(10);
+    /// assert_eq!(tree.len(), 10);
+    /// assert_eq!(tree.query(0, 10), 0);
+    ///
+    /// let mut tree = LazySegmentTree::<isize, Min<isize>>::new(4);
+    /// tree.set(0, 3);
+    /// tree.range_add(0, 4, 1);
+    /// assert_eq!(tree.query(0, 2), 4);
+    /// assert_eq!(tree.query(1, 2), isize::MAX);
+    /// ```
+    pub fn new(n: usize) -> Self {
+        Self::from_vec(vec![Op::identity(); n])
+    }
+    /// Creates a segment tree from a `Vec` of objects.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{LazySegmentTree, Max};
+    /// let tree = LazySegmentTree::<f64, Max<f64>>::from_vec(vec![0.5, 2.5, -1.]);
+    /// assert_eq!(tree.query(0, 3), 2.5);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let n = vec.len();
+        let mut tree = Self {
+            n,
+            tree: vec![Op::identity(); 4 * n.max(1)],
+            lazy: vec![None; 4 * n.max(1)],
+            op: PhantomData,
+        };
+        if n > 0 {
+            tree.build(1, 0, n, &vec);
+        }
+        tree
+    }
+    /// Gives the number of objects in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{LazySegmentTree, Sum};
+    /// let tree = LazySegmentTree::<isize, Sum<isize>>::from_vec(vec![1, 2]);
+    /// assert_eq!(tree.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.n
+    }
+    /// Tests whether or not the tree is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{LazySegmentTree, Sum};
+    /// let tree = LazySegmentTree::<isize, Sum<isize>>::new(0);
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+    fn build(&mut self, node: usize, low: usize, high: usize, vec: &[T]) {
+        if high - low == 1 {
+            self.tree[node] = vec[low].clone();
+            return;
+        }
+        let mid = (low + high) / 2;
+        self.build(2 * node, low, mid, vec);
+        self.build(2 * node + 1, mid, high, vec);
+        self.pull(node);
+    }
+    fn pull(&mut self, node: usize) {
+        self.tree[node] = Op::combine(&self.tree[2 * node], &self.tree[2 * node + 1]);
+    }
+    fn apply(&mut self, node: usize, delta: &T, len: usize) {
+        self.tree[node] = Op::apply(&self.tree[node], delta, len);
+        self.lazy[node] = match &self.lazy[node] {
+            Some(pending) => Some(Op::compose(pending, delta)),
+            None => Some(delta.clone()),
+        };
+    }
+    fn push(&mut self, node: usize, low: usize, high: usize) {
+        // propagates the pending update of a node to its children
+        if let Some(delta) = self.lazy[node].take() {
+            let mid = (low + high) / 2;
+            self.apply(2 * node, &delta, mid - low);
+            self.apply(2 * node + 1, &delta, high - mid);
+        }
+    }
+    /// Returns the aggregate of the objects between two positions (low included, high excluded).
+    /// It is the identity of the monoid when `low = high`.
+    /// # Panics
+    /// If `high > self.len()` or `low > high`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{LazySegmentTree, Sum};
+    /// let mut tree = LazySegmentTree::<isize, Sum<isize>>::from_vec(vec![1, -2, 3, 4]);
+    /// tree.range_add(0, 4, 1);
+    /// assert_eq!(tree.query(1, 3), 3);
+    /// assert_eq!(tree.query(2, 2), 0);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn query(&self, low: usize, high: usize) -> T {
+        check_range(self.n, low, high);
+        self.aggregate(1, 0, self.n, low, high, None)
+    }
+    fn aggregate(
+        &self,
+        node: usize,
+        low: usize,
+        high: usize,
+        qlow: usize,
+        qhigh: usize,
+        delta: Option<&T>,
+    ) -> T {
+        // aggregate of the query range inside the node range, where delta is the
+        // composition of the pending updates of the ancestors of the node
+        if qhigh <= low || high <= qlow {
+            return Op::identity();
+        }
+        if qlow <= low && high <= qhigh {
+            return match delta {
+                Some(d) => Op::apply(&self.tree[node], d, high - low),
+                None => self.tree[node].clone(),
+            };
+        }
+        let pending = match (delta, &self.lazy[node]) {
+            (Some(d), Some(p)) => Some(Op::compose(p, d)),
+            (Some(d), None) => Some(d.clone()),
+            (None, p) => p.clone(),
+        };
+        let mid = (low + high) / 2;
+        let left = self.aggregate(2 * node, low, mid, qlow, qhigh, pending.as_ref());
+        let right = self.aggregate(2 * node + 1, mid, high, qlow, qhigh, pending.as_ref());
+        Op::combine(&left, &right)
+    }
+    /// Returns the object at a given position.
+    /// # Panics
+    /// If `index >= self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{LazySegmentTree, Sum};
+    /// let mut tree = LazySegmentTree::<isize, Sum<isize>>::from_vec(vec![1, -2, 3, 4]);
+    /// tree.range_add(1, 3, 5);
+    /// assert_eq!(tree.get(1), 3);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn get(&self, index: usize) -> T {
+        self.query(index, index + 1)
+    }
+    fn update(&mut self, node: usize, low: usize, high: usize, index: usize, value: T) {
+        if high - low == 1 {
+            self.tree[node] = value;
+            return;
+        }
+        self.push(node, low, high);
+        let mid = (low + high) / 2;
+        if index < mid {
+            self.update(2 * node, low, mid, index, value);
+        } else {
+            self.update(2 * node + 1, mid, high, index, value);
+        }
+        self.pull(node);
+    }
+    /// Replaces the object at a given position.
+    /// # Panics
+    /// If `index >= self.len()`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{LazySegmentTree, Max};
+    /// let mut tree = LazySegmentTree::<isize, Max<isize>>::from_vec(vec![1, -2, 3, 4]);
+    /// tree.range_add(0, 4, 10);
+    /// tree.set(3, -5);
+    /// assert_eq!(tree.query(0, 4), 13);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn set(&mut self, index: usize, value: T) {
+        check_index(self.n, index);
+        self.update(1, 0, self.n, index, value);
+    }
+    fn add(&mut self, node: usize, low: usize, high: usize, qlow: usize, qhigh: usize, delta: &T) {
+        if qhigh <= low || high <= qlow {
+            return;
+        }
+        if qlow <= low && high <= qhigh {
+            self.apply(node, delta, high - low);
+            return;
+        }
+        self.push(node, low, high);
+        let mid = (low + high) / 2;
+        self.add(2 * node, low, mid, qlow, qhigh, delta);
+        self.add(2 * node + 1, mid, high, qlow, qhigh, delta);
+        self.pull(node);
+    }
+    /// Adds `delta` to every object between two positions (low included, high excluded).
+    /// The update is lazily propagated: nodes covering the whole range are updated
+    /// and their children only when they are visited later.
+    /// # Panics
+    /// If `high > self.len()` or `low > high`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{LazySegmentTree, Max};
+    /// let mut tree = LazySegmentTree::<isize, Max<isize>>::from_vec(vec![1, -2, 3, 4]);
+    /// tree.range_add(0, 2, 10);
+    /// assert_eq!(tree.query(0, 4), 11);
+    /// assert_eq!(tree.get(1), 8);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn range_add(&mut self, low: usize, high: usize, delta: T) {
+        check_range(self.n, low, high);
+        self.add(1, 0, self.n, low, high, &delta);
+    }
+}
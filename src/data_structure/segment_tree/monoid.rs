@@ -0,0 +1,214 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Mul, Sub};
+
+/// Numeric types usable in the aggregates provided by the crate (sum, min and max)
+pub trait Number:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    /// Returns the additive identity.
+    fn zero() -> Self;
+    /// Returns a value lower than or equal to any other value of the type,
+    /// i.e. the identity of the maximum (negative infinity for floats).
+    fn min_value() -> Self;
+    /// Returns a value greater than or equal to any other value of the type,
+    /// i.e. the identity of the minimum (infinity for floats).
+    fn max_value() -> Self;
+    /// Converts a count of objects into the type.
+    fn from_usize(nb: usize) -> Self;
+}
+macro_rules! impl_number {
+    ($TYPE:ty) => {
+        impl Number for $TYPE {
+            fn zero() -> Self {
+                0
+            }
+            fn min_value() -> Self {
+                <$TYPE>::MIN
+            }
+            fn max_value() -> Self {
+                <$TYPE>::MAX
+            }
+            fn from_usize(nb: usize) -> Self {
+                nb as $TYPE
+            }
+        }
+    };
+}
+impl_number!(u8);
+impl_number!(u16);
+impl_number!(u32);
+impl_number!(u64);
+impl_number!(u128);
+impl_number!(usize);
+impl_number!(i8);
+impl_number!(i16);
+impl_number!(i32);
+impl_number!(i64);
+impl_number!(i128);
+impl_number!(isize);
+macro_rules! impl_float_number {
+    ($TYPE:ty) => {
+        impl Number for $TYPE {
+            fn zero() -> Self {
+                0.
+            }
+            fn min_value() -> Self {
+                <$TYPE>::NEG_INFINITY
+            }
+            fn max_value() -> Self {
+                <$TYPE>::INFINITY
+            }
+            fn from_usize(nb: usize) -> Self {
+                nb as $TYPE
+            }
+        }
+    };
+}
+impl_float_number!(f32);
+impl_float_number!(f64);
+
+/// Defines an associative operation on `T` with an identity element,
+/// i.e a monoid: `combine(a, combine(b, c)) = combine(combine(a, b), c)`
+/// and `combine(identity(), a) = a = combine(a, identity())`.
+pub trait Monoid<T> {
+    /// Returns the identity element, i.e. the aggregate of no objects.
+    fn identity() -> T;
+    /// Returns the aggregate of two aggregates, `a` coming before `b`.
+    fn combine(a: &T, b: &T) -> T;
+}
+
+/// Defines a monoid whose aggregates can be updated in O(1) when
+/// the same value `delta` is added to every aggregated object.
+/// It enables lazy propagated range updates.
+pub trait LazyMonoid<T>: Monoid<T> {
+    /// Returns the aggregate of `len` objects after adding `delta` to each of them,
+    /// given their previous aggregate.
+    fn apply(aggregate: &T, delta: &T, len: usize) -> T;
+    /// Returns the delta equivalent to adding `first` then `second`.
+    fn compose(first: &T, second: &T) -> T;
+}
+
+/// Sum monoid
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sum<T> {
+    marker: PhantomData<T>,
+}
+impl<T: Number> Monoid<T> for Sum<T> {
+    fn identity() -> T {
+        T::zero()
+    }
+    fn combine(a: &T, b: &T) -> T {
+        *a + *b
+    }
+}
+impl<T: Number> LazyMonoid<T> for Sum<T> {
+    fn apply(aggregate: &T, delta: &T, len: usize) -> T {
+        *aggregate + *delta * T::from_usize(len)
+    }
+    fn compose(first: &T, second: &T) -> T {
+        *first + *second
+    }
+}
+
+/// Minimum monoid
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Min<T> {
+    marker: PhantomData<T>,
+}
+impl<T: Number> Monoid<T> for Min<T> {
+    fn identity() -> T {
+        T::max_value()
+    }
+    fn combine(a: &T, b: &T) -> T {
+        if b < a {
+            *b
+        } else {
+            *a
+        }
+    }
+}
+impl<T: Number> LazyMonoid<T> for Min<T> {
+    fn apply(aggregate: &T, delta: &T, _len: usize) -> T {
+        // the identity is left unchanged, as an infinite value would be
+        if *aggregate == Self::identity() {
+            *aggregate
+        } else {
+            *aggregate + *delta
+        }
+    }
+    fn compose(first: &T, second: &T) -> T {
+        *first + *second
+    }
+}
+
+/// Maximum monoid
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Max<T> {
+    marker: PhantomData<T>,
+}
+impl<T: Number> Monoid<T> for Max<T> {
+    fn identity() -> T {
+        T::min_value()
+    }
+    fn combine(a: &T, b: &T) -> T {
+        if b > a {
+            *b
+        } else {
+            *a
+        }
+    }
+}
+impl<T: Number> LazyMonoid<T> for Max<T> {
+    fn apply(aggregate: &T, delta: &T, _len: usize) -> T {
+        // the identity is left unchanged, as an infinite value would be
+        if *aggregate == Self::identity() {
+            *aggregate
+        } else {
+            *aggregate + *delta
+        }
+    }
+    fn compose(first: &T, second: &T) -> T {
+        *first + *second
+    }
+}
+
+/// Defines a monoid whose operation is idempotent: `combine(a, a) = a`,
+/// so that overlapping ranges can be aggregated without counting any object twice.
+pub trait Idempotent<T>: Monoid<T> {}
+impl<T: Number> Idempotent<T> for Min<T> {}
+impl<T: Number> Idempotent<T> for Max<T> {}
+
+/// Defines a monoid whose operation selects one of its operands,
+/// so that an object can be discarded as soon as a later one is selected over it.
+pub trait Selective<T>: Idempotent<T> {}
+impl<T: Number> Selective<T> for Min<T> {}
+impl<T: Number> Selective<T> for Max<T> {}
+
+/// Greatest common divisor monoid (on unsigned integers)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Gcd<T> {
+    marker: PhantomData<T>,
+}
+macro_rules! impl_gcd {
+    ($TYPE:ty) => {
+        impl Monoid<$TYPE> for Gcd<$TYPE> {
+            fn identity() -> $TYPE {
+                0
+            }
+            fn combine(a: &$TYPE, b: &$TYPE) -> $TYPE {
+                let (mut a, mut b) = (*a, *b);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
+            }
+        }
+        impl Idempotent<$TYPE> for Gcd<$TYPE> {}
+    };
+}
+impl_gcd!(u8);
+impl_gcd!(u16);
+impl_gcd!(u32);
+impl_gcd!(u64);
+impl_gcd!(u128);
+impl_gcd!(usize);
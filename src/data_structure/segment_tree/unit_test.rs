@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    // Greatest common divisor monoid, which has no lazy range update
+    struct Gcd;
+    impl Monoid<u64> for Gcd {
+        fn identity() -> u64 {
+            0
+        }
+        fn combine(a: &u64, b: &u64) -> u64 {
+            let (mut a, mut b) = (*a, *b);
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            a
+        }
+    }
+
+    #[test]
+    fn test_segment_tree_new() {
+        let tree = SegmentTree::<isize, Max<isize>>::new(3);
+        assert_eq!(tree.len(), 3);
+        assert_eq!(tree.query(0, 3), isize::MIN);
+        let empty = SegmentTree::<isize, Sum<isize>>::from_vec(Vec::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.query(0, 0), 0);
+    }
+
+    #[test]
+    fn test_segment_tree_custom_monoid() {
+        let mut tree = SegmentTree::<u64, Gcd>::from_vec(vec![12, 18, 24, 7, 14]);
+        assert_eq!(tree.query(0, 3), 6);
+        assert_eq!(tree.query(3, 5), 7);
+        assert_eq!(tree.query(0, 5), 1);
+        tree.set(3, 30);
+        assert_eq!(tree.query(0, 4), 6);
+    }
+
+    #[test]
+    fn test_segment_tree_infinite_floats() {
+        let tree = SegmentTree::<f64, Min<f64>>::from_vec(vec![f64::INFINITY, 2., 1.]);
+        assert_eq!(tree.query(0, 1), f64::INFINITY);
+        assert_eq!(tree.query(0, 3), 1.);
+        assert_eq!(tree.query(1, 1), f64::INFINITY);
+        let tree = SegmentTree::<f32, Max<f32>>::from_vec(vec![f32::NEG_INFINITY, -2.]);
+        assert_eq!(tree.query(0, 1), f32::NEG_INFINITY);
+        assert_eq!(tree.query(0, 0), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_monoid_infinite_floats() {
+        // the identities are the infinities, which combine as any other value
+        assert_eq!(Min::<f64>::identity(), f64::INFINITY);
+        assert_eq!(Max::<f64>::identity(), f64::NEG_INFINITY);
+        assert_eq!(Min::<f32>::identity(), f32::INFINITY);
+        assert_eq!(Max::<f32>::identity(), f32::NEG_INFINITY);
+        let (inf, neg_inf) = (f64::INFINITY, f64::NEG_INFINITY);
+        assert_eq!(Min::combine(&Min::identity(), &inf), inf);
+        assert_eq!(Min::combine(&3., &Min::identity()), 3.);
+        assert_eq!(Max::combine(&Max::identity(), &neg_inf), neg_inf);
+        assert_eq!(Max::combine(&-3., &Max::identity()), -3.);
+        assert_eq!(Sum::<f32>::identity(), 0.);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_segment_tree_out_of_bounds() {
+        let tree = SegmentTree::<isize, Sum<isize>>::new(3);
+        tree.query(1, 4);
+    }
+
+    fn check_against_brute_force<Op: LazyMonoid<i64>>(reference: fn(&[i64]) -> i64) {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut values = (0..150)
+            .map(|_| rng.gen_range(-50..50))
+            .collect::<Vec<i64>>();
+        let mut tree = LazySegmentTree::<i64, Op>::from_vec(values.clone());
+        for _ in 0..3000 {
+            let low = rng.gen_range(0..values.len());
+            let high = rng.gen_range(low + 1..=values.len());
+            match rng.gen_range(0..3) {
+                0 => {
+                    let delta = rng.gen_range(-20..20);
+                    values[low..high].iter_mut().for_each(|v| *v += delta);
+                    tree.range_add(low, high, delta);
+                }
+                1 => {
+                    let value = rng.gen_range(-50..50);
+                    values[low] = value;
+                    tree.set(low, value);
+                }
+                _ => assert_eq!(tree.get(low), values[low]),
+            }
+            let low = rng.gen_range(0..values.len());
+            let high = rng.gen_range(low + 1..=values.len());
+            assert_eq!(tree.query(low, high), reference(&values[low..high]));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lazy_segment_tree_out_of_bounds() {
+        let mut tree = LazySegmentTree::<isize, Sum<isize>>::new(3);
+        tree.range_add(2, 4, 1);
+    }
+
+    #[test]
+    fn test_lazy_segment_tree_identity_updates() {
+        // positions left to the identity by new are not overflowed by range updates
+        for delta in [1, -1] {
+            let mut tree = LazySegmentTree::<isize, Min<isize>>::new(4);
+            tree.set(0, 3);
+            tree.range_add(0, 4, delta);
+            assert_eq!(tree.query(1, 2), isize::MAX);
+            assert_eq!(tree.query(1, 4), isize::MAX);
+            assert_eq!(tree.query(0, 3), 3 + delta);
+            tree.range_add(1, 3, delta);
+            assert_eq!(tree.get(2), isize::MAX);
+
+            let mut tree = LazySegmentTree::<isize, Max<isize>>::new(4);
+            tree.set(3, -3);
+            tree.range_add(0, 4, delta);
+            assert_eq!(tree.query(1, 2), isize::MIN);
+            assert_eq!(tree.query(0, 3), isize::MIN);
+            assert_eq!(tree.query(2, 4), -3 + delta);
+            tree.range_add(0, 2, delta);
+            assert_eq!(tree.get(0), isize::MIN);
+        }
+    }
+
+    #[test]
+    fn test_lazy_segment_tree_sum() {
+        check_against_brute_force::<Sum<i64>>(|s| s.iter().sum());
+    }
+
+    #[test]
+    fn test_lazy_segment_tree_min() {
+        check_against_brute_force::<Min<i64>>(|s| *s.iter().min().unwrap());
+    }
+
+    #[test]
+    fn test_lazy_segment_tree_max() {
+        check_against_brute_force::<Max<i64>>(|s| *s.iter().max().unwrap());
+    }
+}
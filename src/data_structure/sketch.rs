@@ -0,0 +1,566 @@
+#[cfg(test)]
+mod unit_test;
+use crate::data_structure::bloom_filter::positions;
+use std::cmp::Reverse;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// Implementation of a Count-Min sketch: it estimates the frequencies of the items
+/// of a stream in a fixed memory, never underestimating them.
+/// Counters are increased with the conservative update, i.e. only as much as needed
+/// for the estimate of the item to reach its new value, which reduces overestimation.
+/// # Example
+/// ```
+/// use algods::data_structure::CountMinSketch;
+/// let mut sketch = CountMinSketch::new(0.001, 0.01);
+/// for word in "to be or not to be".split_whitespace() {
+///     sketch.insert(word);
+/// }
+/// assert_eq!(sketch.count("be"), 2);
+/// assert_eq!(sketch.count("or"), 1);
+/// assert_eq!(sketch.total(), 6);
+/// ```
+#[derive(Debug)]
+pub struct CountMinSketch<T: ?Sized> {
+    // depth rows of width counters, stored row after row
+    counters: Vec<u64>,
+    width: usize,
+    depth: usize,
+    total: u64,
+    item: PhantomData<T>,
+}
+// manual implementation, so that no bound is required on the type of the items
+impl<T: ?Sized> Clone for CountMinSketch<T> {
+    fn clone(&self) -> Self {
+        Self {
+            counters: self.counters.clone(),
+            width: self.width,
+            depth: self.depth,
+            total: self.total,
+            item: PhantomData,
+        }
+    }
+}
+impl<T: Hash + ?Sized> CountMinSketch<T> {
+    /// Creates an empty sketch whose estimates exceed the true frequencies by at most
+    /// `epsilon` times the total count, with probability at least `1 - delta`.
+    /// # Panics
+    /// If `epsilon` or `delta` is not in ]0, 1[, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountMinSketch;
+    /// let sketch = CountMinSketch::<str>::new(0.01, 0.01);
+    /// assert_eq!(sketch.width(), 272);
+    /// assert_eq!(sketch.depth(), 5);
+    /// ```
+    pub fn new(epsilon: f64, delta: f64) -> Self {
+        if !(epsilon > 0. && epsilon < 1. && delta > 0. && delta < 1.) {
+            panic!("epsilon and delta should be in ]0, 1[");
+        }
+        let width = (std::f64::consts::E / epsilon).ceil() as usize;
+        let depth = (1. / delta).ln().ceil().max(1.) as usize;
+        Self::with_size(width, depth)
+    }
+    /// Creates an empty sketch with `depth` rows of `width` counters.
+    /// # Panics
+    /// If `width = 0` or `depth = 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountMinSketch;
+    /// let sketch = CountMinSketch::<usize>::with_size(100, 4);
+    /// assert_eq!(sketch.width(), 100);
+    /// ```
+    pub fn with_size(width: usize, depth: usize) -> Self {
+        if width == 0 || depth == 0 {
+            panic!("the width and the depth should be > 0");
+        }
+        Self {
+            counters: vec![0; width * depth],
+            width,
+            depth,
+            total: 0,
+            item: PhantomData,
+        }
+    }
+    /// Gives the number of counters of each row.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    /// Gives the number of rows, i.e. of hash functions.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+    /// Gives the sum of the counts added to the sketch.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+    /// Tests whether or not nothing was added to the sketch.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountMinSketch;
+    /// let sketch = CountMinSketch::<usize>::new(0.1, 0.1);
+    /// assert!(sketch.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+    fn cells(&self, item: &T) -> Vec<usize> {
+        // one counter per row
+        positions(item, self.width, self.depth)
+            .into_iter()
+            .enumerate()
+            .map(|(row, column)| row * self.width + column)
+            .collect()
+    }
+    /// Adds `count` occurrences of an item to the sketch.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountMinSketch;
+    /// let mut sketch = CountMinSketch::new(0.01, 0.01);
+    /// sketch.add(&"key", 10);
+    /// assert_eq!(sketch.count(&"key"), 10);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(d) where d is the depth of the sketch
+    pub fn add(&mut self, item: &T, count: u64) {
+        let cells = self.cells(item);
+        let estimate = cells.iter().map(|cell| self.counters[*cell]).min();
+        let target = estimate.unwrap_or(0).saturating_add(count);
+        for cell in cells {
+            self.counters[cell] = self.counters[cell].max(target);
+        }
+        self.total = self.total.saturating_add(count);
+    }
+    /// Adds one occurrence of an item to the sketch.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountMinSketch;
+    /// let mut sketch = CountMinSketch::new(0.01, 0.01);
+    /// sketch.insert(&1);
+    /// assert_eq!(sketch.count(&1), 1);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(d) where d is the depth of the sketch
+    pub fn insert(&mut self, item: &T) {
+        self.add(item, 1);
+    }
+    /// Returns an estimate of the frequency of an item, which is never below its true frequency.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountMinSketch;
+    /// let sketch = CountMinSketch::new(0.01, 0.01);
+    /// assert_eq!(sketch.count(&1), 0);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(d) where d is the depth of the sketch
+    pub fn count(&self, item: &T) -> u64 {
+        self.cells(item)
+            .into_iter()
+            .map(|cell| self.counters[cell])
+            .min()
+            .unwrap_or(0)
+    }
+    /// Adds the counts of another sketch with the same width and depth,
+    /// as if the items of its stream were added to this sketch.
+    /// # Panics
+    /// If the sketches do not have the same width and depth, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::CountMinSketch;
+    /// let mut s1 = CountMinSketch::new(0.01, 0.01);
+    /// let mut s2 = CountMinSketch::new(0.01, 0.01);
+    /// s1.insert(&1);
+    /// s2.insert(&1);
+    /// s1.merge(&s2);
+    /// assert_eq!(s1.count(&1), 2);
+    /// ```
+    pub fn merge(&mut self, other: &Self) {
+        if self.width != other.width || self.depth != other.depth {
+            panic!("sketches should have the same width and depth");
+        }
+        for (counter, other_counter) in self.counters.iter_mut().zip(other.counters.iter()) {
+            *counter = counter.saturating_add(*other_counter);
+        }
+        self.total = self.total.saturating_add(other.total);
+    }
+}
+
+/// Implementation of HyperLogLog: it estimates the number of distinct items
+/// of a stream in a fixed memory of 2^p small registers, where p is the precision.
+/// # Example
+/// ```
+/// use algods::data_structure::HyperLogLog;
+/// let mut hll = HyperLogLog::new(12);
+/// for k in 0..10_000 {
+///     hll.insert(&(k % 1000));
+/// }
+/// assert!((hll.estimate() - 1000.).abs() < 50.);
+/// ```
+#[derive(Debug)]
+pub struct HyperLogLog<T: ?Sized> {
+    // each register keeps the largest rank seen among the hashes mapped to it
+    registers: Vec<u8>,
+    precision: u8,
+    item: PhantomData<T>,
+}
+impl<T: ?Sized> Clone for HyperLogLog<T> {
+    fn clone(&self) -> Self {
+        Self {
+            registers: self.registers.clone(),
+            precision: self.precision,
+            item: PhantomData,
+        }
+    }
+}
+impl<T: Hash + ?Sized> HyperLogLog<T> {
+    /// Creates an empty estimator with 2^`precision` registers.
+    /// # Panics
+    /// If `precision` is not in [4, 16], then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::HyperLogLog;
+    /// let hll = HyperLogLog::<str>::new(10);
+    /// assert_eq!(hll.nb_registers(), 1024);
+    /// ```
+    pub fn new(precision: u8) -> Self {
+        if !(4..=16).contains(&precision) {
+            panic!("the precision should be in [4, 16], got {precision}");
+        }
+        Self {
+            registers: vec![0; 1 << precision],
+            precision,
+            item: PhantomData,
+        }
+    }
+    /// Gives the precision, i.e. the number of bits of the hash selecting a register.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+    /// Gives the number of registers.
+    pub fn nb_registers(&self) -> usize {
+        self.registers.len()
+    }
+    /// Gives the relative standard error of the estimates, 1.04 / sqrt(2^p).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::HyperLogLog;
+    /// let hll = HyperLogLog::<usize>::new(4);
+    /// assert_eq!(hll.standard_error(), 0.26);
+    /// ```
+    pub fn standard_error(&self) -> f64 {
+        1.04 / (self.nb_registers() as f64).sqrt()
+    }
+    /// Tests whether or not no item was inserted.
+    pub fn is_empty(&self) -> bool {
+        self.registers.iter().all(|register| *register == 0)
+    }
+    /// Inserts an item in the estimator.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::HyperLogLog;
+    /// let mut hll = HyperLogLog::new(8);
+    /// hll.insert("key");
+    /// assert!(!hll.is_empty());
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn insert(&mut self, item: &T) {
+        let mut hasher = DefaultHasher::new();
+        item.hash(&mut hasher);
+        let hash = hasher.finish();
+        // the first p bits select the register, the rank is the position
+        // of the first set bit among the others
+        let index = (hash >> (64 - self.precision)) as usize;
+        let rest = hash << self.precision;
+        let rank = (rest.leading_zeros() + 1).min(64 - self.precision as u32 + 1) as u8;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+    /// Returns an estimate of the number of distinct items inserted.
+    /// Small cardinalities, for which the raw estimate is biased, are estimated
+    /// by linear counting on the number of empty registers.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::HyperLogLog;
+    /// let mut hll = HyperLogLog::new(10);
+    /// for k in 0..10 {
+    ///     hll.insert(&k);
+    /// }
+    /// assert_eq!(hll.estimate().round(), 10.);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(2^p)
+    pub fn estimate(&self) -> f64 {
+        let m = self.nb_registers() as f64;
+        let alpha = match self.registers.len() {
+            16 => 0.673,
+            32 => 0.697,
+            64 => 0.709,
+            _ => 0.7213 / (1. + 1.079 / m),
+        };
+        let sum = self
+            .registers
+            .iter()
+            .map(|register| 2f64.powi(-(*register as i32)))
+            .sum::<f64>();
+        let raw = alpha * m * m / sum;
+        let zeros = self
+            .registers
+            .iter()
+            .filter(|register| **register == 0)
+            .count();
+        if raw <= 2.5 * m && zeros > 0 {
+            m * (m / zeros as f64).ln()
+        } else {
+            raw
+        }
+    }
+    /// Merges another estimator with the same precision, so that this estimator
+    /// accounts for the items of both streams.
+    /// # Panics
+    /// If the estimators do not have the same precision, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::HyperLogLog;
+    /// let mut h1 = HyperLogLog::new(10);
+    /// let mut h2 = HyperLogLog::new(10);
+    /// for k in 0..10 {
+    ///     h1.insert(&k);
+    ///     h2.insert(&(k + 5));
+    /// }
+    /// h1.merge(&h2);
+    /// assert_eq!(h1.estimate().round(), 15.);
+    /// ```
+    pub fn merge(&mut self, other: &Self) {
+        if self.precision != other.precision {
+            panic!("estimators should have the same precision");
+        }
+        for (register, other_register) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other_register);
+        }
+    }
+}
+
+/// Implementation of the Space-Saving algorithm (a variant of Misra-Gries): it tracks
+/// the most frequent items of a stream with at most `capacity` counters.
+/// Every item whose frequency exceeds `total / capacity` is guaranteed to be tracked,
+/// and the count of a tracked item overestimates its frequency by at most its error.
+/// # Example
+/// ```
+/// use algods::data_structure::SpaceSaving;
+/// let mut top = SpaceSaving::new(2);
+/// for word in "a a a b c a".split_whitespace() {
+///     top.insert(word);
+/// }
+/// assert_eq!(top.top(1), vec![("a", 4)]);
+/// assert_eq!(top.count(&"c"), Some(2));
+/// assert_eq!(top.error(&"c"), Some(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SpaceSaving<T> {
+    // counters of the tracked items, as a min heap ordered by (count, stamp)
+    heap: Vec<Counter<T>>,
+    // position in the heap of each tracked item
+    positions: HashMap<T, usize>,
+    capacity: usize,
+    total: u64,
+    // number of updates so far, used to stamp the counters
+    updates: u64,
+    // Remarks:
+    // - among counters with the same count, the least recently updated one is evicted first
+    // - stamps are distinct, so that evictions and rankings do not depend on hashing
+}
+#[derive(Debug, Clone)]
+struct Counter<T> {
+    item: T,
+    count: u64,
+    error: u64,
+    // update at which the count was last changed
+    stamp: u64,
+}
+impl<T: Hash + Eq + Clone> SpaceSaving<T> {
+    /// Creates an empty tracker with a given number of counters.
+    /// # Panics
+    /// If `capacity = 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SpaceSaving;
+    /// let top = SpaceSaving::<usize>::new(10);
+    /// assert_eq!(top.capacity(), 10);
+    /// assert!(top.is_empty());
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        if capacity == 0 {
+            panic!("capacity should be > 0");
+        }
+        Self {
+            heap: Vec::with_capacity(capacity),
+            positions: HashMap::with_capacity(capacity),
+            capacity,
+            total: 0,
+            updates: 0,
+        }
+    }
+    /// Gives the maximum number of tracked items.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Gives the number of tracked items.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+    /// Tests whether or not no item is tracked.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+    /// Gives the sum of the counts added to the tracker.
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+    fn less(&self, i: usize, j: usize) -> bool {
+        (self.heap[i].count, self.heap[i].stamp) < (self.heap[j].count, self.heap[j].stamp)
+    }
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        for k in [i, j] {
+            if let Some(position) = self.positions.get_mut(&self.heap[k].item) {
+                *position = k;
+            }
+        }
+    }
+    fn swim(&mut self, mut k: usize) {
+        while k > 0 && self.less(k, (k - 1) / 2) {
+            self.swap(k, (k - 1) / 2);
+            k = (k - 1) / 2;
+        }
+    }
+    fn sink(&mut self, mut k: usize) {
+        while 2 * k + 1 < self.heap.len() {
+            let mut child = 2 * k + 1;
+            if child + 1 < self.heap.len() && self.less(child + 1, child) {
+                child += 1;
+            }
+            if !self.less(child, k) {
+                break;
+            }
+            self.swap(k, child);
+            k = child;
+        }
+    }
+    /// Adds `count` occurrences of an item. When all counters are used by other items,
+    /// the item replaces the tracked item with the smallest count and inherits it.
+    /// Among the smallest counts, the least recently updated one is replaced.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SpaceSaving;
+    /// let mut top = SpaceSaving::new(1);
+    /// top.add(1, 3);
+    /// top.add(2, 1);
+    /// assert_eq!(top.count(&2), Some(4));
+    /// assert_eq!(top.error(&2), Some(3));
+    /// assert_eq!(top.count(&1), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(k)) where k is the capacity
+    pub fn add(&mut self, item: T, count: u64) {
+        self.total = self.total.saturating_add(count);
+        self.updates += 1;
+        if let Some(&position) = self.positions.get(&item) {
+            let counter = &mut self.heap[position];
+            counter.count = counter.count.saturating_add(count);
+            counter.stamp = self.updates;
+            self.sink(position);
+            return;
+        }
+        if self.heap.len() < self.capacity {
+            self.positions.insert(item.clone(), self.heap.len());
+            self.heap.push(Counter {
+                item,
+                count,
+                error: 0,
+                stamp: self.updates,
+            });
+            self.swim(self.heap.len() - 1);
+            return;
+        }
+        // the counter with the smallest count is at the root
+        let smallest = &mut self.heap[0];
+        self.positions.remove(&smallest.item);
+        self.positions.insert(item.clone(), 0);
+        smallest.item = item;
+        smallest.error = smallest.count;
+        smallest.count = smallest.count.saturating_add(count);
+        smallest.stamp = self.updates;
+        self.sink(0);
+    }
+    /// Adds one occurrence of an item.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SpaceSaving;
+    /// let mut top = SpaceSaving::new(2);
+    /// top.insert('a');
+    /// assert_eq!(top.count(&'a'), Some(1));
+    /// ```
+    pub fn insert(&mut self, item: T) {
+        self.add(item, 1);
+    }
+    /// Returns the estimated count of an item if it is tracked, which is never below
+    /// its true frequency.
+    pub fn count(&self, item: &T) -> Option<u64> {
+        self.positions
+            .get(item)
+            .map(|position| self.heap[*position].count)
+    }
+    /// Returns the maximum overestimation of the count of an item if it is tracked.
+    pub fn error(&self, item: &T) -> Option<u64> {
+        self.positions
+            .get(item)
+            .map(|position| self.heap[*position].error)
+    }
+    fn ranked<'a>(&'a self, counters: impl Iterator<Item = &'a Counter<T>>) -> Vec<(T, u64)> {
+        // by decreasing count, the counters that reached their count first coming first
+        let mut counters = counters.collect::<Vec<&Counter<T>>>();
+        counters.sort_by_key(|counter| (Reverse(counter.count), counter.stamp));
+        counters
+            .into_iter()
+            .map(|counter| (counter.item.clone(), counter.count))
+            .collect()
+    }
+    /// Returns at most `k` tracked items with their estimated counts, by decreasing count.
+    /// Items with the same count come in the order they reached it.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SpaceSaving;
+    /// let mut top = SpaceSaving::new(3);
+    /// for k in [1, 2, 2, 3, 3, 3] {
+    ///     top.insert(k);
+    /// }
+    /// assert_eq!(top.top(2), vec![(3, 3), (2, 2)]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(k log(k)) where k is the capacity
+    pub fn top(&self, k: usize) -> Vec<(T, u64)> {
+        let mut items = self.ranked(self.heap.iter());
+        items.truncate(k);
+        items
+    }
+    /// Returns the tracked items whose frequency is guaranteed to be at least `threshold`,
+    /// i.e. whose count minus error is at least `threshold`, by decreasing count.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SpaceSaving;
+    /// let mut top = SpaceSaving::new(2);
+    /// for k in [1, 1, 1, 2, 3] {
+    ///     top.insert(k);
+    /// }
+    /// assert_eq!(top.heavy_hitters(2), vec![(1, 3)]);
+    /// ```
+    pub fn heavy_hitters(&self, threshold: u64) -> Vec<(T, u64)> {
+        self.ranked(
+            self.heap
+                .iter()
+                .filter(|counter| counter.count - counter.error >= threshold),
+        )
+    }
+}
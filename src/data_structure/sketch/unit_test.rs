@@ -0,0 +1,191 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::{HashMap, HashSet};
+
+    fn zipf_stream(n: usize, seed: u64) -> Vec<usize> {
+        // a skewed stream where item k appears about n / (k + 1) / ln(n) times
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..n)
+            .map(|_| {
+                let u: f64 = rng.gen();
+                ((n as f64).powf(u) - 1.) as usize
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_count_min_sketch_new() {
+        let sketch = CountMinSketch::<usize>::new(0.01, 0.01);
+        assert_eq!(sketch.width(), 272);
+        assert_eq!(sketch.depth(), 5);
+        assert_eq!(sketch.counters.len(), 272 * 5);
+        assert!(sketch.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_count_min_sketch_invalid() {
+        CountMinSketch::<usize>::new(0., 0.01);
+    }
+
+    #[test]
+    fn test_count_min_sketch_bounds() {
+        let stream = zipf_stream(20_000, 0);
+        let mut sketch = CountMinSketch::new(0.001, 0.01);
+        let mut exact = HashMap::new();
+        for item in stream.iter() {
+            sketch.insert(item);
+            *exact.entry(*item).or_insert(0u64) += 1;
+        }
+        assert_eq!(sketch.total(), 20_000);
+        let bound = (0.001 * 20_000f64) as u64;
+        for (item, count) in exact.iter() {
+            let estimate = sketch.count(item);
+            assert!(estimate >= *count);
+            assert!(estimate <= count + bound, "{item}: {estimate} vs {count}");
+        }
+    }
+
+    #[test]
+    fn test_count_min_sketch_conservative_update() {
+        // with a single counter, conservative update behaves like a maximum per item
+        let mut sketch = CountMinSketch::with_size(1, 1);
+        sketch.add(&1, 5);
+        sketch.add(&2, 3);
+        assert_eq!(sketch.count(&2), 8);
+        assert_eq!(sketch.total(), 8);
+    }
+
+    #[test]
+    fn test_count_min_sketch_merge() {
+        let mut s1 = CountMinSketch::new(0.01, 0.01);
+        let mut s2 = CountMinSketch::new(0.01, 0.01);
+        for k in 0..100usize {
+            s1.insert(&k);
+            s2.add(&k, 2);
+        }
+        s1.merge(&s2);
+        assert_eq!(s1.total(), 300);
+        assert!((0..100usize).all(|k| s1.count(&k) >= 3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_count_min_sketch_merge_incompatible() {
+        let mut s1 = CountMinSketch::<usize>::with_size(10, 2);
+        s1.merge(&CountMinSketch::with_size(10, 3));
+    }
+
+    #[test]
+    fn test_hyperloglog_small_range() {
+        let mut hll = HyperLogLog::new(14);
+        assert!(hll.is_empty());
+        assert_eq!(hll.estimate(), 0.);
+        for k in 0..100usize {
+            hll.insert(&k);
+            hll.insert(&k);
+        }
+        assert!((hll.estimate() - 100.).abs() < 2.);
+    }
+
+    #[test]
+    fn test_hyperloglog_large_range() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut hll = HyperLogLog::new(12);
+        let mut exact = HashSet::new();
+        for _ in 0..200_000 {
+            let item = rng.gen_range(0..100_000u64);
+            hll.insert(&item);
+            exact.insert(item);
+        }
+        let error = (hll.estimate() - exact.len() as f64).abs() / exact.len() as f64;
+        assert!(error < 3. * hll.standard_error(), "{error}");
+    }
+
+    #[test]
+    fn test_hyperloglog_merge() {
+        let mut h1 = HyperLogLog::new(12);
+        let mut h2 = HyperLogLog::new(12);
+        let mut union = HyperLogLog::new(12);
+        for k in 0..30_000usize {
+            h1.insert(&k);
+            union.insert(&k);
+        }
+        for k in 20_000..50_000usize {
+            h2.insert(&k);
+            union.insert(&k);
+        }
+        h1.merge(&h2);
+        assert_eq!(h1.registers, union.registers);
+        assert!((h1.estimate() - 50_000.).abs() / 50_000. < 0.05);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hyperloglog_invalid_precision() {
+        HyperLogLog::<usize>::new(17);
+    }
+
+    #[test]
+    fn test_space_saving_heavy_hitters() {
+        let stream = zipf_stream(50_000, 2);
+        let capacity = 100;
+        let mut top = SpaceSaving::new(capacity);
+        let mut exact = HashMap::new();
+        for item in stream.iter() {
+            top.insert(*item);
+            *exact.entry(*item).or_insert(0u64) += 1;
+        }
+        assert_eq!(top.len(), capacity);
+        assert_eq!(top.total(), 50_000);
+        // every item more frequent than total / capacity is tracked, with a bounded count
+        for (item, count) in exact.iter() {
+            if *count > 50_000 / capacity as u64 {
+                let estimate = top.count(item).unwrap();
+                assert!(estimate >= *count);
+                assert!(estimate - top.error(item).unwrap() <= *count);
+            }
+        }
+        for (item, count) in top.heavy_hitters(1000) {
+            assert!(exact[&item] >= 1000);
+            assert_eq!(top.count(&item), Some(count));
+        }
+        let most_frequent = exact.iter().max_by_key(|(_, count)| **count).unwrap();
+        assert_eq!(top.top(1)[0].0, *most_frequent.0);
+    }
+
+    #[test]
+    fn test_space_saving_exact_below_capacity() {
+        let mut top = SpaceSaving::new(10);
+        for k in [5, 1, 5, 2, 5, 1] {
+            top.insert(k);
+        }
+        assert_eq!(top.top(10), vec![(5, 3), (1, 2), (2, 1)]);
+        assert_eq!(top.error(&5), Some(0));
+        assert_eq!(top.count(&7), None);
+    }
+
+    #[test]
+    fn test_space_saving_ties() {
+        let mut top = SpaceSaving::new(2);
+        top.insert("a");
+        top.insert("b");
+        assert_eq!(top.top(2), vec![("a", 1), ("b", 1)]);
+        // the least recently updated of the smallest counters is replaced
+        top.insert("c");
+        assert_eq!(top.count(&"a"), None);
+        assert_eq!(top.top(2), vec![("c", 2), ("b", 1)]);
+        top.insert("b");
+        top.insert("d");
+        assert_eq!(top.count(&"c"), None);
+        assert_eq!(top.top(2), vec![("d", 3), ("b", 2)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_space_saving_zero_capacity() {
+        SpaceSaving::<usize>::new(0);
+    }
+}
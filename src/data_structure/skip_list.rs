@@ -0,0 +1,430 @@
+#[cfg(test)]
+mod unit_test;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::Ordering;
+
+// maximum number of levels of the list, enough for 2^32 keys with probability 1/2
+const MAX_LEVEL: usize = 32;
+
+#[derive(Clone, Debug)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    // position in the arena of the next node at each level of the node
+    forward: Vec<Option<usize>>,
+}
+
+/// Implementation of a skip list: a sorted linked list of (key, value) pairs
+/// where each node is also linked, at random, to farther nodes on higher levels,
+/// giving expected O(log(N)) search, insertion and deletion.
+/// # Example
+/// ```
+/// use algods::data_structure::SkipList;
+/// let mut list = SkipList::new();
+/// list.insert(2, "2");
+/// list.insert(0, "0");
+/// list.insert(1, "1");
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(list.get(&1), Some(&"1"));
+/// assert_eq!(list.delete(&1), Some("1"));
+/// assert_eq!(list.iter().collect::<Vec<_>>(), vec![(&0, &"0"), (&2, &"2")]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SkipList<K, V> {
+    // arena of nodes, None slots are free
+    nodes: Vec<Option<Node<K, V>>>,
+    // free slots in the arena
+    free: Vec<usize>,
+    // first node at each level
+    head: Vec<Option<usize>>,
+    // number of levels in use
+    level: usize,
+    len: usize,
+    rng: StdRng,
+}
+impl<K, V> Default for SkipList<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K, V> SkipList<K, V> {
+    /// Creates an empty skip list instance, whose random number generator
+    /// is seeded from the operating system.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let list = SkipList::<usize, isize>::new();
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self::with_rng(StdRng::from_entropy())
+    }
+    /// Creates an empty skip list instance, whose random number generator
+    /// (used to choose the levels of the nodes) is seeded with `seed`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let list = SkipList::<usize, isize>::with_seed(42);
+    /// assert!(list.is_empty());
+    /// ```
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+    fn with_rng(rng: StdRng) -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: vec![None; MAX_LEVEL],
+            level: 1,
+            len: 0,
+            rng,
+        }
+    }
+    /// Gives the number of (key, value) pairs in the list.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let list = SkipList::<usize, usize>::new();
+    /// assert_eq!(list.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Tests whether or not the list is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::new();
+    /// list.insert(1, 1);
+    /// assert!(!list.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn node(&self, index: usize) -> &Node<K, V> {
+        self.nodes[index].as_ref().expect("live node")
+    }
+    fn next(&self, from: Option<usize>, level: usize) -> Option<usize> {
+        // next node at a given level, from the head when from is None
+        match from {
+            None => self.head[level],
+            Some(index) => self.node(index).forward[level],
+        }
+    }
+    fn set_next(&mut self, from: Option<usize>, level: usize, to: Option<usize>) {
+        match from {
+            None => self.head[level] = to,
+            Some(index) => self.nodes[index].as_mut().expect("live node").forward[level] = to,
+        }
+    }
+    fn random_level(&mut self) -> usize {
+        // each level is kept with probability 1/2
+        let mut level = 1;
+        while level < MAX_LEVEL && self.rng.gen_bool(0.5) {
+            level += 1;
+        }
+        level
+    }
+    /// Returns the smallest key in the list.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::new();
+    /// list.insert(1, 0);
+    /// list.insert(-1, 2);
+    /// assert_eq!(list.min(), Some(&-1));
+    /// ```
+    pub fn min(&self) -> Option<&K> {
+        self.head[0].map(|index| &self.node(index).key)
+    }
+    /// Returns an iterator over the (key, value) pairs of the list, in increasing order of keys.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::new();
+    /// list.insert(1, "b");
+    /// list.insert(0, "a");
+    /// assert_eq!(list.iter().collect::<Vec<_>>(), vec![(&0, &"a"), (&1, &"b")]);
+    /// ```
+    pub fn iter(&self) -> SkipListIter<'_, K, V> {
+        SkipListIter {
+            list: self,
+            current: self.head[0],
+        }
+    }
+}
+impl<K: Ord, V> SkipList<K, V> {
+    fn predecessors(&self, key: &K) -> [Option<usize>; MAX_LEVEL] {
+        // at each level, the last node whose key is strictly smaller than key
+        let mut update = [None; MAX_LEVEL];
+        let mut current = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next(current, level) {
+                if &self.node(next).key < key {
+                    current = Some(next);
+                } else {
+                    break;
+                }
+            }
+            update[level] = current;
+        }
+        update
+    }
+    fn find(&self, key: &K) -> Option<usize> {
+        let mut current = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next(current, level) {
+                match self.node(next).key.cmp(key) {
+                    Ordering::Less => current = Some(next),
+                    Ordering::Equal => return Some(next),
+                    Ordering::Greater => break,
+                }
+            }
+        }
+        None
+    }
+    /// Creates a new list with an initial (key, value) pair.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let list = SkipList::init("skip", 0);
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    pub fn init(key: K, value: V) -> Self {
+        let mut list = Self::new();
+        list.insert(key, value);
+        list
+    }
+    /// Tests whether or not the list contains a given key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let list = SkipList::init("skip", "one");
+    /// assert!(list.contains(&"skip"));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+    /// Returns a reference of the value associated to a key if any exists in the list.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let list = SkipList::init("skip", "one");
+    /// assert_eq!(list.get(&"no skip"), None);
+    /// assert_eq!(list.get(&"skip"), Some(&"one"));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.find(key).map(|index| &self.node(index).value)
+    }
+    /// Inserts a (key, value) pair in the list. When the input key is
+    /// already in the list, then it replaces the old value with the new one specified.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::<isize, usize>::new();
+    /// list.insert(-1, 2);
+    /// list.insert(-2, 3);
+    /// list.insert(-1, 4);
+    /// assert_eq!(list.len(), 2);
+    /// assert_eq!(list.get(&-1), Some(&4));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn insert(&mut self, key: K, value: V) {
+        let update = self.predecessors(&key);
+        if let Some(next) = self.next(update[0], 0) {
+            if self.node(next).key == key {
+                self.nodes[next].as_mut().expect("live node").value = value;
+                return;
+            }
+        }
+        let level = self.random_level();
+        // the new levels start from the head, as update is None there
+        self.level = self.level.max(level);
+        let forward = (0..level)
+            .map(|l| self.next(update[l], l))
+            .collect::<Vec<Option<usize>>>();
+        let node = Node {
+            key,
+            value,
+            forward,
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = Some(node);
+                index
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+        for (l, from) in update.iter().enumerate().take(level) {
+            self.set_next(*from, l, Some(index));
+        }
+        self.len += 1;
+    }
+    /// Removes a key from the list, returning the value associated if any.
+    /// Otherwise it returns `None`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::init(1, 2);
+    /// assert_eq!(list.delete(&1), Some(2));
+    /// assert_eq!(list.delete(&10), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn delete(&mut self, key: &K) -> Option<V> {
+        let update = self.predecessors(key);
+        let target = self.next(update[0], 0)?;
+        if &self.node(target).key != key {
+            return None;
+        }
+        let node = self.nodes[target].take().expect("live node");
+        for (l, next) in node.forward.into_iter().enumerate() {
+            self.set_next(update[l], l, next);
+        }
+        self.free.push(target);
+        while self.level > 1 && self.head[self.level - 1].is_none() {
+            self.level -= 1;
+        }
+        self.len -= 1;
+        Some(node.value)
+    }
+    /// Returns the largest key in the list.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::new();
+    /// list.insert(1, 0);
+    /// list.insert(-1, 2);
+    /// assert_eq!(list.max(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn max(&self) -> Option<&K> {
+        let mut current = None;
+        for level in (0..self.level).rev() {
+            while let Some(next) = self.next(current, level) {
+                current = Some(next);
+            }
+        }
+        current.map(|index| &self.node(index).key)
+    }
+    /// Returns the largest key in the list smaller or equal to the input key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::<isize, usize>::init(1, 0);
+    /// list.insert(-1, 2);
+    /// list.insert(-2, 3);
+    /// assert_eq!(list.floor(&1), Some(&1));
+    /// assert_eq!(list.floor(&0), Some(&-1));
+    /// assert_eq!(list.floor(&-3), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn floor(&self, key: &K) -> Option<&K> {
+        let update = self.predecessors(key);
+        if let Some(next) = self.next(update[0], 0) {
+            if &self.node(next).key == key {
+                return Some(&self.node(next).key);
+            }
+        }
+        update[0].map(|index| &self.node(index).key)
+    }
+    /// Returns the largest key in the list strictly inferior to the input key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::<isize, usize>::init(1, 0);
+    /// list.insert(-1, 2);
+    /// assert_eq!(list.strict_floor(&1), Some(&-1));
+    /// assert_eq!(list.strict_floor(&-1), None);
+    /// ```
+    pub fn strict_floor(&self, key: &K) -> Option<&K> {
+        self.predecessors(key)[0].map(|index| &self.node(index).key)
+    }
+    /// Returns the smallest key in the list larger or equal to the input key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::<isize, usize>::init(1, 0);
+    /// list.insert(-1, 2);
+    /// list.insert(-2, 3);
+    /// assert_eq!(list.ceil(&1), Some(&1));
+    /// assert_eq!(list.ceil(&0), Some(&1));
+    /// assert_eq!(list.ceil(&2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn ceil(&self, key: &K) -> Option<&K> {
+        let update = self.predecessors(key);
+        self.next(update[0], 0).map(|index| &self.node(index).key)
+    }
+    /// Returns the list of keys in the list that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::<isize, usize>::init(1, 0);
+    /// list.insert(-1, 2);
+    /// list.insert(-2, 2);
+    /// list.insert(-3, 3);
+    /// assert_eq!(list.range_search(&-2, &1), vec![&-2, &-1]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N) + R) on average, where R is the number of keys returned.
+    pub fn range_search(&self, low: &K, high: &K) -> Vec<&K> {
+        let mut current = self.next(self.predecessors(low)[0], 0);
+        let mut keys = Vec::new();
+        while let Some(index) = current {
+            let node = self.node(index);
+            if &node.key >= high {
+                break;
+            }
+            keys.push(&node.key);
+            current = node.forward[0];
+        }
+        keys
+    }
+    /// Returns the number of keys in the list that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SkipList;
+    /// let mut list = SkipList::<isize, usize>::init(1, 0);
+    /// list.insert(-1, 2);
+    /// list.insert(-2, 2);
+    /// list.insert(-3, 3);
+    /// assert_eq!(list.range_count(&-3, &-1), 2);
+    /// ```
+    pub fn range_count(&self, low: &K, high: &K) -> usize {
+        self.range_search(low, high).len()
+    }
+}
+
+/// Iterator over the (key, value) pairs of a `SkipList` in increasing order of keys.
+#[derive(Debug)]
+pub struct SkipListIter<'a, K, V> {
+    list: &'a SkipList<K, V>,
+    current: Option<usize>,
+}
+impl<'a, K, V> Iterator for SkipListIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.list.node(self.current?);
+        self.current = node.forward[0];
+        Some((&node.key, &node.value))
+    }
+}
+impl<'a, K, V> IntoIterator for &'a SkipList<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = SkipListIter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_skip_list_new() {
+        let list = SkipList::<usize, isize>::new();
+        assert!(list.is_empty());
+        assert_eq!(list.min(), None);
+        assert_eq!(list.max(), None);
+        assert_eq!(list.floor(&1), None);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn test_skip_list_insert_delete() {
+        let mut list = SkipList::<usize, &str>::with_seed(0);
+        list.insert(10, "test10");
+        list.insert(1, "test1");
+        list.insert(5, "test5");
+        list.insert(5, "test5bis");
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.get(&5), Some(&"test5bis"));
+        assert_eq!(list.floor(&4), Some(&1));
+        assert_eq!(list.ceil(&6), Some(&10));
+        assert_eq!(list.strict_floor(&5), Some(&1));
+        assert_eq!(list.delete(&5), Some("test5bis"));
+        assert_eq!(list.delete(&5), None);
+        assert_eq!(list.len(), 2);
+        // the freed slot of the arena is reused
+        list.insert(7, "test7");
+        assert_eq!(list.nodes.len(), 3);
+        assert_eq!(list.range_search(&0, &10), vec![&1, &7]);
+    }
+
+    #[test]
+    fn test_skip_list_against_btree_map() {
+        let mut list = SkipList::with_seed(1);
+        let mut map = BTreeMap::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2);
+        for step in 0..5000usize {
+            let key = rng.gen_range(0..1000usize);
+            if rng.gen_bool(0.3) {
+                assert_eq!(list.delete(&key), map.remove(&key));
+            } else {
+                list.insert(key, step);
+                map.insert(key, step);
+            }
+            assert_eq!(list.len(), map.len());
+        }
+        assert!(list.iter().eq(map.iter()));
+        assert_eq!(list.min(), map.keys().next());
+        assert_eq!(list.max(), map.keys().next_back());
+        for key in 0..1001usize {
+            assert_eq!(list.get(&key), map.get(&key));
+            assert_eq!(list.floor(&key), map.range(..=key).next_back().map(|e| e.0));
+            assert_eq!(list.ceil(&key), map.range(key..).next().map(|e| e.0));
+        }
+        assert_eq!(
+            list.range_search(&100, &400),
+            map.range(100..400).map(|e| e.0).collect::<Vec<&usize>>()
+        );
+        assert_eq!(list.range_count(&0, &1000), map.len());
+    }
+
+    #[test]
+    fn test_skip_list_levels() {
+        let mut list = SkipList::with_seed(3);
+        for key in 0..1024 {
+            list.insert(key, key);
+        }
+        assert!(list.level > 5 && list.level <= MAX_LEVEL);
+        for key in 0..1024 {
+            list.delete(&key);
+        }
+        assert!(list.is_empty());
+        assert_eq!(list.level, 1);
+    }
+}
@@ -0,0 +1,306 @@
+#[cfg(test)]
+mod unit_test;
+use crate::data_structure::{Monoid, RingDeque, Selective, VecStack};
+use std::marker::PhantomData;
+
+#[derive(Debug, Clone)]
+enum Strategy<T> {
+    // queue made of two stacks: objects are pushed on the back stack and popped
+    // from the front stack, which holds each object along with the aggregate
+    // of the object and the more recent objects below it
+    TwoStacks {
+        front: VecStack<(T, T)>,
+        back: VecStack<T>,
+        back_aggregate: T,
+    },
+    // monotonic deque: only the objects that may still be selected are candidates,
+    // the oldest candidate being selected over all the objects of the window
+    Monotonic {
+        objects: RingDeque<T>,
+        // candidates with their position in the stream of pushed objects
+        candidates: RingDeque<(usize, T)>,
+        pushed: usize,
+        // tells whether or not a new object is selected over an older one
+        dominates: fn(&T, &T) -> bool,
+    },
+}
+
+/// Implementation of a sliding window over a stream of objects, giving the aggregate
+/// of the objects in the window for a monoid `Op` (from the oldest to the most recent object).
+/// Windows either use a two-stack queue, for any monoid, or a monotonic deque,
+/// for monoids selecting one of their operands such as min and max.
+/// Windows can have a fixed size, the oldest object then leaving when a new one comes in.
+/// # Examples
+/// ```
+/// use algods::data_structure::{Max, SlidingWindow, Sum};
+/// let mut window = SlidingWindow::<usize, Max<usize>>::monotonic_with_size(3);
+/// let maxima = [1, 3, 2, 0, 1, 4]
+///     .into_iter()
+///     .map(|x| {
+///         window.push(x);
+///         window.query()
+///     })
+///     .collect::<Vec<usize>>();
+/// assert_eq!(maxima, vec![1, 3, 3, 3, 2, 4]);
+///
+/// let mut window = SlidingWindow::<isize, Sum<isize>>::new();
+/// window.push(2);
+/// window.push(-5);
+/// window.push(4);
+/// assert_eq!(window.query(), 1);
+/// assert_eq!(window.pop_front(), Some(2));
+/// assert_eq!(window.query(), -1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SlidingWindow<T, Op> {
+    strategy: Strategy<T>,
+    // maximum number of objects in the window, if any
+    size: Option<usize>,
+    op: PhantomData<Op>,
+}
+impl<T: Clone, Op: Monoid<T>> Default for SlidingWindow<T, Op> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T: Clone, Op: Monoid<T>> SlidingWindow<T, Op> {
+    fn two_stacks(size: Option<usize>) -> Self {
+        Self {
+            strategy: Strategy::TwoStacks {
+                front: VecStack::new(),
+                back: VecStack::new(),
+                back_aggregate: Op::identity(),
+            },
+            size,
+            op: PhantomData,
+        }
+    }
+    /// Creates an empty window, with no size limit, using a two-stack queue.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SlidingWindow, Sum};
+    /// let window = SlidingWindow::<usize, Sum<usize>>::new();
+    /// assert!(window.is_empty());
+    /// assert_eq!(window.size(), None);
+    /// ```
+    pub fn new() -> Self {
+        Self::two_stacks(None)
+    }
+    /// Creates an empty window holding at most `size` objects, using a two-stack queue.
+    /// # Panics
+    /// If `size = 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SlidingWindow, Sum};
+    /// let window = SlidingWindow::<usize, Sum<usize>>::with_size(10);
+    /// assert_eq!(window.size(), Some(10));
+    /// ```
+    pub fn with_size(size: usize) -> Self {
+        if size == 0 {
+            panic!("the size of the window should be > 0");
+        }
+        Self::two_stacks(Some(size))
+    }
+    /// Gives the number of objects in the window.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SlidingWindow, Sum};
+    /// let mut window = SlidingWindow::<usize, Sum<usize>>::new();
+    /// window.push(1);
+    /// assert_eq!(window.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        match &self.strategy {
+            Strategy::TwoStacks { front, back, .. } => front.len() + back.len(),
+            Strategy::Monotonic { objects, .. } => objects.len(),
+        }
+    }
+    /// Tests whether or not the window is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SlidingWindow, Sum};
+    /// let window = SlidingWindow::<usize, Sum<usize>>::new();
+    /// assert!(window.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Gives the maximum number of objects in the window, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Min, SlidingWindow};
+    /// let window = SlidingWindow::<usize, Min<usize>>::monotonic_with_size(5);
+    /// assert_eq!(window.size(), Some(5));
+    /// ```
+    pub fn size(&self) -> Option<usize> {
+        self.size
+    }
+    /// Adds an object at the back of the window. When the window has a fixed size
+    /// and is full, then the oldest object leaves the window and is returned.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{SlidingWindow, Sum};
+    /// let mut window = SlidingWindow::<usize, Sum<usize>>::with_size(2);
+    /// assert_eq!(window.push(1), None);
+    /// assert_eq!(window.push(2), None);
+    /// assert_eq!(window.push(3), Some(1));
+    /// assert_eq!(window.query(), 5);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(1)
+    pub fn push(&mut self, object: T) -> Option<T> {
+        let evicted = match self.size {
+            Some(size) if self.len() == size => self.pop_front(),
+            _ => None,
+        };
+        match &mut self.strategy {
+            Strategy::TwoStacks {
+                back,
+                back_aggregate,
+                ..
+            } => {
+                *back_aggregate = Op::combine(back_aggregate, &object);
+                back.push(object);
+            }
+            Strategy::Monotonic {
+                objects,
+                candidates,
+                pushed,
+                dominates,
+            } => {
+                // older candidates that the object is selected over can never be selected again
+                while candidates
+                    .last()
+                    .is_some_and(|(_, candidate)| dominates(candidate, &object))
+                {
+                    candidates.remove_last();
+                }
+                candidates.add_last((*pushed, object.clone()));
+                objects.add_last(object);
+                *pushed += 1;
+            }
+        }
+        evicted
+    }
+    /// Removes the oldest object from the window and returns it, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Min, SlidingWindow};
+    /// let mut window = SlidingWindow::<usize, Min<usize>>::monotonic();
+    /// window.push(1);
+    /// window.push(2);
+    /// assert_eq!(window.pop_front(), Some(1));
+    /// assert_eq!(window.query(), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(1)
+    pub fn pop_front(&mut self) -> Option<T> {
+        let len = self.len();
+        match &mut self.strategy {
+            Strategy::TwoStacks {
+                front,
+                back,
+                back_aggregate,
+            } => {
+                if front.is_empty() {
+                    // the most recent objects go first, so that the oldest one ends on top
+                    while !back.is_empty() {
+                        let object = back.pop().expect("non empty stack");
+                        let aggregate = match front.peek() {
+                            Some((_, aggregate)) => Op::combine(&object, aggregate),
+                            None => object.clone(),
+                        };
+                        front.push((object, aggregate));
+                    }
+                    *back_aggregate = Op::identity();
+                }
+                if front.is_empty() {
+                    None
+                } else {
+                    front.pop().map(|(object, _)| object)
+                }
+            }
+            Strategy::Monotonic {
+                objects,
+                candidates,
+                pushed,
+                ..
+            } => {
+                let object = objects.remove_first()?;
+                if candidates
+                    .first()
+                    .is_some_and(|(position, _)| *position == *pushed - len)
+                {
+                    candidates.remove_first();
+                }
+                Some(object)
+            }
+        }
+    }
+    /// Gives the aggregate of the objects in the window, the identity of the monoid when empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Min, SlidingWindow};
+    /// let mut window = SlidingWindow::<usize, Min<usize>>::new();
+    /// assert_eq!(window.query(), usize::MAX);
+    /// window.push(3);
+    /// window.push(2);
+    /// assert_eq!(window.query(), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn query(&self) -> T {
+        match &self.strategy {
+            Strategy::TwoStacks {
+                front,
+                back_aggregate,
+                ..
+            } => match front.peek() {
+                Some((_, aggregate)) => Op::combine(aggregate, back_aggregate),
+                None => back_aggregate.clone(),
+            },
+            Strategy::Monotonic { candidates, .. } => candidates
+                .first()
+                .map_or_else(Op::identity, |(_, candidate)| candidate.clone()),
+        }
+    }
+}
+impl<T: Clone + PartialEq, Op: Selective<T>> SlidingWindow<T, Op> {
+    fn monotonic_deque(size: Option<usize>) -> Self {
+        Self {
+            strategy: Strategy::Monotonic {
+                objects: RingDeque::new(),
+                candidates: RingDeque::new(),
+                pushed: 0,
+                dominates: |older, newer| Op::combine(older, newer) == *newer,
+            },
+            size,
+            op: PhantomData,
+        }
+    }
+    /// Creates an empty window, with no size limit, using a monotonic deque.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Max, SlidingWindow};
+    /// let window = SlidingWindow::<isize, Max<isize>>::monotonic();
+    /// assert!(window.is_empty());
+    /// ```
+    pub fn monotonic() -> Self {
+        Self::monotonic_deque(None)
+    }
+    /// Creates an empty window holding at most `size` objects, using a monotonic deque.
+    /// # Panics
+    /// If `size = 0`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Max, SlidingWindow};
+    /// let window = SlidingWindow::<isize, Max<isize>>::monotonic_with_size(3);
+    /// assert_eq!(window.size(), Some(3));
+    /// ```
+    pub fn monotonic_with_size(size: usize) -> Self {
+        if size == 0 {
+            panic!("the size of the window should be > 0");
+        }
+        Self::monotonic_deque(Some(size))
+    }
+}
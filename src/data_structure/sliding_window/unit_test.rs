@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use crate::data_structure::{Gcd, Max, Min, Sum};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::VecDeque;
+
+    // non commutative monoid checking that the aggregate follows the order of the window
+    #[derive(Debug)]
+    struct Concat;
+    impl Monoid<String> for Concat {
+        fn identity() -> String {
+            String::new()
+        }
+        fn combine(a: &String, b: &String) -> String {
+            format!("{a}{b}")
+        }
+    }
+
+    fn check_against_vec_deque<T, Op>(
+        mut window: SlidingWindow<T, Op>,
+        stream: Vec<T>,
+        pop_probability: f64,
+        seed: u64,
+    ) where
+        T: Clone + PartialEq + std::fmt::Debug,
+        Op: Monoid<T>,
+    {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut model = VecDeque::new();
+        let aggregate = |model: &VecDeque<T>| {
+            model
+                .iter()
+                .fold(Op::identity(), |acc: T, x| Op::combine(&acc, x))
+        };
+        for object in stream {
+            if rng.gen_bool(pop_probability) {
+                assert_eq!(window.pop_front(), model.pop_front());
+            }
+            let evicted = match window.size() {
+                Some(size) if model.len() == size => model.pop_front(),
+                _ => None,
+            };
+            model.push_back(object.clone());
+            assert_eq!(window.push(object), evicted);
+            assert_eq!(window.len(), model.len());
+            assert_eq!(window.query(), aggregate(&model));
+        }
+        while !model.is_empty() {
+            assert_eq!(window.pop_front(), model.pop_front());
+            assert_eq!(window.query(), aggregate(&model));
+        }
+        assert_eq!(window.pop_front(), None);
+    }
+
+    #[test]
+    fn test_sliding_window_min_max() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let stream = (0..3000)
+            .map(|_| rng.gen_range(-50..50))
+            .collect::<Vec<isize>>();
+        for (seed, size) in [None, Some(1), Some(7), Some(100)].into_iter().enumerate() {
+            let seed = seed as u64;
+            let (min, max) = match size {
+                None => (
+                    SlidingWindow::<isize, Min<isize>>::monotonic(),
+                    SlidingWindow::<isize, Max<isize>>::monotonic(),
+                ),
+                Some(size) => (
+                    SlidingWindow::monotonic_with_size(size),
+                    SlidingWindow::monotonic_with_size(size),
+                ),
+            };
+            check_against_vec_deque(min, stream.clone(), 0.4, seed);
+            check_against_vec_deque(max, stream.clone(), 0.4, seed);
+            let min = size.map_or_else(SlidingWindow::<isize, Min<isize>>::new, |size| {
+                SlidingWindow::with_size(size)
+            });
+            check_against_vec_deque(min, stream.clone(), 0.4, seed);
+        }
+    }
+
+    #[test]
+    fn test_sliding_window_general_monoids() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let stream = (0..2000)
+            .map(|_| 6 * rng.gen_range(1..20u64))
+            .collect::<Vec<u64>>();
+        check_against_vec_deque(
+            SlidingWindow::<u64, Gcd<u64>>::new(),
+            stream.clone(),
+            0.5,
+            2,
+        );
+        check_against_vec_deque(
+            SlidingWindow::<u64, Sum<u64>>::with_size(50),
+            stream,
+            0.1,
+            3,
+        );
+        let words = (0..500)
+            .map(|_| char::from(rng.gen_range(b'a'..=b'z')).to_string())
+            .collect::<Vec<String>>();
+        check_against_vec_deque(
+            SlidingWindow::<String, Concat>::new(),
+            words.clone(),
+            0.3,
+            4,
+        );
+        check_against_vec_deque(SlidingWindow::<String, Concat>::with_size(5), words, 0.0, 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sliding_window_zero_size() {
+        SlidingWindow::<usize, Max<usize>>::monotonic_with_size(0);
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod unit_test;
+use crate::data_structure::Idempotent;
+use std::marker::PhantomData;
+
+/// Implementation of a sparse table: a static structure answering range queries
+/// for an idempotent monoid `Op` (e.g. min, max or gcd) in O(1), after an O(N log(N)) build.
+/// The row k holds the aggregates of all the ranges of length 2^k, so that any range
+/// is covered by two (overlapping) ranges of the same row.
+/// # Examples
+/// ```
+/// use algods::data_structure::{Gcd, Max, Min, SparseTable};
+/// let table = SparseTable::<isize, Min<isize>>::from_vec(vec![5, 3, 8, 6, 1, 4]);
+/// assert_eq!(table.query(0, 3), 3);
+/// assert_eq!(table.query(2, 6), 1);
+///
+/// let table = SparseTable::<usize, Max<usize>>::from_vec(vec![5, 3, 8, 6, 1, 4]);
+/// assert_eq!(table.query(3, 6), 6);
+///
+/// let table = SparseTable::<u64, Gcd<u64>>::from_vec(vec![12, 18, 24, 9]);
+/// assert_eq!(table.query(0, 3), 6);
+/// assert_eq!(table.query(0, 4), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseTable<T, Op> {
+    // table[k][i] is the aggregate of the objects in [i, i + 2^k)
+    table: Vec<Vec<T>>,
+    op: PhantomData<Op>,
+}
+impl<T: Clone, Op: Idempotent<T>> SparseTable<T, Op> {
+    /// Creates a sparse table from a `Vec` of objects.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Min, SparseTable};
+    /// let table = SparseTable::<usize, Min<usize>>::from_vec(vec![2, 1]);
+    /// assert_eq!(table.len(), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N log(N))
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let n = vec.len();
+        let mut table = vec![vec];
+        let mut width = 1;
+        while 2 * width <= n {
+            let previous = &table[table.len() - 1];
+            let row = (0..=n - 2 * width)
+                .map(|i| Op::combine(&previous[i], &previous[i + width]))
+                .collect();
+            table.push(row);
+            width *= 2;
+        }
+        Self {
+            table,
+            op: PhantomData,
+        }
+    }
+    /// Gives the number of objects in the table.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Min, SparseTable};
+    /// let table = SparseTable::<usize, Min<usize>>::from_vec(vec![2, 1]);
+    /// assert_eq!(table.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+    /// Tests whether or not the table is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Min, SparseTable};
+    /// let table = SparseTable::<usize, Min<usize>>::from_vec(Vec::new());
+    /// assert!(table.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns the aggregate of the objects between two positions (low included, high excluded).
+    /// It is the identity of the monoid when `low = high`.
+    /// # Panics
+    /// If `high > self.len()` or `low > high`, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::{Min, SparseTable};
+    /// let table = SparseTable::<isize, Min<isize>>::from_vec(vec![1, -2, 3, 4]);
+    /// assert_eq!(table.query(2, 4), 3);
+    /// assert_eq!(table.query(2, 2), isize::MAX);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn query(&self, low: usize, high: usize) -> T {
+        if high > self.len() {
+            panic!(
+                "range end {high} is out of bounds, the table has {} objects",
+                self.len()
+            );
+        }
+        if low > high {
+            panic!("range start {low} is larger than range end {high}");
+        }
+        if low == high {
+            return Op::identity();
+        }
+        let k = (high - low).ilog2() as usize;
+        Op::combine(&self.table[k][low], &self.table[k][high - (1 << k)])
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use crate::data_structure::{Gcd, Max, Min};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn gcd(a: u32, b: u32) -> u32 {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn test_sparse_table_brute_force() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for n in [1, 2, 3, 7, 8, 9, 100] {
+            let vec = (0..n)
+                .map(|_| 6 * rng.gen_range(1..50u32))
+                .collect::<Vec<_>>();
+            let min = SparseTable::<u32, Min<u32>>::from_vec(vec.clone());
+            let max = SparseTable::<u32, Max<u32>>::from_vec(vec.clone());
+            let gcds = SparseTable::<u32, Gcd<u32>>::from_vec(vec.clone());
+            for low in 0..n {
+                for high in low + 1..=n {
+                    let range = &vec[low..high];
+                    assert_eq!(min.query(low, high), *range.iter().min().unwrap());
+                    assert_eq!(max.query(low, high), *range.iter().max().unwrap());
+                    assert_eq!(
+                        gcds.query(low, high),
+                        range.iter().fold(0, |a, b| gcd(a, *b))
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_sparse_table_empty() {
+        let table = SparseTable::<u32, Gcd<u32>>::from_vec(Vec::new());
+        assert!(table.is_empty());
+        assert_eq!(table.query(0, 0), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sparse_table_out_of_bounds() {
+        let table = SparseTable::<u32, Min<u32>>::from_vec(vec![1, 2]);
+        table.query(1, 3);
+    }
+}
@@ -0,0 +1,519 @@
+#[cfg(test)]
+mod unit_test;
+use std::cell::Cell;
+use std::cmp::Ordering;
+
+#[derive(Clone, Debug)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    // positions of the children in the arena, in cells so that
+    // lookups can restructure the tree through a shared reference
+    left: Cell<Option<usize>>,
+    right: Cell<Option<usize>>,
+}
+
+/// Implementation of a splay tree: a self-adjusting binary search tree of (key, value) pairs
+/// where every access moves the accessed key to the root by a sequence of rotations.
+/// Operations run in amortized O(log(N)), and recently or frequently accessed keys
+/// are found faster, which suits skewed access patterns.
+/// Only the links between nodes change on lookups, they are kept in cells
+/// so that lookups take a shared reference as in other ordered maps.
+/// # Example
+/// ```
+/// use algods::data_structure::SplayTree;
+/// let mut tree = SplayTree::new();
+/// tree.insert(2, "2");
+/// tree.insert(0, "0");
+/// tree.insert(1, "1");
+/// assert_eq!(tree.len(), 3);
+/// assert_eq!(tree.get(&1), Some(&"1"));
+/// assert_eq!(tree.delete(&1), Some("1"));
+/// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![(&0, &"0"), (&2, &"2")]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SplayTree<K, V> {
+    // arena of nodes, None slots are free
+    nodes: Vec<Option<Node<K, V>>>,
+    // free slots in the arena
+    free: Vec<usize>,
+    root: Cell<Option<usize>>,
+    len: usize,
+}
+impl<K, V> Default for SplayTree<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K, V> SplayTree<K, V> {
+    /// Creates an empty splay tree instance.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let tree = SplayTree::<usize, isize>::new();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: Cell::new(None),
+            len: 0,
+        }
+    }
+    /// Gives the number of (key, value) pairs in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let tree = SplayTree::<usize, usize>::new();
+    /// assert_eq!(tree.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Tests whether or not the tree is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1, 1);
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn node(&self, index: usize) -> &Node<K, V> {
+        self.nodes[index].as_ref().expect("live node")
+    }
+    /// Returns an iterator over the (key, value) pairs of the tree, in increasing order of keys.
+    /// Iterating does not restructure the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1, "b");
+    /// tree.insert(0, "a");
+    /// assert_eq!(tree.iter().collect::<Vec<_>>(), vec![(&0, &"a"), (&1, &"b")]);
+    /// ```
+    pub fn iter(&self) -> SplayTreeIter<'_, K, V> {
+        // the order is collected beforehand, as lookups during the iteration may restructure the tree
+        let mut order = Vec::with_capacity(self.len);
+        let (mut stack, mut current) = (Vec::new(), self.root.get());
+        loop {
+            while let Some(index) = current {
+                stack.push(index);
+                current = self.node(index).left.get();
+            }
+            let Some(index) = stack.pop() else {
+                break;
+            };
+            order.push(index);
+            current = self.node(index).right.get();
+        }
+        SplayTreeIter {
+            tree: self,
+            order: order.into_iter(),
+        }
+    }
+}
+impl<K: Ord, V> SplayTree<K, V> {
+    // Top-down splay of the subtree rooted at `root`: brings the node with the key,
+    // or the last node on its search path, to the root of the subtree and returns it.
+    fn splay(&self, mut root: usize, key: &K) -> usize {
+        // roots of the trees of nodes smaller and larger than the key, and the nodes
+        // where the next smaller (resp. larger) nodes are attached
+        let (mut smaller, mut larger) = (None, None);
+        let (mut smaller_last, mut larger_last): (Option<usize>, Option<usize>) = (None, None);
+        loop {
+            let node = self.node(root);
+            match key.cmp(&node.key) {
+                Ordering::Less => {
+                    let Some(mut child) = node.left.get() else {
+                        break;
+                    };
+                    if *key < self.node(child).key {
+                        // rotates right
+                        node.left.set(self.node(child).right.get());
+                        self.node(child).right.set(Some(root));
+                        root = child;
+                        match self.node(root).left.get() {
+                            Some(next) => child = next,
+                            None => break,
+                        }
+                    }
+                    // links the root to the tree of larger nodes
+                    match larger_last {
+                        Some(last) => self.node(last).left.set(Some(root)),
+                        None => larger = Some(root),
+                    }
+                    larger_last = Some(root);
+                    root = child;
+                }
+                Ordering::Greater => {
+                    let Some(mut child) = node.right.get() else {
+                        break;
+                    };
+                    if *key > self.node(child).key {
+                        // rotates left
+                        node.right.set(self.node(child).left.get());
+                        self.node(child).left.set(Some(root));
+                        root = child;
+                        match self.node(root).right.get() {
+                            Some(next) => child = next,
+                            None => break,
+                        }
+                    }
+                    // links the root to the tree of smaller nodes
+                    match smaller_last {
+                        Some(last) => self.node(last).right.set(Some(root)),
+                        None => smaller = Some(root),
+                    }
+                    smaller_last = Some(root);
+                    root = child;
+                }
+                Ordering::Equal => break,
+            }
+        }
+        // reassembles the trees around the new root
+        let node = self.node(root);
+        match smaller_last {
+            Some(last) => self.node(last).right.set(node.left.get()),
+            None => smaller = node.left.get(),
+        }
+        match larger_last {
+            Some(last) => self.node(last).left.set(node.right.get()),
+            None => larger = node.right.get(),
+        }
+        node.left.set(smaller);
+        node.right.set(larger);
+        root
+    }
+    // Splays the tree on a key and returns the new root
+    fn splay_root(&self, key: &K) -> Option<usize> {
+        let root = self.splay(self.root.get()?, key);
+        self.root.set(Some(root));
+        Some(root)
+    }
+    fn find(&self, key: &K) -> Option<usize> {
+        self.splay_root(key)
+            .filter(|root| self.node(*root).key == *key)
+    }
+    /// Creates a new tree with an initial (key, value) pair.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let tree = SplayTree::init("splay", 0);
+    /// assert_eq!(tree.len(), 1);
+    /// ```
+    pub fn init(key: K, value: V) -> Self {
+        let mut tree = Self::new();
+        tree.insert(key, value);
+        tree
+    }
+    /// Tests whether or not the tree contains a given key, and moves it to the root.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let tree = SplayTree::init("splay", "one");
+    /// assert!(tree.contains(&"splay"));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn contains(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+    /// Returns a reference of the value associated to a key if any exists in the tree,
+    /// and moves the key to the root. Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let tree = SplayTree::init("splay", "one");
+    /// assert_eq!(tree.get(&"no splay"), None);
+    /// assert_eq!(tree.get(&"splay"), Some(&"one"));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.find(key).map(|index| &self.node(index).value)
+    }
+    /// Inserts a (key, value) pair at the root of the tree. When the input key is
+    /// already in the tree, then it replaces the old value with the new one specified.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::<isize, usize>::new();
+    /// tree.insert(-1, 2);
+    /// tree.insert(-2, 3);
+    /// tree.insert(-1, 4);
+    /// assert_eq!(tree.len(), 2);
+    /// assert_eq!(tree.get(&-1), Some(&4));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn insert(&mut self, key: K, value: V) {
+        let (left, right) = match self.splay_root(&key) {
+            None => (None, None),
+            Some(root) => {
+                let node = self.node(root);
+                match key.cmp(&node.key) {
+                    Ordering::Equal => {
+                        self.nodes[root].as_mut().expect("live node").value = value;
+                        return;
+                    }
+                    // the root and its right subtree go to the right of the new node
+                    Ordering::Less => (node.left.replace(None), Some(root)),
+                    Ordering::Greater => (Some(root), node.right.replace(None)),
+                }
+            }
+        };
+        let node = Node {
+            key,
+            value,
+            left: Cell::new(left),
+            right: Cell::new(right),
+        };
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = Some(node);
+                index
+            }
+            None => {
+                self.nodes.push(Some(node));
+                self.nodes.len() - 1
+            }
+        };
+        self.root.set(Some(index));
+        self.len += 1;
+    }
+    /// Removes a key from the tree, returning the value associated if any.
+    /// Otherwise it returns `None`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::init(1, 2);
+    /// assert_eq!(tree.delete(&1), Some(2));
+    /// assert_eq!(tree.delete(&10), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn delete(&mut self, key: &K) -> Option<V> {
+        let target = self.find(key)?;
+        let node = self.nodes[target].take().expect("live node");
+        // the largest key of the left subtree becomes the root, without right child
+        let root = match node.left.get() {
+            None => node.right.get(),
+            Some(left) => {
+                let root = self.splay(left, key);
+                self.node(root).right.set(node.right.get());
+                Some(root)
+            }
+        };
+        self.root.set(root);
+        self.free.push(target);
+        self.len -= 1;
+        Some(node.value)
+    }
+    /// Returns the smallest key in the tree, and moves it to the root.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1, 0);
+    /// tree.insert(-1, 2);
+    /// assert_eq!(tree.min(), Some(&-1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn min(&self) -> Option<&K> {
+        let mut current = self.root.get()?;
+        while let Some(left) = self.node(current).left.get() {
+            current = left;
+        }
+        self.splay_at(current)
+    }
+    /// Returns the largest key in the tree, and moves it to the root.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(1, 0);
+    /// tree.insert(-1, 2);
+    /// assert_eq!(tree.max(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn max(&self) -> Option<&K> {
+        let mut current = self.root.get()?;
+        while let Some(right) = self.node(current).right.get() {
+            current = right;
+        }
+        self.splay_at(current)
+    }
+    // Moves a node found by a search to the root, which pays for the search path
+    fn splay_at(&self, index: usize) -> Option<&K> {
+        let key = &self.node(index).key;
+        self.splay_root(key);
+        Some(key)
+    }
+    /// Returns the largest key in the tree smaller or equal to the input key,
+    /// and moves it to the root.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::<isize, usize>::init(1, 0);
+    /// tree.insert(-1, 2);
+    /// assert_eq!(tree.floor(&1), Some(&1));
+    /// assert_eq!(tree.floor(&0), Some(&-1));
+    /// assert_eq!(tree.floor(&-3), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn floor(&self, key: &K) -> Option<&K> {
+        let (mut current, mut floor) = (self.root.get(), None);
+        while let Some(index) = current {
+            let node = self.node(index);
+            if node.key <= *key {
+                floor = Some(index);
+                current = node.right.get();
+            } else {
+                current = node.left.get();
+            }
+        }
+        self.splay_at(floor?)
+    }
+    /// Returns the largest key in the tree strictly inferior to the input key,
+    /// and moves it to the root.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::<isize, usize>::init(1, 0);
+    /// tree.insert(-1, 2);
+    /// assert_eq!(tree.strict_floor(&1), Some(&-1));
+    /// assert_eq!(tree.strict_floor(&-1), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn strict_floor(&self, key: &K) -> Option<&K> {
+        let (mut current, mut floor) = (self.root.get(), None);
+        while let Some(index) = current {
+            let node = self.node(index);
+            if node.key < *key {
+                floor = Some(index);
+                current = node.right.get();
+            } else {
+                current = node.left.get();
+            }
+        }
+        self.splay_at(floor?)
+    }
+    /// Returns the smallest key in the tree larger or equal to the input key,
+    /// and moves it to the root.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::<isize, usize>::init(1, 0);
+    /// tree.insert(-1, 2);
+    /// assert_eq!(tree.ceil(&1), Some(&1));
+    /// assert_eq!(tree.ceil(&0), Some(&1));
+    /// assert_eq!(tree.ceil(&2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N))
+    pub fn ceil(&self, key: &K) -> Option<&K> {
+        let (mut current, mut ceil) = (self.root.get(), None);
+        while let Some(index) = current {
+            let node = self.node(index);
+            if node.key >= *key {
+                ceil = Some(index);
+                current = node.left.get();
+            } else {
+                current = node.right.get();
+            }
+        }
+        self.splay_at(ceil?)
+    }
+    /// Returns the list of keys in the tree that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::<isize, usize>::init(1, 0);
+    /// tree.insert(-1, 2);
+    /// tree.insert(-2, 2);
+    /// tree.insert(-3, 3);
+    /// assert_eq!(tree.range_search(&-2, &1), vec![&-2, &-1]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in amortized O(log(N) + R), where R is the number of keys returned.
+    pub fn range_search(&self, low: &K, high: &K) -> Vec<&K> {
+        let mut keys = Vec::new();
+        // the smallest key larger or equal to low becomes the root,
+        // the range then lies in the root and its right subtree
+        let Some(first) = self.ceil(low) else {
+            return keys;
+        };
+        if first >= high {
+            return keys;
+        }
+        let root = self.node(self.root.get().expect("non empty tree"));
+        keys.push(&root.key);
+        let mut stack = Vec::new();
+        let mut current = root.right.get();
+        loop {
+            while let Some(index) = current {
+                stack.push(index);
+                current = self.node(index).left.get();
+            }
+            match stack.pop() {
+                Some(index) if self.node(index).key < *high => {
+                    keys.push(&self.node(index).key);
+                    current = self.node(index).right.get();
+                }
+                _ => return keys,
+            }
+        }
+    }
+    /// Returns the number of keys in the tree that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::SplayTree;
+    /// let mut tree = SplayTree::<isize, usize>::init(1, 0);
+    /// tree.insert(-1, 2);
+    /// tree.insert(-2, 2);
+    /// tree.insert(-3, 3);
+    /// assert_eq!(tree.range_count(&-3, &-1), 2);
+    /// ```
+    pub fn range_count(&self, low: &K, high: &K) -> usize {
+        self.range_search(low, high).len()
+    }
+}
+impl<K: Ord, V> FromIterator<(K, V)> for SplayTree<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut tree = Self::new();
+        for (key, value) in iter {
+            tree.insert(key, value);
+        }
+        tree
+    }
+}
+
+/// Iterator over the (key, value) pairs of a `SplayTree` in increasing order of keys.
+#[derive(Debug)]
+pub struct SplayTreeIter<'a, K, V> {
+    tree: &'a SplayTree<K, V>,
+    // positions of the nodes in increasing order of keys
+    order: std::vec::IntoIter<usize>,
+}
+impl<'a, K, V> Iterator for SplayTreeIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.tree.node(self.order.next()?);
+        Some((&node.key, &node.value))
+    }
+}
+impl<'a, K, V> IntoIterator for &'a SplayTree<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = SplayTreeIter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,97 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    // checks the search tree order and returns the number of nodes
+    fn check_order<K: Ord, V>(tree: &SplayTree<K, V>, index: Option<usize>) -> usize {
+        match index {
+            None => 0,
+            Some(index) => {
+                let node = tree.node(index);
+                if let Some(left) = node.left.get() {
+                    assert!(tree.node(left).key < node.key);
+                }
+                if let Some(right) = node.right.get() {
+                    assert!(tree.node(right).key > node.key);
+                }
+                1 + check_order(tree, node.left.get()) + check_order(tree, node.right.get())
+            }
+        }
+    }
+
+    #[test]
+    fn test_splay_tree_against_btree_map() {
+        let mut tree = SplayTree::new();
+        let mut map = BTreeMap::new();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(2);
+        for step in 0..5000usize {
+            let key = rng.gen_range(0..1000usize);
+            match rng.gen_range(0..3) {
+                0 => assert_eq!(tree.delete(&key), map.remove(&key)),
+                1 => assert_eq!(tree.get(&key), map.get(&key)),
+                _ => {
+                    tree.insert(key, step);
+                    map.insert(key, step);
+                }
+            }
+            assert_eq!(tree.len(), map.len());
+        }
+        assert_eq!(check_order(&tree, tree.root.get()), map.len());
+        assert!(tree.iter().eq(map.iter()));
+        assert_eq!(tree.min(), map.keys().next());
+        assert_eq!(tree.max(), map.keys().next_back());
+        for key in 0..1001usize {
+            assert_eq!(tree.get(&key), map.get(&key));
+            assert_eq!(tree.floor(&key), map.range(..=key).next_back().map(|e| e.0));
+            assert_eq!(
+                tree.strict_floor(&key),
+                map.range(..key).next_back().map(|e| e.0)
+            );
+            assert_eq!(tree.ceil(&key), map.range(key..).next().map(|e| e.0));
+        }
+        for (low, high) in [(100, 400), (0, 1000), (500, 500), (700, 300)] {
+            let expected = map
+                .range(low..high.max(low))
+                .map(|e| e.0)
+                .collect::<Vec<_>>();
+            assert_eq!(tree.range_search(&low, &high), expected);
+            assert_eq!(tree.range_count(&low, &high), expected.len());
+        }
+        assert_eq!(check_order(&tree, tree.root.get()), map.len());
+    }
+
+    #[test]
+    fn test_splay_tree_accessed_key_is_root() {
+        let tree = (0..1000).map(|k| (k, k)).collect::<SplayTree<_, _>>();
+        assert_eq!(tree.get(&500), Some(&500));
+        assert_eq!(tree.node(tree.root.get().unwrap()).key, 500);
+        assert_eq!(tree.ceil(&1000), None);
+        assert_eq!(tree.floor(&-1), None);
+        assert_eq!(tree.strict_floor(&10), Some(&9));
+        assert_eq!(tree.node(tree.root.get().unwrap()).key, 9);
+    }
+
+    #[test]
+    fn test_splay_tree_freed_slots_are_reused() {
+        let mut tree = SplayTree::init(1, 1);
+        tree.insert(2, 2);
+        assert_eq!(tree.delete(&1), Some(1));
+        tree.insert(3, 3);
+        assert_eq!(tree.nodes.len(), 2);
+        assert_eq!(tree.iter().collect::<Vec<_>>(), vec![(&2, &2), (&3, &3)]);
+    }
+
+    #[test]
+    fn test_splay_tree_iter_with_lookups() {
+        let tree = (0..100).map(|k| (k, k)).collect::<SplayTree<_, _>>();
+        let mut keys = Vec::new();
+        for (key, _) in tree.iter() {
+            keys.push(*key);
+            // restructures the tree during the iteration
+            tree.get(&(99 - key));
+        }
+        assert_eq!(keys, (0..100).collect::<Vec<_>>());
+    }
+}
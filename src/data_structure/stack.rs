@@ -133,7 +133,7 @@ impl<T> Stack<T> {
     }
 }
 
-impl<T: Clone> Stack<T> {
+impl<T> Stack<T> {
     /// Deletes and returns the last object in the stack, if any.
     /// # Panics
     /// When there is no element in the stack, it panics.
@@ -144,14 +144,12 @@ impl<T: Clone> Stack<T> {
     /// assert_eq!(stack.pop(), Some(1));
     /// ```
     pub fn pop(&mut self) -> Option<T> {
-        // run time complexity O(N) (due to cloning) ?
-        // space complexity O(N) (due to cloning) ?
-        match self.first {
-            Some(ref node) => {
-                let item = node.item.clone();
-                self.first = node.next.clone();
+        // run time complexity O(1)
+        match self.first.take() {
+            Some(mut node) => {
+                self.first = node.next.take();
                 self.len -= 1;
-                Some(item)
+                Some(node.item)
             }
             None => panic!("cannot pop, stack is empty"),
         }
@@ -167,16 +165,25 @@ impl<T: Clone> Stack<T> {
     /// assert_eq!(stack.pop(), Some(-2));
     /// ```
     pub fn push(&mut self, s: T) {
-        // run time complexity O(1) (due to cloning) ?
-        let oldfirst = self.first.clone();
+        // run time complexity O(1)
         let new_node = Node {
             item: s,
-            next: oldfirst,
+            next: self.first.take(),
         };
         self.first = Some(Box::new(new_node));
         self.len += 1;
     }
 }
+impl<T> Drop for Stack<T> {
+    fn drop(&mut self) {
+        // unlinks the nodes one by one, since the recursive drop
+        // of a long chain of boxes would overflow the call stack
+        let mut first = self.first.take();
+        while let Some(mut node) = first {
+            first = node.next.take();
+        }
+    }
+}
 
 /// Implementation of stacks using the standard library
 /// # Examples
@@ -415,6 +422,23 @@ impl<T> VecStack<T> {
         }
     }
 
+    /// Returns a reference to the last object in the stack, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecStack;
+    /// let mut stack = VecStack::new();
+    /// assert_eq!(stack.peek(), None);
+    /// stack.push(1);
+    /// stack.push(0);
+    /// assert_eq!(stack.peek(), Some(&0));
+    /// ```
+    pub fn peek(&self) -> Option<&T> {
+        // run time complexity O(1)
+        self.n
+            .checked_sub(1)
+            .and_then(|last| self.vec[last].as_ref())
+    }
+
     fn double(&mut self) {
         // run time complexity O(N)
         // doubling the size of the stack
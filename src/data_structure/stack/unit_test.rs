@@ -2,15 +2,11 @@
 mod tests {
     use super::super::*;
 
-    fn unbox<T>(value: Box<T>) -> T {
-        *value
-    }
-
     #[test]
     fn test_stack_init() {
         let string = "test".to_string();
         let stack = Stack::<String>::init(string.clone());
-        assert_eq!(string, unbox(stack.first.unwrap()).item);
+        assert_eq!(string, stack.first.as_ref().unwrap().item);
         assert_eq!(1, stack.len);
     }
 
@@ -139,4 +135,14 @@ mod tests {
         stack.pop();
         assert_eq!(stack.vec.len(), 2);
     }
+
+    #[test]
+    fn test_stack_deep_drop() {
+        let mut stack = Stack::new();
+        for k in 0..1_000_000 {
+            stack.push(k);
+        }
+        assert_eq!(stack.len(), 1_000_000);
+        drop(stack);
+    }
 }
@@ -0,0 +1,548 @@
+#[cfg(test)]
+mod unit_test;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::Ordering;
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+#[derive(Clone, Debug)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    // random priority, a node has a larger priority than its children
+    priority: u64,
+    // number of nodes in the subtree rooted at the node
+    size: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+impl<K, V> Node<K, V> {
+    fn update(&mut self) {
+        self.size = 1 + size(&self.left) + size(&self.right);
+    }
+}
+fn size<K, V>(link: &Link<K, V>) -> usize {
+    link.as_ref().map_or(0, |node| node.size)
+}
+// Concatenates two treaps, the keys of `left` being smaller than the keys of `right`
+fn merge<K, V>(left: Link<K, V>, right: Link<K, V>) -> Link<K, V> {
+    match (left, right) {
+        (None, link) | (link, None) => link,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update();
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                right.update();
+                Some(right)
+            }
+        }
+    }
+}
+// Splits a treap into the keys smaller than `key` (or equal when `inclusive`) and the others
+fn split<K: Ord, V>(link: Link<K, V>, key: &K, inclusive: bool) -> (Link<K, V>, Link<K, V>) {
+    match link {
+        None => (None, None),
+        Some(mut node) => {
+            if node.key < *key || (inclusive && node.key == *key) {
+                let (left, right) = split(node.right.take(), key, inclusive);
+                node.right = left;
+                node.update();
+                (Some(node), right)
+            } else {
+                let (left, right) = split(node.left.take(), key, inclusive);
+                node.left = right;
+                node.update();
+                (left, Some(node))
+            }
+        }
+    }
+}
+// Merges two treaps whose keys may interleave, the values of `right` winning on equal keys
+fn union<K: Ord, V>(left: Link<K, V>, right: Link<K, V>) -> Link<K, V> {
+    match (left, right) {
+        (None, link) | (link, None) => link,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                let (smaller, larger) = split(Some(right), &left.key, false);
+                let (equal, larger) = split(larger, &left.key, true);
+                if let Some(equal) = equal {
+                    left.value = equal.value;
+                }
+                left.left = union(left.left.take(), smaller);
+                left.right = union(left.right.take(), larger);
+                left.update();
+                Some(left)
+            } else {
+                let (smaller, larger) = split(Some(left), &right.key, false);
+                let (_, larger) = split(larger, &right.key, true);
+                right.left = union(smaller, right.left.take());
+                right.right = union(larger, right.right.take());
+                right.update();
+                Some(right)
+            }
+        }
+    }
+}
+
+/// Implementation of a treap: a binary search tree of (key, value) pairs that is also
+/// a heap on random priorities, hence balanced with high probability.
+/// Treaps can be split and merged in O(log(N)), which allows to cut and paste ranges of keys.
+/// # Example
+/// ```
+/// use algods::data_structure::Treap;
+/// let mut treap = Treap::new();
+/// for key in 0..10 {
+///     treap.insert(key, key * key);
+/// }
+/// assert_eq!(treap.get(&3), Some(&9));
+/// let mut middle = treap.cut(&3, &7);
+/// assert_eq!(middle.len(), 4);
+/// assert_eq!(treap.range_search(&0, &10), vec![&0, &1, &2, &7, &8, &9]);
+/// treap.append(&mut middle);
+/// assert_eq!(treap.len(), 10);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Treap<K, V> {
+    root: Link<K, V>,
+    rng: StdRng,
+}
+impl<K, V> Default for Treap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K, V> Treap<K, V> {
+    /// Creates an empty treap instance, whose random number generator
+    /// is seeded from the operating system.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let treap = Treap::<usize, isize>::new();
+    /// assert_eq!(treap.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self::with_rng(StdRng::from_entropy())
+    }
+    /// Creates an empty treap instance, whose random number generator
+    /// (used to choose the priorities of the nodes) is seeded with `seed`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let treap = Treap::<usize, isize>::with_seed(42);
+    /// assert!(treap.is_empty());
+    /// ```
+    pub fn with_seed(seed: u64) -> Self {
+        Self::with_rng(StdRng::seed_from_u64(seed))
+    }
+    fn with_rng(rng: StdRng) -> Self {
+        Self { root: None, rng }
+    }
+    // Creates a treap with the same kind of random number generator, holding a given tree
+    fn with_root(&mut self, root: Link<K, V>) -> Self {
+        Self {
+            root,
+            rng: StdRng::seed_from_u64(self.rng.gen()),
+        }
+    }
+    /// Gives the number of (key, value) pairs in the treap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let treap = Treap::<usize, usize>::new();
+    /// assert_eq!(treap.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        size(&self.root)
+    }
+    /// Tests whether or not the treap is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1, 1);
+    /// assert!(!treap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns the smallest key in the treap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1, 0);
+    /// treap.insert(-1, 2);
+    /// assert_eq!(treap.min(), Some(&-1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn min(&self) -> Option<&K> {
+        let mut node = self.root.as_ref()?;
+        while let Some(left) = &node.left {
+            node = left;
+        }
+        Some(&node.key)
+    }
+    /// Returns the largest key in the treap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1, 0);
+    /// treap.insert(-1, 2);
+    /// assert_eq!(treap.max(), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn max(&self) -> Option<&K> {
+        let mut node = self.root.as_ref()?;
+        while let Some(right) = &node.right {
+            node = right;
+        }
+        Some(&node.key)
+    }
+    /// Returns an iterator over the (key, value) pairs of the treap, in increasing order of keys.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::new();
+    /// treap.insert(1, "b");
+    /// treap.insert(0, "a");
+    /// assert_eq!(treap.iter().collect::<Vec<_>>(), vec![(&0, &"a"), (&1, &"b")]);
+    /// ```
+    pub fn iter(&self) -> TreapIter<'_, K, V> {
+        let mut iter = TreapIter { stack: Vec::new() };
+        iter.push_left(self.root.as_deref());
+        iter
+    }
+}
+impl<K: Ord, V> Treap<K, V> {
+    fn find(&self, key: &K) -> Option<&Node<K, V>> {
+        let mut current = self.root.as_deref();
+        while let Some(node) = current {
+            current = match key.cmp(&node.key) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => node.right.as_deref(),
+                Ordering::Equal => return Some(node),
+            };
+        }
+        None
+    }
+    /// Creates a new treap with an initial (key, value) pair.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let treap = Treap::init("treap", 0);
+    /// assert_eq!(treap.len(), 1);
+    /// ```
+    pub fn init(key: K, value: V) -> Self {
+        let mut treap = Self::new();
+        treap.insert(key, value);
+        treap
+    }
+    /// Tests whether or not the treap contains a given key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let treap = Treap::init("treap", "one");
+    /// assert!(treap.contains(&"treap"));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.find(key).is_some()
+    }
+    /// Returns a reference of the value associated to a key if any exists in the treap.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let treap = Treap::init("treap", "one");
+    /// assert_eq!(treap.get(&"no treap"), None);
+    /// assert_eq!(treap.get(&"treap"), Some(&"one"));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.find(key).map(|node| &node.value)
+    }
+    /// Inserts a (key, value) pair in the treap. When the input key is
+    /// already in the treap, then it replaces the old value with the new one specified.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::<isize, usize>::new();
+    /// treap.insert(-1, 2);
+    /// treap.insert(-2, 3);
+    /// treap.insert(-1, 4);
+    /// assert_eq!(treap.len(), 2);
+    /// assert_eq!(treap.get(&-1), Some(&4));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn insert(&mut self, key: K, value: V) {
+        let (smaller, larger) = split(self.root.take(), &key, false);
+        let (equal, larger) = split(larger, &key, true);
+        let node = match equal {
+            Some(mut node) => {
+                node.value = value;
+                node
+            }
+            None => Box::new(Node {
+                key,
+                value,
+                priority: self.rng.gen(),
+                size: 1,
+                left: None,
+                right: None,
+            }),
+        };
+        self.root = merge(merge(smaller, Some(node)), larger);
+    }
+    /// Removes a key from the treap, returning the value associated if any.
+    /// Otherwise it returns `None`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::init(1, 2);
+    /// assert_eq!(treap.delete(&1), Some(2));
+    /// assert_eq!(treap.delete(&10), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn delete(&mut self, key: &K) -> Option<V> {
+        let (smaller, larger) = split(self.root.take(), key, false);
+        let (equal, larger) = split(larger, key, true);
+        self.root = merge(smaller, larger);
+        equal.map(|node| node.value)
+    }
+    /// Returns the largest key in the treap smaller or equal to the input key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::<isize, usize>::init(1, 0);
+    /// treap.insert(-1, 2);
+    /// assert_eq!(treap.floor(&1), Some(&1));
+    /// assert_eq!(treap.floor(&0), Some(&-1));
+    /// assert_eq!(treap.floor(&-3), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn floor(&self, key: &K) -> Option<&K> {
+        let (mut current, mut floor) = (self.root.as_deref(), None);
+        while let Some(node) = current {
+            current = match key.cmp(&node.key) {
+                Ordering::Less => node.left.as_deref(),
+                Ordering::Greater => {
+                    floor = Some(&node.key);
+                    node.right.as_deref()
+                }
+                Ordering::Equal => return Some(&node.key),
+            };
+        }
+        floor
+    }
+    /// Returns the largest key in the treap strictly inferior to the input key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::<isize, usize>::init(1, 0);
+    /// treap.insert(-1, 2);
+    /// assert_eq!(treap.strict_floor(&1), Some(&-1));
+    /// assert_eq!(treap.strict_floor(&-1), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn strict_floor(&self, key: &K) -> Option<&K> {
+        let (mut current, mut floor) = (self.root.as_deref(), None);
+        while let Some(node) = current {
+            if node.key < *key {
+                floor = Some(&node.key);
+                current = node.right.as_deref();
+            } else {
+                current = node.left.as_deref();
+            }
+        }
+        floor
+    }
+    /// Returns the smallest key in the treap larger or equal to the input key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::<isize, usize>::init(1, 0);
+    /// treap.insert(-1, 2);
+    /// assert_eq!(treap.ceil(&1), Some(&1));
+    /// assert_eq!(treap.ceil(&0), Some(&1));
+    /// assert_eq!(treap.ceil(&2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn ceil(&self, key: &K) -> Option<&K> {
+        let (mut current, mut ceil) = (self.root.as_deref(), None);
+        while let Some(node) = current {
+            if node.key >= *key {
+                ceil = Some(&node.key);
+                current = node.left.as_deref();
+            } else {
+                current = node.right.as_deref();
+            }
+        }
+        ceil
+    }
+    // Gives the number of keys strictly smaller than a given key
+    fn rank(&self, key: &K) -> usize {
+        let (mut current, mut rank) = (self.root.as_deref(), 0);
+        while let Some(node) = current {
+            if node.key < *key {
+                rank += 1 + size(&node.left);
+                current = node.right.as_deref();
+            } else {
+                current = node.left.as_deref();
+            }
+        }
+        rank
+    }
+    /// Returns the list of keys in the treap that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::<isize, usize>::init(1, 0);
+    /// treap.insert(-1, 2);
+    /// treap.insert(-2, 2);
+    /// treap.insert(-3, 3);
+    /// assert_eq!(treap.range_search(&-2, &1), vec![&-2, &-1]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N) + R) on average, where R is the number of keys returned.
+    pub fn range_search(&self, low: &K, high: &K) -> Vec<&K> {
+        let mut keys = Vec::new();
+        let mut stack = Vec::new();
+        let mut current = self.root.as_deref();
+        loop {
+            // goes down to the smallest key larger or equal to low
+            while let Some(node) = current {
+                if node.key >= *low {
+                    stack.push(node);
+                    current = node.left.as_deref();
+                } else {
+                    current = node.right.as_deref();
+                }
+            }
+            match stack.pop() {
+                Some(node) if node.key < *high => {
+                    keys.push(&node.key);
+                    current = node.right.as_deref();
+                }
+                _ => return keys,
+            }
+        }
+    }
+    /// Returns the number of keys in the treap that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::<isize, usize>::init(1, 0);
+    /// treap.insert(-1, 2);
+    /// treap.insert(-2, 2);
+    /// treap.insert(-3, 3);
+    /// assert_eq!(treap.range_count(&-3, &-1), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn range_count(&self, low: &K, high: &K) -> usize {
+        self.rank(high).saturating_sub(self.rank(low))
+    }
+    /// Moves the keys larger or equal to a given key into a new treap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = (0..10).map(|k| (k, k)).collect::<Treap<_, _>>();
+    /// let larger = treap.split_off(&6);
+    /// assert_eq!(treap.max(), Some(&5));
+    /// assert_eq!(larger.min(), Some(&6));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn split_off(&mut self, key: &K) -> Self {
+        let (smaller, larger) = split(self.root.take(), key, false);
+        self.root = smaller;
+        self.with_root(larger)
+    }
+    /// Moves the keys between two keys (low included, high excluded) into a new treap.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = (0..10).map(|k| (k, k)).collect::<Treap<_, _>>();
+    /// let middle = treap.cut(&2, &8);
+    /// assert_eq!(middle.len(), 6);
+    /// assert_eq!(treap.range_search(&0, &10), vec![&0, &1, &8, &9]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average
+    pub fn cut(&mut self, low: &K, high: &K) -> Self {
+        let (smaller, larger) = split(self.root.take(), low, false);
+        let (middle, larger) = split(larger, high, false);
+        self.root = merge(smaller, larger);
+        self.with_root(middle)
+    }
+    /// Moves all the (key, value) pairs of another treap into the treap, leaving the other one empty.
+    /// On equal keys, the values of the other treap are kept.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::Treap;
+    /// let mut treap = Treap::init(1, "a");
+    /// let mut other = Treap::init(1, "b");
+    /// other.insert(2, "c");
+    /// treap.append(&mut other);
+    /// assert!(other.is_empty());
+    /// assert_eq!(treap.iter().collect::<Vec<_>>(), vec![(&1, &"b"), (&2, &"c")]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) on average when the keys of the other treap
+    /// all fall between two consecutive keys of the treap (as in pasting a range back),
+    /// and in O(M log(N/M + 1)) in general, where M is the size of the smaller treap.
+    pub fn append(&mut self, other: &mut Self) {
+        self.root = union(self.root.take(), other.root.take());
+    }
+}
+impl<K: Ord, V> FromIterator<(K, V)> for Treap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut treap = Self::new();
+        for (key, value) in iter {
+            treap.insert(key, value);
+        }
+        treap
+    }
+}
+
+/// Iterator over the (key, value) pairs of a `Treap` in increasing order of keys.
+#[derive(Debug)]
+pub struct TreapIter<'a, K, V> {
+    // nodes whose left subtree has been visited but not the node itself
+    stack: Vec<&'a Node<K, V>>,
+}
+impl<'a, K, V> TreapIter<'a, K, V> {
+    fn push_left(&mut self, mut current: Option<&'a Node<K, V>>) {
+        while let Some(node) = current {
+            self.stack.push(node);
+            current = node.left.as_deref();
+        }
+    }
+}
+impl<'a, K, V> Iterator for TreapIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        Some((&node.key, &node.value))
+    }
+}
+impl<'a, K, V> IntoIterator for &'a Treap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = TreapIter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeMap;
+
+    // checks the search tree order, the heap order and the sizes
+    fn check_invariants<K: Ord, V>(link: &Link<K, V>) -> usize {
+        match link {
+            None => 0,
+            Some(node) => {
+                for child in [&node.left, &node.right].into_iter().flatten() {
+                    assert!(child.priority <= node.priority);
+                }
+                if let Some(left) = &node.left {
+                    assert!(left.key < node.key);
+                }
+                if let Some(right) = &node.right {
+                    assert!(right.key > node.key);
+                }
+                let size = 1 + check_invariants(&node.left) + check_invariants(&node.right);
+                assert_eq!(size, node.size);
+                size
+            }
+        }
+    }
+
+    #[test]
+    fn test_treap_against_btree_map() {
+        let mut treap = Treap::with_seed(1);
+        let mut map = BTreeMap::new();
+        let mut rng = StdRng::seed_from_u64(2);
+        for step in 0..5000usize {
+            let key = rng.gen_range(0..1000usize);
+            if rng.gen_bool(0.3) {
+                assert_eq!(treap.delete(&key), map.remove(&key));
+            } else {
+                treap.insert(key, step);
+                map.insert(key, step);
+            }
+            assert_eq!(treap.len(), map.len());
+        }
+        check_invariants(&treap.root);
+        assert!(treap.iter().eq(map.iter()));
+        assert_eq!(treap.min(), map.keys().next());
+        assert_eq!(treap.max(), map.keys().next_back());
+        for key in 0..1001usize {
+            assert_eq!(treap.get(&key), map.get(&key));
+            assert_eq!(
+                treap.floor(&key),
+                map.range(..=key).next_back().map(|e| e.0)
+            );
+            assert_eq!(
+                treap.strict_floor(&key),
+                map.range(..key).next_back().map(|e| e.0)
+            );
+            assert_eq!(treap.ceil(&key), map.range(key..).next().map(|e| e.0));
+        }
+        for (low, high) in [(100, 400), (0, 1000), (500, 500), (700, 300)] {
+            let expected = map
+                .range(low..high.max(low))
+                .map(|e| e.0)
+                .collect::<Vec<_>>();
+            assert_eq!(treap.range_search(&low, &high), expected);
+            assert_eq!(treap.range_count(&low, &high), expected.len());
+        }
+    }
+
+    #[test]
+    fn test_treap_cut_and_append() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let mut treap = Treap::with_seed(4);
+        let mut other = Treap::with_seed(5);
+        let mut map = BTreeMap::new();
+        for _ in 0..2000 {
+            let key = rng.gen_range(0..5000usize);
+            treap.insert(key, 0);
+            map.insert(key, 0);
+            let key = rng.gen_range(0..5000usize);
+            other.insert(key, 1);
+        }
+        // pastes back a range that was cut
+        let mut middle = treap.cut(&1000, &2000);
+        check_invariants(&treap.root);
+        check_invariants(&middle.root);
+        assert_eq!(treap.range_count(&1000, &2000), 0);
+        assert_eq!(middle.len(), map.range(1000..2000).count());
+        treap.append(&mut middle);
+        assert!(middle.is_empty());
+        assert!(treap.iter().eq(map.iter()));
+        // merges interleaved keys, the appended values win
+        for (key, value) in other.iter() {
+            map.insert(*key, *value);
+        }
+        treap.append(&mut other);
+        check_invariants(&treap.root);
+        assert_eq!(treap.len(), map.len());
+        assert!(treap.iter().eq(map.iter()));
+        let larger = treap.split_off(&2500);
+        assert!(treap.iter().eq(map.range(..2500)));
+        assert!(larger.iter().eq(map.range(2500..)));
+    }
+
+    #[test]
+    fn test_treap_sorted_inserts_are_balanced() {
+        let treap = (0..100_000).map(|k| (k, k)).collect::<Treap<_, _>>();
+        fn height<K, V>(link: &Link<K, V>) -> usize {
+            link.as_ref()
+                .map_or(0, |node| 1 + height(&node.left).max(height(&node.right)))
+        }
+        assert!(height(&treap.root) < 60);
+    }
+}
@@ -313,24 +313,6 @@ impl<T: Eq + Ord, U: Eq> BSearchTree<T, U> {
     }
 }
 impl<T: Ord, U> BSearchTree<T, U> {
-    fn put(node: &mut Option<Box<Node<T, U>>>, key: T, value: U) -> Option<&U> {
-        match node {
-            None => *node = Some(Box::new(Node::init(key, value))),
-            Some(ref mut nod) => match key.cmp(&nod.key) {
-                Ordering::Less => {
-                    return Self::put(&mut nod.left, key, value);
-                }
-                Ordering::Greater => {
-                    return Self::put(&mut nod.right, key, value);
-                }
-                Ordering::Equal => {
-                    nod.value = value;
-                    return Some(&nod.value);
-                }
-            },
-        }
-        None
-    }
     /// Inserts a (key, value) pair in the tree. When the input key is
     /// already on the map, then it replaces the old value with the new one specified.   
     /// # Example
@@ -344,8 +326,30 @@ impl<T: Ord, U> BSearchTree<T, U> {
     /// assert_eq!(bt.get(&-2), Some(&3));
     /// ```
     pub fn insert(&mut self, key: T, value: U) {
-        if Self::put(&mut self.root, key, value).is_none() {
-            self.len += 1;
+        // goes down iteratively, a degenerate tree being as deep as a list
+        let mut node = &mut self.root;
+        while let Some(current) = node {
+            match key.cmp(&current.key) {
+                Ordering::Less => node = &mut current.left,
+                Ordering::Greater => node = &mut current.right,
+                Ordering::Equal => {
+                    current.value = value;
+                    return;
+                }
+            }
+        }
+        *node = Some(Box::new(Node::init(key, value)));
+        self.len += 1;
+    }
+}
+impl<T, U> Drop for BSearchTree<T, U> {
+    fn drop(&mut self) {
+        // detaches the nodes with an explicit stack, since the recursive drop
+        // of a degenerate tree would overflow the call stack
+        let mut nodes = Vec::from_iter(self.root.take());
+        while let Some(mut node) = nodes.pop() {
+            nodes.extend(node.left.take());
+            nodes.extend(node.right.take());
         }
     }
 }
@@ -434,6 +438,241 @@ impl<T: Eq + Ord, U: Ord> BSearchTree<T, U> {
     }
 }
 
+#[derive(Clone, Debug)]
+struct VecNode<T, U> {
+    key: T,
+    value: U,
+    // positions of the children in the arena
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+/// Implementation of a binary search tree whose nodes are stored in a `Vec`
+/// and linked by their positions, instead of being boxed one by one:
+/// it saves an allocation per node and keeps the nodes contiguous in memory.
+/// # Example
+/// ```
+/// use algods::data_structure::VecBSearchTree;
+/// let mut bt = VecBSearchTree::new();
+/// bt.insert(0,"1");
+/// bt.insert(1,"2");
+/// bt.insert(2,"3");
+/// assert_eq!(bt.len(), 3);
+/// assert!(bt.contains(&0));
+/// assert_eq!(bt.get(&2), Some(&"3"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VecBSearchTree<T, U> {
+    // arena of nodes, the root being at position 0
+    nodes: Vec<VecNode<T, U>>,
+}
+impl<T, U> Default for VecBSearchTree<T, U> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T, U> VecBSearchTree<T, U> {
+    /// Creates an empty tree instance.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let bt = VecBSearchTree::<usize, isize>::new();
+    /// assert_eq!(bt.len(), 0);
+    /// ```
+    pub fn new() -> Self {
+        Self { nodes: Vec::new() }
+    }
+    /// Creates an empty tree instance with room for `capacity` nodes.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let bt = VecBSearchTree::<usize, isize>::with_capacity(10);
+    /// assert!(bt.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+        }
+    }
+    /// Creates a new tree with an initial (key, value) pair.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let bt = VecBSearchTree::init("btree", 0);
+    /// assert_eq!(bt.len(), 1);
+    /// ```
+    pub fn init(key: T, value: U) -> Self {
+        let mut tree = Self::new();
+        tree.nodes.push(VecNode {
+            key,
+            value,
+            left: None,
+            right: None,
+        });
+        tree
+    }
+    /// Gives the number of (key, value) pairs in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let bt = VecBSearchTree::<usize, usize>::new();
+    /// assert_eq!(bt.len(), 0);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+    /// Tests whether or not the tree is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let mut bt = VecBSearchTree::new();
+    /// bt.insert(1, 1);
+    /// assert!(!bt.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+    fn root(&self) -> Option<usize> {
+        if self.nodes.is_empty() {
+            None
+        } else {
+            Some(0)
+        }
+    }
+}
+impl<T: Ord, U> VecBSearchTree<T, U> {
+    fn find(&self, key: &T) -> Result<usize, Option<usize>> {
+        // position of the node of the key, or of the last node visited
+        // while looking for the key (None if the tree is empty)
+        let mut parent = None;
+        let mut current = self.root();
+        while let Some(index) = current {
+            parent = Some(index);
+            let node = &self.nodes[index];
+            current = match key.cmp(&node.key) {
+                Ordering::Less => node.left,
+                Ordering::Greater => node.right,
+                Ordering::Equal => return Ok(index),
+            };
+        }
+        Err(parent)
+    }
+    /// Tests whether or not the tree contains a given key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let bt = VecBSearchTree::init("btree", "one");
+    /// assert!(bt.contains(&"btree"));
+    /// ```
+    pub fn contains(&self, key: &T) -> bool {
+        self.find(key).is_ok()
+    }
+    /// Returns a reference of the value associated to a key if any exists in the tree.
+    /// Returns `None` otherwise.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let bt = VecBSearchTree::init("btree", "one");
+    /// assert_eq!(bt.get(&"no btree"), None);
+    /// assert_eq!(bt.get(&"btree"), Some(&"one"));
+    /// ```
+    pub fn get(&self, key: &T) -> Option<&U> {
+        // run time complexity on average O(log(N)), O(N) guaranteed (unbalanced tree)
+        self.find(key).ok().map(|index| &self.nodes[index].value)
+    }
+    /// Inserts a (key, value) pair in the tree. When the input key is
+    /// already on the map, then it replaces the old value with the new one specified.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let mut bt = VecBSearchTree::<isize, usize>::new();
+    /// bt.insert(-1, 2);
+    /// bt.insert(-2, 3);
+    /// bt.insert(-1, 4);
+    /// assert_eq!(bt.len(), 2);
+    /// assert_eq!(bt.get(&-1), Some(&4));
+    /// ```
+    pub fn insert(&mut self, key: T, value: U) {
+        match self.find(&key) {
+            Ok(index) => self.nodes[index].value = value,
+            Err(parent) => {
+                let index = self.nodes.len();
+                if let Some(parent) = parent {
+                    let parent = &mut self.nodes[parent];
+                    if key < parent.key {
+                        parent.left = Some(index);
+                    } else {
+                        parent.right = Some(index);
+                    }
+                }
+                self.nodes.push(VecNode {
+                    key,
+                    value,
+                    left: None,
+                    right: None,
+                });
+            }
+        }
+    }
+    /// Returns the smallest key in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let mut bt = VecBSearchTree::<isize, usize>::init(1, 0);
+    /// bt.insert(-1, 2);
+    /// assert_eq!(bt.min(), Some(&-1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut index = self.root()?;
+        while let Some(left) = self.nodes[index].left {
+            index = left;
+        }
+        Some(&self.nodes[index].key)
+    }
+    /// Returns the largest key in the tree.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let mut bt = VecBSearchTree::<isize, usize>::init(0, 0);
+    /// bt.insert(-1, 2);
+    /// assert_eq!(bt.max(), Some(&0));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut index = self.root()?;
+        while let Some(right) = self.nodes[index].right {
+            index = right;
+        }
+        Some(&self.nodes[index].key)
+    }
+    /// Returns the largest key in the tree smaller or equal to the input key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VecBSearchTree;
+    /// let mut bt = VecBSearchTree::<isize, usize>::init(1, 0);
+    /// bt.insert(-1, 2);
+    /// bt.insert(-2, 3);
+    /// assert_eq!(bt.floor(&1), Some(&1));
+    /// assert_eq!(bt.floor(&0), Some(&-1));
+    /// ```
+    pub fn floor(&self, key: &T) -> Option<&T> {
+        // run time complexity O(log(N)) on average, O(N) (guaranteed)
+        let mut floor = None;
+        let mut current = self.root();
+        while let Some(index) = current {
+            let node = &self.nodes[index];
+            current = match key.cmp(&node.key) {
+                Ordering::Less => node.left,
+                Ordering::Greater => {
+                    floor = Some(&node.key);
+                    node.right
+                }
+                Ordering::Equal => return Some(&node.key),
+            };
+        }
+        floor
+    }
+}
+
 /// Implementation of a tree map based on an ordered `Vec`.
 /// # Example
 /// ```
@@ -450,6 +689,14 @@ impl<T: Eq + Ord, U: Ord> BSearchTree<T, U> {
 pub struct OrdVecTable<T, U> {
     // collection of key-value pair (no duplicate keys)
     vec: Vec<Pair<T, Option<U>>>,
+    // number of deleted keys, whose value is None
+    deleted: usize,
+    // Remarks:
+    // - deletions are lazy: the value of the key is replaced by None, and the deleted
+    //   keys are removed all at once when they outnumber the other ones, so that
+    //   a deletion runs in amortized O(log(N)) time and the Vec is at least half used
+    // - the order statistics (rank and select) are computed from the positions
+    //   in the Vec, hence in O(log(N)) once the table is compacted
 }
 impl<T, U> OrdVecTable<T, U> {
     /// Creates an empty tree instance.
@@ -460,7 +707,10 @@ impl<T, U> OrdVecTable<T, U> {
     /// assert_eq!(tree.len(), 0);
     /// ```
     pub fn new() -> Self {
-        Self { vec: Vec::new() }
+        Self {
+            vec: Vec::new(),
+            deleted: 0,
+        }
     }
     /// Creates a new tree with an initial (key, value) pair.
     /// # Example
@@ -482,7 +732,7 @@ impl<T, U> OrdVecTable<T, U> {
     /// assert_eq!(table.len(), 0);
     /// ```
     pub fn len(&self) -> usize {
-        self.vec.len()
+        self.vec.len() - self.deleted
     }
     /// Tests whether or not the tree is empty.
     /// # Example
@@ -495,6 +745,9 @@ impl<T, U> OrdVecTable<T, U> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+    fn is_live(&self, index: usize) -> bool {
+        self.vec[index].second().is_some()
+    }
     /// Returns the smallest key in the tree.
     /// # Example
     /// ```
@@ -504,12 +757,8 @@ impl<T, U> OrdVecTable<T, U> {
     /// assert_eq!(table.min(), Some(&-1));
     /// ```
     pub fn min(&self) -> Option<&T> {
-        // smallest key O(1)
-        if self.is_empty() {
-            None
-        } else {
-            Some(self.vec[0].first())
-        }
+        // smallest key O(1) once compacted
+        self.iter().next().map(|(key, _)| key)
     }
     /// Returns the largest key in the tree.
     /// # Example
@@ -520,13 +769,204 @@ impl<T, U> OrdVecTable<T, U> {
     /// assert_eq!(table.max(), Some(&1));
     /// ```
     pub fn max(&self) -> Option<&T> {
-        // largest key O(1)
-        if self.vec.is_empty() {
-            None
+        // largest key O(1) once compacted
+        self.iter().next_back().map(|(key, _)| key)
+    }
+    /// Returns an iterator over the (key, value) pairs of the tree in increasing order of keys.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::OrdVecTable;
+    /// let mut table = OrdVecTable::new();
+    /// table.insert(2, "2");
+    /// table.insert(1, "1");
+    /// table.insert(3, "3");
+    /// table.delete(&2);
+    /// assert_eq!(table.iter().collect::<Vec<_>>(), vec![(&1, &"1"), (&3, &"3")]);
+    /// ```
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&T, &U)> {
+        self.vec
+            .iter()
+            .filter_map(|pair| pair.second().as_ref().map(|value| (pair.first(), value)))
+    }
+    /// Removes the deleted keys from the tree, which is done automatically
+    /// once they outnumber the other keys.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::OrdVecTable;
+    /// let mut table = OrdVecTable::new();
+    /// for k in 0..4 {
+    ///     table.insert(k, k);
+    /// }
+    /// table.delete(&0);
+    /// table.compact();
+    /// assert_eq!(table.len(), 3);
+    /// assert_eq!(table.select(0), Some(&1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn compact(&mut self) {
+        if self.deleted > 0 {
+            self.vec.retain(|pair| pair.second().is_some());
+            self.deleted = 0;
+        }
+    }
+    /// Returns the key of rank `k` (the k+1-th smallest key) in the tree, if any.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::OrdVecTable;
+    /// let mut table = OrdVecTable::new();
+    /// for k in [10, 30, 20] {
+    ///     table.insert(k, k);
+    /// }
+    /// assert_eq!(table.select(1), Some(&20));
+    /// assert_eq!(table.select(3), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) once compacted, O(N) otherwise
+    pub fn select(&self, k: usize) -> Option<&T> {
+        if self.deleted == 0 {
+            self.vec.get(k).map(|pair| pair.first())
+        } else {
+            self.iter().nth(k).map(|(key, _)| key)
+        }
+    }
+}
+impl<T: Ord, U> OrdVecTable<T, U> {
+    fn search(&self, key: &T) -> Result<usize, usize> {
+        self.vec.binary_search_by(|pair| pair.first().cmp(key))
+    }
+    /// Creates a tree from (key, value) pairs sorted in strictly increasing order of keys.
+    /// # Panics
+    /// If the keys are not sorted in strictly increasing order, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::OrdVecTable;
+    /// let table = OrdVecTable::from_sorted_iter((0..5).map(|k| (k, 2 * k)));
+    /// assert_eq!(table.len(), 5);
+    /// assert_eq!(table.get(&3), Some(&6));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N)
+    pub fn from_sorted_iter<I: IntoIterator<Item = (T, U)>>(iter: I) -> Self {
+        let vec = iter
+            .into_iter()
+            .map(|(key, value)| Pair::init(key, Some(value)))
+            .collect::<Vec<_>>();
+        if vec.windows(2).any(|w| w[0].first() >= w[1].first()) {
+            panic!("the keys should be sorted in strictly increasing order");
+        }
+        Self { vec, deleted: 0 }
+    }
+    /// Returns the number of keys in the tree strictly smaller than a key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::OrdVecTable;
+    /// let mut table = OrdVecTable::new();
+    /// for k in [10, 30, 20] {
+    ///     table.insert(k, k);
+    /// }
+    /// assert_eq!(table.rank(&20), 1);
+    /// assert_eq!(table.rank(&25), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) once compacted, O(N) otherwise
+    pub fn rank(&self, key: &T) -> usize {
+        let index = match self.search(key) {
+            Ok(index) | Err(index) => index,
+        };
+        if self.deleted == 0 {
+            index
+        } else {
+            (0..index).filter(|i| self.is_live(*i)).count()
+        }
+    }
+    /// Returns the list of keys in the tree that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::OrdVecTable;
+    /// let mut table = OrdVecTable::<isize, usize>::new();
+    /// table.insert(-1, 2);
+    /// table.insert(-2, 2);
+    /// table.insert(-3, 3);
+    /// assert_eq!(table.range_search(&-2, &1), vec![&-2, &-1]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N) + R) where R is the number of keys
+    /// (deleted ones included) in the range
+    pub fn range_search(&self, low: &T, high: &T) -> Vec<&T> {
+        if low >= high {
+            return Vec::new();
+        }
+        let (start, end) = match (self.search(low), self.search(high)) {
+            (Ok(start) | Err(start), Ok(end) | Err(end)) => (start, end),
+        };
+        self.vec[start..end]
+            .iter()
+            .filter(|pair| pair.second().is_some())
+            .map(|pair| pair.first())
+            .collect()
+    }
+    /// Returns the number of keys in the tree that are between two keys (low included, high excluded).
+    /// # Example
+    /// ```
+    /// use algods::data_structure::OrdVecTable;
+    /// let mut table = OrdVecTable::<isize, usize>::new();
+    /// table.insert(-1, 2);
+    /// table.insert(-2, 2);
+    /// table.insert(-3, 3);
+    /// assert_eq!(table.range_count(&-3, &-1), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) once compacted, O(N) otherwise
+    pub fn range_count(&self, low: &T, high: &T) -> usize {
+        if low >= high {
+            0
         } else {
-            Some(self.vec[self.vec.len() - 1].first())
+            self.rank(high) - self.rank(low)
         }
     }
+    /// Merges another tree into the tree, the values of the other tree
+    /// replacing those of the keys present in both trees.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::OrdVecTable;
+    /// let mut table = OrdVecTable::from_sorted_iter([(1, "1"), (3, "3")]);
+    /// let other = OrdVecTable::from_sorted_iter([(2, "2"), (3, "three")]);
+    /// table.merge(other);
+    /// assert_eq!(table.iter().collect::<Vec<_>>(), vec![(&1, &"1"), (&2, &"2"), (&3, &"three")]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N + M) where M is the size of the other tree
+    pub fn merge(&mut self, other: Self) {
+        let mut vec = Vec::with_capacity(self.len() + other.len());
+        let mut left = std::mem::take(&mut self.vec)
+            .into_iter()
+            .filter(|pair| pair.second().is_some())
+            .peekable();
+        let mut right = other
+            .vec
+            .into_iter()
+            .filter(|pair| pair.second().is_some())
+            .peekable();
+        loop {
+            let order = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) => a.first().cmp(b.first()),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+            match order {
+                Ordering::Less => vec.extend(left.next()),
+                Ordering::Greater => vec.extend(right.next()),
+                Ordering::Equal => {
+                    left.next();
+                    vec.extend(right.next());
+                }
+            }
+        }
+        self.vec = vec;
+        self.deleted = 0;
+    }
 }
 impl<T: Ord + Clone, U: Eq> OrdVecTable<T, U> {
     /// Tests whether or not the tree contains a given key.
@@ -551,10 +991,9 @@ impl<T: Ord + Clone, U: Eq> OrdVecTable<T, U> {
     /// ```
     pub fn get(&self, key: &T) -> Option<&U> {
         // run time complexity O(log(N))
-        if let Ok(index) = self.vec.binary_search(&Pair::init(key.clone(), None)) {
-            return self.vec[index].second().as_ref();
-        } else {
-            None
+        match self.search(key) {
+            Ok(index) => self.vec[index].second().as_ref(),
+            Err(_) => None,
         }
     }
     /// Returns the largest key in the tree smaller or equal to the input key.
@@ -568,23 +1007,15 @@ impl<T: Ord + Clone, U: Eq> OrdVecTable<T, U> {
     /// assert_eq!(table.floor(&0), Some(&-1));
     /// ```
     pub fn floor(&self, key: &T) -> Option<&T> {
-        // largest key smaller or equal to key O(log(N))
-        if self.is_empty() {
-            None
-        } else {
-            let index = self.vec.binary_search(&Pair::init(key.clone(), None));
-            match index {
-                Ok(ind) => Some(self.vec[ind].first()),
-                Err(ind) => {
-                    if ind > 0 {
-                        Some(self.vec[ind - 1].first())
-                    } else {
-                        // all keys in the table are > keys
-                        None
-                    }
-                }
-            }
-        }
+        // largest key smaller or equal to key O(log(N)) once compacted
+        let end = match self.search(key) {
+            Ok(index) => index + 1,
+            Err(index) => index,
+        };
+        (0..end)
+            .rev()
+            .find(|index| self.is_live(*index))
+            .map(|index| self.vec[index].first())
     }
     /// Returns the smallest key in the tree larger or equal to the input key.
     /// # Example
@@ -596,54 +1027,19 @@ impl<T: Ord + Clone, U: Eq> OrdVecTable<T, U> {
     /// assert_eq!(table.ceil(&1), Some(&1));
     /// assert_eq!(table.ceil(&2), None);
     /// assert_eq!(table.ceil(&-3), Some(&-2));
+    /// assert_eq!(table.ceil(&0), Some(&1));
     /// ```
     pub fn ceil(&self, key: &T) -> Option<&T> {
-        // smallest key larger or equal to key, O(log(N))
-        if self.is_empty() {
-            None
-        } else {
-            let index = self.vec.binary_search(&Pair::init(key.clone(), None));
-            match index {
-                Ok(ind) => Some(self.vec[ind].first()),
-                Err(ind) => {
-                    if ind < self.vec.len() - 1 && ind > 0 {
-                        Some(self.vec[ind + 1].first())
-                    } else if ind == 0 {
-                        // key is < to all keys
-                        Some(self.vec[0].first())
-                    } else {
-                        // all keys in the table are < key
-                        None
-                    }
-                }
-            }
-        }
+        // smallest key larger or equal to key, O(log(N)) once compacted
+        let start = match self.search(key) {
+            Ok(index) | Err(index) => index,
+        };
+        (start..self.vec.len())
+            .find(|index| self.is_live(*index))
+            .map(|index| self.vec[index].first())
     }
 }
 impl<T: Ord + Clone, U: Eq + Clone> OrdVecTable<T, U> {
-    fn put(&mut self, key: T, value: Option<U>) -> Option<U> {
-        // run time complexity O(N) due to insertion
-        let candidate = Pair::init(key.clone(), None);
-        let index = self.vec.binary_search(&candidate);
-        match index {
-            Ok(ind) => {
-                // key is found
-                let temp_val = self.vec[ind].second().as_ref().cloned();
-                let mut_val = self.vec[ind].second_mut();
-                *mut_val = value;
-                temp_val
-            }
-            Err(ind) => {
-                // index where to insert key to keep self.vec sorted
-                if ind < self.vec.len() {
-                    self.vec.insert(ind, Pair::init(key, value));
-                } else {
-                    self.vec.push(Pair::init(key, value))
-                }
-                None
-            }
-        }
-    }
     /// Inserts a (key, value) pair in the tree.
     /// # Example
     /// ```
@@ -656,11 +1052,21 @@ impl<T: Ord + Clone, U: Eq + Clone> OrdVecTable<T, U> {
     /// assert_eq!(table.get(&-2), Some(&3));
     /// ```
     pub fn insert(&mut self, key: T, value: U) {
-        // run time complexity O(N)
-        self.put(key, Some(value));
+        // run time complexity O(N) due to insertion
+        match self.search(&key) {
+            Ok(index) => {
+                if !self.is_live(index) {
+                    // the key was deleted
+                    self.deleted -= 1;
+                }
+                *self.vec[index].second_mut() = Some(value);
+            }
+            Err(index) => self.vec.insert(index, Pair::init(key, Some(value))),
+        }
     }
-    /// Deletes a key in the tree using a lazy implementation:
-    /// meaning that it replaces the value of the key by `None` if any, but the key still persists on the map.
+    /// Deletes a key in the tree and returns its value, if any. The deletion is lazy: the value
+    /// of the key is removed but the key is only removed from the underlying `Vec` when
+    /// the deleted keys outnumber the other ones (or when the tree is compacted).
     /// # Example
     /// ```
     /// use algods::data_structure::OrdVecTable;
@@ -671,10 +1077,17 @@ impl<T: Ord + Clone, U: Eq + Clone> OrdVecTable<T, U> {
     /// assert_eq!(table.delete(&-1), Some(4));
     /// assert_eq!(table.delete(&-1), None);
     /// assert_eq!(table.delete(&0), None);
+    /// assert_eq!(table.len(), 1);
     /// ```
     pub fn delete(&mut self, key: &T) -> Option<U> {
-        // run time complexity O(N)
-        self.put(key.clone(), None) // lazy implementation
+        // run time complexity O(log(N)) amortized
+        let index = self.search(key).ok()?;
+        let value = self.vec[index].second_mut().take()?;
+        self.deleted += 1;
+        if 2 * self.deleted > self.vec.len() {
+            self.compact();
+        }
+        Some(value)
     }
 }
 #[derive(Default, Clone, Debug)]
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use super::super::{BSearchTree, BTreeTable, OrdVecTable};
+    use super::super::{BSearchTree, BTreeTable, Node, OrdVecTable, VecBSearchTree};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeMap;
 
     #[test]
     fn test_ordered_vec_symbol_table() {
@@ -15,7 +18,8 @@ mod tests {
         st.delete(&0);
         assert!(!st.contains(&0));
         st.insert(1, "test1");
-        assert_eq!(st.min(), Some(&0));
+        // deleted keys are not reported anymore
+        assert_eq!(st.min(), Some(&1));
         assert_eq!(st.max(), Some(&2));
         assert_eq!(st.floor(&1), Some(&1));
         assert_eq!(st.floor(&3), Some(&2));
@@ -65,4 +69,123 @@ mod tests {
         st.insert(10, "test10");
         assert_eq!(st.range_search(&0, &5), vec![&2, &3]);
     }
+
+    #[test]
+    fn test_binary_search_tree_deep_drop() {
+        // a degenerate tree is as deep as a list
+        let mut root = None;
+        for key in 0..1_000_000 {
+            let mut node = Box::new(Node::init(key, key));
+            node.right = root;
+            root = Some(node);
+        }
+        let st = BSearchTree {
+            root,
+            len: 1_000_000,
+        };
+        assert_eq!(st.min(), Some(&999_999));
+        drop(st);
+    }
+
+    #[test]
+    fn test_binary_search_tree_sorted_inserts() {
+        let mut st = BSearchTree::new();
+        for key in 0..10_000 {
+            st.insert(key, key);
+        }
+        assert_eq!(st.len(), 10_000);
+        assert_eq!(st.max(), Some(&9_999));
+    }
+
+    #[test]
+    fn test_vec_binary_search_tree() {
+        let mut st = VecBSearchTree::<usize, &str>::init(10, "test10");
+        st.insert(1, "test1");
+        st.insert(5, "test5");
+        assert_eq!(st.get(&10), Some(&"test10"));
+        assert_eq!(st.get(&0), None);
+        assert!(st.contains(&5));
+        assert_eq!(st.floor(&5), Some(&5));
+        assert_eq!(st.floor(&4), Some(&1));
+        assert_eq!(st.floor(&0), None);
+        assert_eq!(st.floor(&15), Some(&10));
+        assert_eq!(st.min(), Some(&1));
+        assert_eq!(st.max(), Some(&10));
+        assert!(VecBSearchTree::<usize, usize>::new().min().is_none());
+
+        // same answers as the boxed tree on random keys
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut boxed = BSearchTree::new();
+        let mut arena = VecBSearchTree::new();
+        let mut map = BTreeMap::new();
+        for value in 0..2000 {
+            let key = rng.gen_range(0..1000);
+            boxed.insert(key, value);
+            arena.insert(key, value);
+            map.insert(key, value);
+        }
+        assert_eq!(arena.len(), map.len());
+        assert_eq!(boxed.len(), map.len());
+        for key in 0..1100 {
+            assert_eq!(arena.get(&key), map.get(&key));
+            assert_eq!(arena.floor(&key), boxed.floor(&key));
+        }
+    }
+
+    #[test]
+    fn test_ordered_vec_symbol_table_order_statistics() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut st = OrdVecTable::new();
+        let mut map = BTreeMap::new();
+        for step in 0..5000 {
+            let key = rng.gen_range(0..300);
+            if rng.gen_bool(0.5) {
+                st.insert(key, step);
+                map.insert(key, step);
+            } else {
+                assert_eq!(st.delete(&key), map.remove(&key));
+            }
+            assert_eq!(st.len(), map.len());
+            // the deleted keys never outnumber the other ones
+            assert!(2 * st.deleted <= st.vec.len());
+            if step % 100 == 0 {
+                let (low, high) = (rng.gen_range(0..300), rng.gen_range(0..300));
+                assert_eq!(st.rank(&key), map.range(..key).count());
+                assert_eq!(st.select(step % 50), map.keys().nth(step % 50));
+                assert_eq!(st.floor(&key), map.range(..=key).next_back().map(|p| p.0));
+                assert_eq!(st.ceil(&key), map.range(key..).next().map(|p| p.0));
+                assert_eq!(st.min(), map.keys().next());
+                assert_eq!(st.max(), map.keys().next_back());
+                let expected = map
+                    .range(low..high.max(low))
+                    .map(|p| p.0)
+                    .collect::<Vec<_>>();
+                assert_eq!(st.range_count(&low, &high), expected.len());
+                assert_eq!(st.range_search(&low, &high), expected);
+            }
+        }
+        assert!(st.iter().eq(map.iter()));
+        st.compact();
+        assert_eq!(st.vec.len(), map.len());
+    }
+
+    #[test]
+    fn test_ordered_vec_symbol_table_merge() {
+        let mut st = OrdVecTable::from_sorted_iter((0..10).map(|k| (2 * k, k)));
+        st.delete(&4);
+        let mut other = OrdVecTable::from_sorted_iter((0..10).map(|k| (3 * k, 100 + k)));
+        other.delete(&9);
+        st.merge(other);
+        let mut map = (0..10).map(|k| (2 * k, k)).collect::<BTreeMap<_, _>>();
+        map.remove(&4);
+        map.extend((0..10).filter(|k| *k != 3).map(|k| (3 * k, 100 + k)));
+        assert!(st.iter().eq(map.iter()));
+        assert_eq!(st.len(), map.len());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ordered_vec_symbol_table_unsorted_iter() {
+        OrdVecTable::from_sorted_iter([(1, 1), (1, 2)]);
+    }
 }
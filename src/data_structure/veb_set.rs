@@ -0,0 +1,555 @@
+#[cfg(test)]
+mod unit_test;
+use crate::graph::Index;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+// Universes of at most 2^LEAF_BITS keys are stored in a single 64 bits word
+const LEAF_BITS: u32 = 6;
+
+// Outcome of a deletion in a node
+#[derive(Debug, PartialEq)]
+enum Deleted {
+    Absent,
+    Removed,
+    // the node held only the deleted key, the parent must drop it
+    Emptied,
+}
+
+// A non empty van Emde Boas node over keys of `bits` bits. The minimum of a branch
+// is not stored in its clusters, and empty clusters are not stored at all,
+// so that the memory is linear in the number of keys.
+#[derive(Debug, Clone)]
+enum Node {
+    Leaf(u64),
+    Branch {
+        // number of bits of the keys, split into high bits (cluster index)
+        // and low bits (key in the cluster)
+        bits: u32,
+        min: usize,
+        max: usize,
+        // set of the indices of the non empty clusters (None when there are none)
+        summary: Option<Box<Node>>,
+        clusters: HashMap<usize, Node>,
+    },
+}
+impl Node {
+    fn singleton(key: usize, bits: u32) -> Self {
+        if bits <= LEAF_BITS {
+            Self::Leaf(1 << key)
+        } else {
+            Self::Branch {
+                bits,
+                min: key,
+                max: key,
+                summary: None,
+                clusters: HashMap::new(),
+            }
+        }
+    }
+    fn min(&self) -> usize {
+        match self {
+            Self::Leaf(word) => word.trailing_zeros() as usize,
+            Self::Branch { min, .. } => *min,
+        }
+    }
+    fn max(&self) -> usize {
+        match self {
+            Self::Leaf(word) => 63 - word.leading_zeros() as usize,
+            Self::Branch { max, .. } => *max,
+        }
+    }
+    fn contains(&self, key: usize) -> bool {
+        match self {
+            Self::Leaf(word) => word >> key & 1 == 1,
+            Self::Branch {
+                bits,
+                min,
+                max,
+                clusters,
+                ..
+            } => {
+                let low_bits = *bits / 2;
+                key == *min
+                    || key == *max
+                    || clusters
+                        .get(&(key >> low_bits))
+                        .is_some_and(|cluster| cluster.contains(key & mask(low_bits)))
+            }
+        }
+    }
+    fn insert(&mut self, mut key: usize) -> bool {
+        match self {
+            Self::Leaf(word) => {
+                let absent = *word >> key & 1 == 0;
+                *word |= 1 << key;
+                absent
+            }
+            Self::Branch {
+                bits,
+                min,
+                max,
+                summary,
+                clusters,
+            } => {
+                let low_bits = *bits / 2;
+                if key == *min {
+                    return false;
+                }
+                if key < *min {
+                    // the former minimum goes down into the clusters
+                    std::mem::swap(&mut key, min);
+                }
+                let (high, low) = (key >> low_bits, key & mask(low_bits));
+                match clusters.get_mut(&high) {
+                    Some(cluster) => {
+                        if !cluster.insert(low) {
+                            return false;
+                        }
+                    }
+                    None => {
+                        clusters.insert(high, Self::singleton(low, low_bits));
+                        match summary {
+                            Some(summary) => {
+                                summary.insert(high);
+                            }
+                            None => {
+                                *summary = Some(Box::new(Self::singleton(high, *bits - low_bits)))
+                            }
+                        }
+                    }
+                }
+                if key > *max {
+                    *max = key;
+                }
+                true
+            }
+        }
+    }
+    fn delete(&mut self, mut key: usize) -> Deleted {
+        match self {
+            Self::Leaf(word) => {
+                if *word >> key & 1 == 0 {
+                    Deleted::Absent
+                } else {
+                    *word &= !(1 << key);
+                    if *word == 0 {
+                        Deleted::Emptied
+                    } else {
+                        Deleted::Removed
+                    }
+                }
+            }
+            Self::Branch {
+                bits,
+                min,
+                max,
+                summary,
+                clusters,
+            } => {
+                let low_bits = *bits / 2;
+                if *min == *max {
+                    return if key == *min {
+                        Deleted::Emptied
+                    } else {
+                        Deleted::Absent
+                    };
+                }
+                if key == *min {
+                    // the smallest key of the clusters becomes the minimum
+                    // and is deleted from its cluster
+                    let high = summary.as_ref().expect("clusters are not empty").min();
+                    key = high << low_bits | clusters[&high].min();
+                    *min = key;
+                }
+                let (high, low) = (key >> low_bits, key & mask(low_bits));
+                match clusters.get_mut(&high).map(|cluster| cluster.delete(low)) {
+                    None | Some(Deleted::Absent) => return Deleted::Absent,
+                    Some(Deleted::Removed) => (),
+                    Some(Deleted::Emptied) => {
+                        clusters.remove(&high);
+                        if let Some(Deleted::Emptied) = summary.as_mut().map(|s| s.delete(high)) {
+                            *summary = None;
+                        }
+                    }
+                }
+                if key == *max {
+                    *max = match summary {
+                        Some(summary) => {
+                            let high = summary.max();
+                            high << low_bits | clusters[&high].max()
+                        }
+                        None => *min,
+                    };
+                }
+                Deleted::Removed
+            }
+        }
+    }
+    fn successor(&self, key: usize) -> Option<usize> {
+        match self {
+            Self::Leaf(word) => {
+                let above = if key >= 63 {
+                    0
+                } else {
+                    word >> (key + 1) << (key + 1)
+                };
+                (above != 0).then(|| above.trailing_zeros() as usize)
+            }
+            Self::Branch {
+                bits,
+                min,
+                summary,
+                clusters,
+                ..
+            } => {
+                let low_bits = *bits / 2;
+                if key < *min {
+                    return Some(*min);
+                }
+                let (high, low) = (key >> low_bits, key & mask(low_bits));
+                if let Some(cluster) = clusters.get(&high) {
+                    if low < cluster.max() {
+                        return cluster.successor(low).map(|low| high << low_bits | low);
+                    }
+                }
+                let next = summary.as_ref()?.successor(high)?;
+                Some(next << low_bits | clusters[&next].min())
+            }
+        }
+    }
+    fn predecessor(&self, key: usize) -> Option<usize> {
+        match self {
+            Self::Leaf(word) => {
+                let below = word & ((1 << key) - 1);
+                (below != 0).then(|| 63 - below.leading_zeros() as usize)
+            }
+            Self::Branch {
+                bits,
+                min,
+                max,
+                summary,
+                clusters,
+            } => {
+                let low_bits = *bits / 2;
+                if key > *max {
+                    return Some(*max);
+                }
+                let (high, low) = (key >> low_bits, key & mask(low_bits));
+                if let Some(cluster) = clusters.get(&high) {
+                    if low > cluster.min() {
+                        return cluster.predecessor(low).map(|low| high << low_bits | low);
+                    }
+                }
+                match summary
+                    .as_ref()
+                    .and_then(|summary| summary.predecessor(high))
+                {
+                    Some(previous) => Some(previous << low_bits | clusters[&previous].max()),
+                    None => (key > *min).then_some(*min),
+                }
+            }
+        }
+    }
+}
+// Gives the mask of the `bits` lowest bits of a key
+fn mask(bits: u32) -> usize {
+    (1 << bits) - 1
+}
+
+/// Implementation of a van Emde Boas set of unsigned integer keys,
+/// bounded by a universe [0, U). Clusters are stored in hash maps and only when
+/// not empty, so that memory is linear in the number of keys.
+/// Keys of `u128` sets are bounded by `usize::MAX`: larger keys cannot be inserted
+/// and are never in the set.
+/// # Examples
+/// ```
+/// use algods::data_structure::VebSet;
+/// let mut set = VebSet::<u32>::new();
+/// set.insert(3);
+/// set.insert(1_000_000);
+/// set.insert(42);
+/// assert_eq!(set.min(), Some(3));
+/// assert_eq!(set.successor(&3), Some(42));
+/// assert_eq!(set.predecessor(&42), Some(3));
+/// assert!(set.delete(&42));
+/// assert_eq!(set.successor(&3), Some(1_000_000));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VebSet<N> {
+    root: Option<Node>,
+    // number of bits of the keys
+    bits: u32,
+    // largest key allowed in the set
+    limit: usize,
+    len: usize,
+    key_type: PhantomData<N>,
+}
+impl<N: Index> Default for VebSet<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<N: Index> VebSet<N> {
+    /// Creates an empty set whose universe is the whole range of the key type.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = VebSet::<u16>::new();
+    /// assert!(set.is_empty());
+    /// assert_eq!(set.universe(), 1 << 16);
+    /// ```
+    pub fn new() -> Self {
+        Self::with_limit(N::maximum().to_usize())
+    }
+    /// Creates an empty set of keys in [0, universe).
+    /// # Panics
+    /// If `universe = 0` or if it is larger than the range of the key type, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = VebSet::<u32>::with_universe(1000);
+    /// assert_eq!(set.universe(), 1000);
+    /// ```
+    pub fn with_universe(universe: usize) -> Self {
+        if universe == 0 {
+            panic!("the universe must contain at least one key");
+        }
+        if universe - 1 > N::maximum().to_usize() {
+            panic!("the universe {universe} is larger than the range of the key type");
+        }
+        Self::with_limit(universe - 1)
+    }
+    // Converts a key to its position in the universe, if it fits in a usize
+    fn position(key: &N) -> Option<usize> {
+        let position = key.to_usize();
+        (N::to_vertex(position) == *key).then_some(position)
+    }
+    fn with_limit(limit: usize) -> Self {
+        Self {
+            root: None,
+            bits: usize::BITS - limit.leading_zeros(),
+            limit,
+            len: 0,
+            key_type: PhantomData,
+        }
+    }
+    /// Gives the number of possible keys, saturated at `usize::MAX`.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = VebSet::<u8>::new();
+    /// assert_eq!(set.universe(), 256);
+    /// ```
+    pub fn universe(&self) -> usize {
+        self.limit.saturating_add(1)
+    }
+    /// Gives the number of keys in the set.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let mut set = VebSet::<u8>::new();
+    /// set.insert(1);
+    /// set.insert(1);
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Tests whether or not the set is empty.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = VebSet::<u8>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Tests whether or not a key is in the set.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let mut set = VebSet::<u32>::with_universe(100);
+    /// set.insert(7);
+    /// assert!(set.contains(&7));
+    /// assert!(!set.contains(&8));
+    /// assert!(!set.contains(&1000));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(log(U)))
+    pub fn contains(&self, key: &N) -> bool {
+        let Some(key) = Self::position(key) else {
+            return false;
+        };
+        key <= self.limit && self.root.as_ref().is_some_and(|root| root.contains(key))
+    }
+    /// Inserts a key in the set, returns whether or not it was absent.
+    /// # Panics
+    /// If the key is out of the universe, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let mut set = VebSet::<u32>::new();
+    /// assert!(set.insert(7));
+    /// assert!(!set.insert(7));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(log(U)))
+    pub fn insert(&mut self, key: N) -> bool {
+        let Some(key) = Self::position(&key) else {
+            panic!("key is larger than usize::MAX, out of the universe of the set");
+        };
+        if key > self.limit {
+            panic!(
+                "key {key} is out of the universe of {} keys",
+                self.universe()
+            );
+        }
+        let inserted = match &mut self.root {
+            Some(root) => root.insert(key),
+            None => {
+                self.root = Some(Node::singleton(key, self.bits));
+                true
+            }
+        };
+        if inserted {
+            self.len += 1;
+        }
+        inserted
+    }
+    /// Deletes a key from the set, returns whether or not it was present.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let mut set = VebSet::<u32>::new();
+    /// set.insert(7);
+    /// assert!(set.delete(&7));
+    /// assert!(!set.delete(&7));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(log(U)))
+    pub fn delete(&mut self, key: &N) -> bool {
+        let key = match Self::position(key) {
+            Some(key) if key <= self.limit => key,
+            _ => return false,
+        };
+        let deleted = match self.root.as_mut().map(|root| root.delete(key)) {
+            None | Some(Deleted::Absent) => return false,
+            Some(Deleted::Removed) => true,
+            Some(Deleted::Emptied) => {
+                self.root = None;
+                true
+            }
+        };
+        self.len -= 1;
+        deleted
+    }
+    /// Gives the smallest key in the set.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = [5u8, 2, 9].into_iter().collect::<VebSet<u8>>();
+    /// assert_eq!(set.min(), Some(2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn min(&self) -> Option<N> {
+        self.root.as_ref().map(|root| N::to_vertex(root.min()))
+    }
+    /// Gives the largest key in the set.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = [5u8, 2, 9].into_iter().collect::<VebSet<u8>>();
+    /// assert_eq!(set.max(), Some(9));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1)
+    pub fn max(&self) -> Option<N> {
+        self.root.as_ref().map(|root| N::to_vertex(root.max()))
+    }
+    /// Gives the smallest key in the set strictly larger than a given key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = [5u8, 2, 9].into_iter().collect::<VebSet<u8>>();
+    /// assert_eq!(set.successor(&5), Some(9));
+    /// assert_eq!(set.successor(&9), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(log(U)))
+    pub fn successor(&self, key: &N) -> Option<N> {
+        let key = match Self::position(key) {
+            Some(key) if key < self.limit => key,
+            _ => return None,
+        };
+        self.root
+            .as_ref()?
+            .successor(key)
+            .map(|key| N::to_vertex(key))
+    }
+    /// Gives the largest key in the set strictly smaller than a given key.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = [5u8, 2, 9].into_iter().collect::<VebSet<u8>>();
+    /// assert_eq!(set.predecessor(&5), Some(2));
+    /// assert_eq!(set.predecessor(&2), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(log(U)))
+    pub fn predecessor(&self, key: &N) -> Option<N> {
+        let key = match Self::position(key) {
+            Some(key) if key <= self.limit => key,
+            _ => return self.max(),
+        };
+        self.root
+            .as_ref()?
+            .predecessor(key)
+            .map(|key| N::to_vertex(key))
+    }
+    /// Gives an iterator over the keys of the set, in increasing order.
+    /// # Example
+    /// ```
+    /// use algods::data_structure::VebSet;
+    /// let set = [5u8, 2, 9].into_iter().collect::<VebSet<u8>>();
+    /// assert_eq!(set.iter().collect::<Vec<u8>>(), vec![2, 5, 9]);
+    /// ```
+    pub fn iter(&self) -> VebSetIter<'_, N> {
+        VebSetIter {
+            set: self,
+            next: self.min(),
+        }
+    }
+}
+impl<N: Index> FromIterator<N> for VebSet<N> {
+    fn from_iter<I: IntoIterator<Item = N>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for key in iter {
+            set.insert(key);
+        }
+        set
+    }
+}
+
+/// Iterator over the keys of a `VebSet`, in increasing order
+pub struct VebSetIter<'a, N> {
+    set: &'a VebSet<N>,
+    next: Option<N>,
+}
+impl<N: Index> Iterator for VebSetIter<'_, N> {
+    type Item = N;
+    fn next(&mut self) -> Option<Self::Item> {
+        let key = self.next?;
+        self.next = self.set.successor(&key);
+        Some(key)
+    }
+}
+impl<'a, N: Index> IntoIterator for &'a VebSet<N> {
+    type Item = N;
+    type IntoIter = VebSetIter<'a, N>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    fn check_against_btree_set<N: Index + std::fmt::Debug>(
+        mut set: VebSet<N>,
+        universe: usize,
+        seed: u64,
+    ) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut model = BTreeSet::new();
+        for _ in 0..20_000 {
+            let key = N::to_vertex(rng.gen_range(0..universe));
+            if rng.gen_bool(0.4) {
+                assert_eq!(set.delete(&key), model.remove(&key));
+            } else {
+                assert_eq!(set.insert(key), model.insert(key));
+            }
+            assert_eq!(set.len(), model.len());
+            assert_eq!(set.min(), model.first().copied());
+            assert_eq!(set.max(), model.last().copied());
+            let probe = N::to_vertex(rng.gen_range(0..universe));
+            assert_eq!(set.contains(&probe), model.contains(&probe));
+            assert_eq!(
+                set.successor(&probe),
+                model.range(probe..).find(|k| **k != probe).copied()
+            );
+            assert_eq!(
+                set.predecessor(&probe),
+                model.range(..probe).last().copied()
+            );
+        }
+        assert!(set.iter().eq(model.iter().copied()));
+    }
+
+    #[test]
+    fn test_veb_set_small_universes() {
+        for (seed, universe) in [1, 2, 3, 64, 65, 100, 1000].into_iter().enumerate() {
+            check_against_btree_set(
+                VebSet::<u16>::with_universe(universe),
+                universe,
+                seed as u64,
+            );
+        }
+    }
+
+    #[test]
+    fn test_veb_set_full_universes() {
+        check_against_btree_set(VebSet::<u8>::new(), 256, 10);
+        check_against_btree_set(VebSet::<u16>::new(), 1 << 16, 11);
+        // dense keys in a sparse large universe
+        check_against_btree_set(VebSet::<u32>::new(), 5000, 12);
+        check_against_btree_set(VebSet::<u64>::new(), 300, 13);
+    }
+
+    #[test]
+    fn test_veb_set_extreme_keys() {
+        let mut set = VebSet::<u64>::new();
+        assert!(set.insert(u64::MAX));
+        assert!(set.insert(0));
+        assert!(set.insert(1 << 40));
+        assert_eq!(set.successor(&0), Some(1 << 40));
+        assert_eq!(set.successor(&(1 << 40)), Some(u64::MAX));
+        assert_eq!(set.successor(&u64::MAX), None);
+        assert_eq!(set.predecessor(&u64::MAX), Some(1 << 40));
+        assert!(set.delete(&u64::MAX));
+        assert_eq!(set.max(), Some(1 << 40));
+        assert_eq!(set.iter().collect::<Vec<u64>>(), vec![0, 1 << 40]);
+    }
+
+    #[test]
+    fn test_veb_set_clusters_are_freed() {
+        let mut set = VebSet::<u32>::new();
+        for key in 0..10_000 {
+            set.insert(key * 7919);
+        }
+        for key in 0..10_000 {
+            assert!(set.delete(&(key * 7919)));
+        }
+        assert!(set.is_empty());
+        assert!(set.root.is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_veb_set_out_of_universe() {
+        let mut set = VebSet::<u32>::with_universe(10);
+        set.insert(10);
+    }
+
+    #[test]
+    fn test_veb_set_u128_keys_beyond_usize() {
+        let mut set = VebSet::<u128>::new();
+        let large = 1u128 << usize::BITS;
+        set.insert(0);
+        set.insert(5);
+        // large would be truncated to 0 if converted as is
+        assert!(!set.contains(&large));
+        assert!(!set.contains(&(large + 5)));
+        assert!(!set.delete(&large));
+        assert_eq!(set.successor(&large), None);
+        assert_eq!(set.predecessor(&large), Some(5));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.min(), Some(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_veb_set_u128_insert_beyond_usize() {
+        let mut set = VebSet::<u128>::new();
+        set.insert(1 << usize::BITS);
+    }
+}
@@ -1,9 +1,11 @@
 mod connection;
+mod lca;
 mod maxflow_mincut;
 mod search;
 mod sort;
 
 pub use connection::{ConnectedComponent, StrongConnectedComponent};
+pub use lca::{Lca, LcaAlgo};
 pub use maxflow_mincut::FordFulkerson;
 pub use search::{bfs, dfs, BreadthFirstSearch, DepthFirstSearch, ShortestPath, ShortestPathAlgo};
 pub use sort::TopologicalSort;
@@ -0,0 +1,324 @@
+#[cfg(test)]
+mod unit_test;
+use crate::data_structure::{Idempotent, Monoid, SparseTable};
+use crate::graph::{DiGraph, Index};
+use std::marker::PhantomData;
+
+// Vertices outside of the tree(s) (or their missing parent) are marked with this value
+const NONE: usize = usize::MAX;
+
+/// Algorithms answering lowest common ancestor queries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LcaAlgo {
+    /// Range minimum queries over the depths along an Euler tour,
+    /// in O(1) per query after an O(N log(N)) build
+    #[default]
+    EulerTour,
+    /// Jumps by powers of two along the ancestors, in O(log(N)) per query
+    /// after an O(N log(N)) build. It also answers k-th ancestor queries in O(log(N)).
+    BinaryLifting,
+}
+
+// Minimum over (depth, vertex) pairs of an Euler tour
+#[derive(Debug, Clone)]
+struct MinDepth;
+impl Monoid<(usize, usize)> for MinDepth {
+    fn identity() -> (usize, usize) {
+        (NONE, NONE)
+    }
+    fn combine(a: &(usize, usize), b: &(usize, usize)) -> (usize, usize) {
+        *a.min(b)
+    }
+}
+impl Idempotent<(usize, usize)> for MinDepth {}
+
+/// Implementation of lowest common ancestor queries on a rooted tree (or forest),
+/// given either as a directed graph with edges going from parents to children,
+/// or as an array of parents.
+/// # Examples
+/// ```
+/// use algods::graph::DiGraph;
+/// use algods::graph::processing::{Lca, LcaAlgo};
+/// //        0
+/// //      /   \
+/// //     1     2
+/// //    / \     \
+/// //   3   4     5
+/// let graph = DiGraph::<usize>::from_vec(vec![(0, 1), (0, 2), (1, 3), (1, 4), (2, 5)]);
+/// for algo in [LcaAlgo::EulerTour, LcaAlgo::BinaryLifting] {
+///     let lca = Lca::from_digraph(&graph, 0, algo);
+///     assert_eq!(lca.lca(&3, &4), Some(1));
+///     assert_eq!(lca.lca(&3, &5), Some(0));
+///     assert_eq!(lca.lca(&1, &4), Some(1));
+///     assert_eq!(lca.distance(&4, &5), Some(4));
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Lca<N> {
+    algo: LcaAlgo,
+    // depth of each vertex, from the root of its tree
+    depth: Vec<usize>,
+    // parent of each vertex (NONE for roots and vertices outside the trees)
+    parent: Vec<usize>,
+    // root of the tree containing each vertex (NONE for vertices outside the trees)
+    root: Vec<usize>,
+    // position of the first occurrence of each vertex in the Euler tour (Euler tour only)
+    first: Vec<usize>,
+    // minimum (depth, vertex) over ranges of the Euler tour (Euler tour only)
+    tour: Option<SparseTable<(usize, usize), MinDepth>>,
+    // up[k][v] is the 2^k-th ancestor of v, or the root of its tree (binary lifting only)
+    up: Vec<Vec<usize>>,
+    vertex_type: PhantomData<N>,
+}
+impl<N: Index> Lca<N> {
+    /// Builds the lowest common ancestor structure of the tree rooted at `root`
+    /// in a directed graph whose edges go from parents to children.
+    /// Vertices not reachable from `root` are not part of the tree.
+    /// # Panics
+    /// If a vertex can be reached from `root` through two different paths
+    /// (i.e. the reachable subgraph is not a tree), then it panics.
+    /// # Example
+    /// ```
+    /// use algods::graph::DiGraph;
+    /// use algods::graph::processing::{Lca, LcaAlgo};
+    /// let graph = DiGraph::<u32>::from_vec(vec![(2, 0), (2, 1), (0, 3)]);
+    /// let lca = Lca::from_digraph(&graph, 2, LcaAlgo::default());
+    /// assert_eq!(lca.lca(&3, &1), Some(2));
+    /// assert_eq!(lca.depth(&3), Some(2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N log(N))
+    pub fn from_digraph(graph: &DiGraph<N>, root: N, algo: LcaAlgo) -> Self {
+        let children = (0..graph.nb_vertices())
+            .map(|v| {
+                graph
+                    .out_edges(&N::to_vertex(v))
+                    .iter()
+                    .map(|w| w.to_usize())
+                    .collect()
+            })
+            .collect::<Vec<Vec<usize>>>();
+        Self::build(children, &[root.to_usize()], algo)
+    }
+    /// Builds the lowest common ancestor structure of a forest given by the parent
+    /// of each vertex, `None` marking the roots.
+    /// # Panics
+    /// If the parents contain a cycle, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::graph::processing::{Lca, LcaAlgo};
+    /// // two trees: 0 -> {1, 2} and 3 -> {4}
+    /// let parents = [None, Some(0), Some(0), None, Some(3)];
+    /// let lca = Lca::<usize>::from_parents(&parents, LcaAlgo::BinaryLifting);
+    /// assert_eq!(lca.lca(&1, &2), Some(0));
+    /// assert_eq!(lca.lca(&1, &4), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(N log(N))
+    pub fn from_parents(parents: &[Option<N>], algo: LcaAlgo) -> Self {
+        let mut children = vec![Vec::new(); parents.len()];
+        let mut roots = Vec::new();
+        for (v, parent) in parents.iter().enumerate() {
+            match parent {
+                Some(p) => children[p.to_usize()].push(v),
+                None => roots.push(v),
+            }
+        }
+        let lca = Self::build(children, &roots, algo);
+        if lca.root.contains(&NONE) {
+            panic!("the parents contain a cycle");
+        }
+        lca
+    }
+    fn build(children: Vec<Vec<usize>>, roots: &[usize], algo: LcaAlgo) -> Self {
+        let nb = children.len();
+        let mut depth = vec![0; nb];
+        let mut parent = vec![NONE; nb];
+        let mut root = vec![NONE; nb];
+        let mut first = Vec::new();
+        let mut euler = Vec::new();
+        if algo == LcaAlgo::EulerTour {
+            first = vec![NONE; nb];
+            euler.reserve(2 * nb);
+        }
+        // iterative depth first search, each entry holds a vertex
+        // and the position of its next child to visit
+        let mut stack = Vec::new();
+        for &r in roots {
+            root[r] = r;
+            stack.push((r, 0));
+            while let Some((v, next)) = stack.pop() {
+                if algo == LcaAlgo::EulerTour {
+                    if next == 0 {
+                        first[v] = euler.len();
+                    }
+                    euler.push((depth[v], v));
+                }
+                if let Some(&w) = children[v].get(next) {
+                    if root[w] != NONE {
+                        panic!("vertex {w} has several parents, the graph is not a tree");
+                    }
+                    root[w] = r;
+                    parent[w] = v;
+                    depth[w] = depth[v] + 1;
+                    stack.push((v, next + 1));
+                    stack.push((w, 0));
+                }
+            }
+        }
+        let mut tour = None;
+        let mut up = Vec::new();
+        match algo {
+            LcaAlgo::EulerTour => tour = Some(SparseTable::from_vec(euler)),
+            LcaAlgo::BinaryLifting => {
+                // roots (and vertices outside the trees) are their own ancestors
+                up.push(
+                    (0..nb)
+                        .map(|v| if parent[v] == NONE { v } else { parent[v] })
+                        .collect::<Vec<usize>>(),
+                );
+                let max_depth = depth.iter().copied().max().unwrap_or(0);
+                while 1 << up.len() <= max_depth {
+                    let previous = &up[up.len() - 1];
+                    let row = (0..nb).map(|v| previous[previous[v]]).collect();
+                    up.push(row);
+                }
+            }
+        }
+        Self {
+            algo,
+            depth,
+            parent,
+            root,
+            first,
+            tour,
+            up,
+            vertex_type: PhantomData,
+        }
+    }
+    /// Gives the algorithm used to answer the queries.
+    /// # Example
+    /// ```
+    /// use algods::graph::processing::{Lca, LcaAlgo};
+    /// let lca = Lca::<u8>::from_parents(&[None], LcaAlgo::BinaryLifting);
+    /// assert_eq!(lca.algo(), LcaAlgo::BinaryLifting);
+    /// ```
+    pub fn algo(&self) -> LcaAlgo {
+        self.algo
+    }
+    /// Gives the depth of a vertex (0 for a root),
+    /// or `None` if the vertex is not part of the tree(s).
+    /// # Panics
+    /// If the vertex is out of bounds, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::graph::processing::{Lca, LcaAlgo};
+    /// let lca = Lca::<u8>::from_parents(&[None, Some(0), Some(1)], LcaAlgo::default());
+    /// assert_eq!(lca.depth(&2), Some(2));
+    /// ```
+    pub fn depth(&self, vertex: &N) -> Option<usize> {
+        let v = vertex.to_usize();
+        (self.root[v] != NONE).then_some(self.depth[v])
+    }
+    /// Gives the lowest common ancestor of two vertices, that is their deepest common ancestor
+    /// (a vertex being an ancestor of itself). It is `None` if the vertices are not in the same tree.
+    /// # Panics
+    /// If one of the vertices is out of bounds, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::graph::processing::{Lca, LcaAlgo};
+    /// let parents = [None, Some(0), Some(1), Some(1)];
+    /// let lca = Lca::<u8>::from_parents(&parents, LcaAlgo::EulerTour);
+    /// assert_eq!(lca.lca(&2, &3), Some(1));
+    /// assert_eq!(lca.lca(&2, &2), Some(2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) with the Euler tour and in O(log(N)) with binary lifting.
+    pub fn lca(&self, u: &N, v: &N) -> Option<N> {
+        let (u, v) = (u.to_usize(), v.to_usize());
+        if self.root[u] == NONE || self.root[u] != self.root[v] {
+            return None;
+        }
+        let ancestor = match &self.tour {
+            Some(tour) => {
+                let (low, high) = if self.first[u] <= self.first[v] {
+                    (self.first[u], self.first[v])
+                } else {
+                    (self.first[v], self.first[u])
+                };
+                tour.query(low, high + 1).1
+            }
+            None => {
+                let (mut u, mut v) = if self.depth[u] >= self.depth[v] {
+                    (u, v)
+                } else {
+                    (v, u)
+                };
+                u = self.lift(u, self.depth[u] - self.depth[v]);
+                if u != v {
+                    for row in self.up.iter().rev() {
+                        if row[u] != row[v] {
+                            u = row[u];
+                            v = row[v];
+                        }
+                    }
+                    u = self.up[0][u];
+                }
+                u
+            }
+        };
+        Some(N::to_vertex(ancestor))
+    }
+    /// Gives the number of edges on the path between two vertices,
+    /// or `None` if the vertices are not in the same tree.
+    /// # Panics
+    /// If one of the vertices is out of bounds, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::graph::processing::{Lca, LcaAlgo};
+    /// let parents = [None, Some(0), Some(1), Some(0)];
+    /// let lca = Lca::<u8>::from_parents(&parents, LcaAlgo::default());
+    /// assert_eq!(lca.distance(&2, &3), Some(3));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(1) with the Euler tour and in O(log(N)) with binary lifting.
+    pub fn distance(&self, u: &N, v: &N) -> Option<usize> {
+        let ancestor = self.lca(u, v)?.to_usize();
+        Some(self.depth[u.to_usize()] + self.depth[v.to_usize()] - 2 * self.depth[ancestor])
+    }
+    /// Gives the ancestor of a vertex `k` levels above it (the vertex itself when `k = 0`),
+    /// or `None` if there is no such ancestor or the vertex is not part of the tree(s).
+    /// # Panics
+    /// If the vertex is out of bounds, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::graph::processing::{Lca, LcaAlgo};
+    /// let parents = [None, Some(0), Some(1), Some(2)];
+    /// let lca = Lca::<u8>::from_parents(&parents, LcaAlgo::BinaryLifting);
+    /// assert_eq!(lca.kth_ancestor(&3, 2), Some(1));
+    /// assert_eq!(lca.kth_ancestor(&3, 4), None);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N)) with binary lifting and in O(k) with the Euler tour.
+    pub fn kth_ancestor(&self, vertex: &N, k: usize) -> Option<N> {
+        let v = vertex.to_usize();
+        if self.root[v] == NONE || k > self.depth[v] {
+            return None;
+        }
+        Some(N::to_vertex(self.lift(v, k)))
+    }
+    fn lift(&self, mut v: usize, k: usize) -> usize {
+        if self.up.is_empty() {
+            for _ in 0..k {
+                v = self.parent[v];
+            }
+        } else {
+            for (j, row) in self.up.iter().enumerate() {
+                if (k >> j) & 1 == 1 {
+                    v = row[v];
+                }
+            }
+        }
+        v
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const ALGOS: [LcaAlgo; 2] = [LcaAlgo::EulerTour, LcaAlgo::BinaryLifting];
+
+    // naive lowest common ancestor by climbing the parents
+    fn naive_lca(parents: &[Option<usize>], u: usize, v: usize) -> Option<usize> {
+        let mut ancestors = vec![u];
+        while let Some(p) = parents[ancestors[ancestors.len() - 1]] {
+            ancestors.push(p);
+        }
+        let mut w = v;
+        loop {
+            if ancestors.contains(&w) {
+                return Some(w);
+            }
+            w = parents[w]?;
+        }
+    }
+
+    #[test]
+    fn test_lca_random_forest() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let n = 300;
+        // vertices are shuffled so that parents are not always smaller than children
+        let mut labels = (0..n).collect::<Vec<usize>>();
+        rand::seq::SliceRandom::shuffle(labels.as_mut_slice(), &mut rng);
+        let mut parents = vec![None; n];
+        for v in 1..n {
+            if rng.gen_bool(0.97) {
+                // deep trees: parents are often close to their children
+                let p = v - 1 - rng.gen_range(0..v.min(4));
+                parents[labels[v]] = Some(labels[p]);
+            }
+        }
+        for algo in ALGOS {
+            let lca = Lca::from_parents(&parents, algo);
+            for _ in 0..2000 {
+                let (u, v) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                let expected = naive_lca(&parents, u, v);
+                assert_eq!(lca.lca(&u, &v), expected);
+                let k = rng.gen_range(0..n / 4);
+                let mut ancestor = Some(u);
+                for _ in 0..k {
+                    ancestor = ancestor.and_then(|w| parents[w]);
+                }
+                assert_eq!(lca.kth_ancestor(&u, k), ancestor);
+                if let Some(w) = expected {
+                    let d = |x: usize| lca.depth(&x).unwrap();
+                    assert_eq!(lca.distance(&u, &v), Some(d(u) + d(v) - 2 * d(w)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_lca_digraph() {
+        // vertex 6 is not reachable from the root
+        let graph = DiGraph::<u16>::from_vec(vec![(0, 1), (0, 2), (1, 3), (3, 4), (2, 5), (6, 5)]);
+        for algo in ALGOS {
+            let lca = Lca::from_digraph(&graph, 0, algo);
+            assert_eq!(lca.lca(&4, &5), Some(0));
+            assert_eq!(lca.lca(&4, &1), Some(1));
+            assert_eq!(lca.depth(&4), Some(3));
+            assert_eq!(lca.depth(&6), None);
+            assert_eq!(lca.lca(&6, &5), None);
+            assert_eq!(lca.kth_ancestor(&6, 0), None);
+        }
+    }
+
+    #[test]
+    fn test_lca_deep_path() {
+        let n = 200_000usize;
+        let parents = (0..n)
+            .map(|v| v.checked_sub(1))
+            .collect::<Vec<Option<usize>>>();
+        for algo in ALGOS {
+            let lca = Lca::from_parents(&parents, algo);
+            assert_eq!(lca.lca(&(n - 1), &12345), Some(12345));
+            assert_eq!(lca.distance(&0, &(n - 1)), Some(n - 1));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lca_not_a_tree() {
+        let graph = DiGraph::<u8>::from_vec(vec![(0, 1), (0, 2), (1, 3), (2, 3)]);
+        Lca::from_digraph(&graph, 0, LcaAlgo::EulerTour);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lca_cycle() {
+        Lca::<u8>::from_parents(&[None, Some(2), Some(1)], LcaAlgo::BinaryLifting);
+    }
+}
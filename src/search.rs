@@ -1,5 +1,7 @@
 mod binary_search;
+mod dynamic_connectivity;
 mod union_find;
 
 pub use binary_search::binary_search;
-pub use union_find::{UnionFind, UnionFindAlgorithm};
+pub use dynamic_connectivity::OfflineConnectivity;
+pub use union_find::{KeyedUnionFind, RollbackUnionFind, UnionFind, UnionFindAlgorithm};
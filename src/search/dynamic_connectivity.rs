@@ -0,0 +1,247 @@
+#[cfg(test)]
+mod unit_test;
+use crate::search::RollbackUnionFind;
+use std::collections::HashMap;
+
+/// Implementation of an offline solver of dynamic connectivity: given a sequence of
+/// edge insertions and deletions between objects, it answers whether or not two objects
+/// are connected at given times, the time t being the state after the first t updates.
+/// Each edge is alive on an interval of times, which is split over the nodes of a segment
+/// tree over time. A depth first traversal of the tree then unions the edges of each node
+/// in a `RollbackUnionFind`, answers the queries at the leaves and rolls back on the way up.
+/// # Examples
+/// ```
+/// use algods::search::OfflineConnectivity;
+/// let mut solver = OfflineConnectivity::with_capacity(3);
+/// solver.add_edge(0, 1);
+/// solver.add_edge(1, 2);
+/// let before = solver.query(0, 2, solver.time());
+/// solver.remove_edge(0, 1);
+/// let after = solver.query(0, 2, solver.time());
+/// let at_start = solver.query(0, 1, 0);
+/// assert_eq!(solver.solve(), vec![true, false, false]);
+/// assert_eq!((before, after, at_start), (0, 1, 2));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OfflineConnectivity {
+    nb_objects: usize,
+    // number of updates so far
+    time: usize,
+    // times of insertion of the edges still alive, by edge
+    alive: HashMap<(usize, usize), Vec<usize>>,
+    // edges with the interval of times [start, end) they are alive on,
+    // the end being None for the edges still alive
+    intervals: Vec<(usize, usize, usize, Option<usize>)>,
+    // queries (p, q, time)
+    queries: Vec<(usize, usize, usize)>,
+}
+impl OfflineConnectivity {
+    /// Creates a solver over `nb` objects, with no edge.
+    /// # Example
+    /// ```
+    /// use algods::search::OfflineConnectivity;
+    /// let solver = OfflineConnectivity::with_capacity(10);
+    /// assert_eq!(solver.len(), 10);
+    /// assert_eq!(solver.time(), 0);
+    /// ```
+    pub fn with_capacity(nb: usize) -> Self {
+        Self {
+            nb_objects: nb,
+            ..Default::default()
+        }
+    }
+    /// Gives the number of objects.
+    /// # Example
+    /// ```
+    /// use algods::search::OfflineConnectivity;
+    /// let solver = OfflineConnectivity::with_capacity(10);
+    /// assert_eq!(solver.len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.nb_objects
+    }
+    /// Indicates whether or not there is no object.
+    /// # Example
+    /// ```
+    /// use algods::search::OfflineConnectivity;
+    /// let solver = OfflineConnectivity::with_capacity(0);
+    /// assert!(solver.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Gives the number of updates (edge insertions and deletions) so far.
+    /// # Example
+    /// ```
+    /// use algods::search::OfflineConnectivity;
+    /// let mut solver = OfflineConnectivity::with_capacity(2);
+    /// solver.add_edge(0, 1);
+    /// solver.remove_edge(1, 0);
+    /// assert_eq!(solver.time(), 2);
+    /// ```
+    pub fn time(&self) -> usize {
+        self.time
+    }
+    fn check(&self, p: usize, q: usize) {
+        if p.max(q) >= self.nb_objects {
+            panic!(
+                "object {} is out of bounds, there are {} objects",
+                p.max(q),
+                self.nb_objects
+            );
+        }
+    }
+    /// Inserts an edge between two objects. Edges are undirected and can be inserted several times.
+    /// # Panics
+    /// If one of the objects is out of bounds, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::search::OfflineConnectivity;
+    /// let mut solver = OfflineConnectivity::with_capacity(2);
+    /// solver.add_edge(0, 1);
+    /// solver.query(1, 0, 1);
+    /// assert_eq!(solver.solve(), vec![true]);
+    /// ```
+    pub fn add_edge(&mut self, p: usize, q: usize) {
+        self.check(p, q);
+        self.time += 1;
+        self.alive
+            .entry((p.min(q), p.max(q)))
+            .or_default()
+            .push(self.intervals.len());
+        self.intervals.push((p, q, self.time, None));
+    }
+    /// Deletes an edge between two objects (one copy of it when inserted several times).
+    /// # Panics
+    /// If the edge is not in the graph, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::search::OfflineConnectivity;
+    /// let mut solver = OfflineConnectivity::with_capacity(2);
+    /// solver.add_edge(0, 1);
+    /// solver.add_edge(0, 1);
+    /// solver.remove_edge(1, 0);
+    /// solver.query(0, 1, 3);
+    /// assert_eq!(solver.solve(), vec![true]);
+    /// ```
+    pub fn remove_edge(&mut self, p: usize, q: usize) {
+        let edge = (p.min(q), p.max(q));
+        let Some(interval) = self.alive.get_mut(&edge).and_then(|starts| starts.pop()) else {
+            panic!("edge ({p}, {q}) is not in the graph");
+        };
+        self.time += 1;
+        self.intervals[interval].3 = Some(self.time);
+    }
+    /// Asks whether or not two objects are connected at a given time,
+    /// and returns the position of the answer in the result of `solve`.
+    /// # Example
+    /// ```
+    /// use algods::search::OfflineConnectivity;
+    /// let mut solver = OfflineConnectivity::with_capacity(2);
+    /// assert_eq!(solver.query(0, 1, 0), 0);
+    /// assert_eq!(solver.query(1, 1, 0), 1);
+    /// assert_eq!(solver.solve(), vec![false, true]);
+    /// ```
+    pub fn query(&mut self, p: usize, q: usize, time: usize) -> usize {
+        self.check(p, q);
+        self.queries.push((p, q, time));
+        self.queries.len() - 1
+    }
+    /// Answers all the queries, in the order they were asked.
+    /// # Panics
+    /// If a query is about a time later than the last update, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::search::OfflineConnectivity;
+    /// let mut solver = OfflineConnectivity::with_capacity(3);
+    /// solver.add_edge(0, 1);
+    /// solver.add_edge(1, 2);
+    /// solver.remove_edge(0, 1);
+    /// solver.query(0, 2, 2);
+    /// solver.query(0, 2, 3);
+    /// assert_eq!(solver.solve(), vec![true, false]);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O((U log(U) + Q) log(N)), for U updates and Q queries.
+    pub fn solve(&self) -> Vec<bool> {
+        // times are the leaves of the segment tree, from 0 to the last update
+        let nb_times = self.time + 1;
+        let mut queries_at = vec![Vec::new(); nb_times];
+        for (index, (p, q, time)) in self.queries.iter().enumerate() {
+            if *time >= nb_times {
+                panic!(
+                    "query at time {time} is after the last update, at time {}",
+                    self.time
+                );
+            }
+            queries_at[*time].push((index, *p, *q));
+        }
+        let mut edges_at = vec![Vec::new(); 4 * nb_times];
+        for (p, q, start, end) in self.intervals.iter() {
+            let end = end.unwrap_or(nb_times);
+            if start < &end {
+                add_interval(&mut edges_at, 1, 0, nb_times, *start, end, (*p, *q));
+            }
+        }
+        let mut answers = vec![false; self.queries.len()];
+        let mut uf = RollbackUnionFind::with_capacity(self.nb_objects);
+        traverse(
+            &edges_at,
+            &queries_at,
+            &mut uf,
+            &mut answers,
+            1,
+            0,
+            nb_times,
+        );
+        answers
+    }
+}
+// Adds an edge on the nodes covering [start, end) in the subtree of the node covering [low, high)
+fn add_interval(
+    edges_at: &mut [Vec<(usize, usize)>],
+    node: usize,
+    low: usize,
+    high: usize,
+    start: usize,
+    end: usize,
+    edge: (usize, usize),
+) {
+    if start <= low && high <= end {
+        edges_at[node].push(edge);
+        return;
+    }
+    let mid = low + (high - low) / 2;
+    if start < mid {
+        add_interval(edges_at, 2 * node, low, mid, start, end, edge);
+    }
+    if mid < end {
+        add_interval(edges_at, 2 * node + 1, mid, high, start, end, edge);
+    }
+}
+// Unions the edges of the node covering [low, high), answers the queries
+// at the leaves of its subtree, then undoes the unions
+fn traverse(
+    edges_at: &[Vec<(usize, usize)>],
+    queries_at: &[Vec<(usize, usize, usize)>],
+    uf: &mut RollbackUnionFind,
+    answers: &mut [bool],
+    node: usize,
+    low: usize,
+    high: usize,
+) {
+    let snapshot = uf.snapshot();
+    for (p, q) in edges_at[node].iter() {
+        uf.union(*p, *q);
+    }
+    if high - low == 1 {
+        for (index, p, q) in queries_at[low].iter() {
+            answers[*index] = uf.connected(*p, *q);
+        }
+    } else {
+        let mid = low + (high - low) / 2;
+        traverse(edges_at, queries_at, uf, answers, 2 * node, low, mid);
+        traverse(edges_at, queries_at, uf, answers, 2 * node + 1, mid, high);
+    }
+    uf.rollback(snapshot);
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use crate::search::{UnionFind, UnionFindAlgorithm};
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_offline_connectivity_against_recomputation() {
+        let n = 30;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut solver = OfflineConnectivity::with_capacity(n);
+        let mut edges = Vec::new();
+        let mut expected = Vec::new();
+        for _ in 0..600 {
+            if rng.gen_bool(0.4) && !edges.is_empty() {
+                let (p, q) = edges.swap_remove(rng.gen_range(0..edges.len()));
+                solver.remove_edge(q, p);
+            } else {
+                let (p, q) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                solver.add_edge(p, q);
+                edges.push((p, q));
+            }
+            // connectivity recomputed from scratch on the current edges
+            let mut uf = UnionFind::with_capacity(n, UnionFindAlgorithm::WeightedQuickUnion);
+            for (p, q) in edges.iter() {
+                uf.union(*p, *q);
+            }
+            for _ in 0..3 {
+                let (p, q) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                solver.query(p, q, solver.time());
+                expected.push(uf.connected(p, q));
+            }
+        }
+        assert_eq!(solver.solve(), expected);
+    }
+
+    #[test]
+    fn test_offline_connectivity_no_update() {
+        let mut solver = OfflineConnectivity::with_capacity(2);
+        solver.query(0, 0, 0);
+        solver.query(0, 1, 0);
+        assert_eq!(solver.solve(), vec![true, false]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_offline_connectivity_missing_edge() {
+        let mut solver = OfflineConnectivity::with_capacity(2);
+        solver.add_edge(0, 1);
+        solver.remove_edge(0, 1);
+        solver.remove_edge(0, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_offline_connectivity_query_in_future() {
+        let mut solver = OfflineConnectivity::with_capacity(2);
+        solver.add_edge(0, 1);
+        solver.query(0, 1, 2);
+        solver.solve();
+    }
+}
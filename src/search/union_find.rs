@@ -1,8 +1,12 @@
 mod algorithm;
+mod rollback;
 #[cfg(test)]
 mod unit_test;
 use crate::utils::read_lines;
 pub use algorithm::UnionFindAlgorithm;
+pub use rollback::RollbackUnionFind;
+use std::collections::HashMap;
+use std::hash::Hash;
 use std::path::Path;
 
 /// Implementation of union-find algorithms
@@ -33,6 +37,9 @@ pub struct UnionFind {
     // optional size of the trees the objects belong to,
     // except for QuickFind algorithm (no tree representation)
     size: Vec<usize>,
+
+    // number of components
+    count: usize,
     // Some axioms of the connection relation:
     //  - reflexive: object p is connected to p
     //  - transitive: p connected to q and q connected to r => q connected to r
@@ -55,6 +62,7 @@ impl UnionFind {
             algo: UnionFindAlgorithm::default(),
             ids: (0..nb).collect::<Vec<usize>>(),
             size: vec![1; nb],
+            count: nb,
         }
     }
 
@@ -66,12 +74,14 @@ impl UnionFind {
                 algo: algorithm,
                 ids: (0..nb).collect::<Vec<usize>>(),
                 size: Vec::new(),
+                count: nb,
             },
             _ => Self {
                 nb_objects: nb,
                 algo: algorithm,
                 ids: (0..nb).collect::<Vec<usize>>(),
                 size: vec![1; nb],
+                count: nb,
             },
         }
     }
@@ -153,38 +163,99 @@ impl UnionFind {
         uf
     }
 
+    /// Adds a new object, in its own component, and returns its id.
+    /// # Example
+    /// ```
+    /// use algods::search::{UnionFind, UnionFindAlgorithm};
+    /// let mut uf = UnionFind::with_capacity(2, UnionFindAlgorithm::WeightedQuickUnion);
+    /// assert_eq!(uf.add(), 2);
+    /// assert_eq!(uf.len(), 3);
+    /// assert_eq!(uf.count(), 3);
+    /// ```
+    pub fn add(&mut self) -> usize {
+        let id = self.nb_objects;
+        self.ids.push(id);
+        if self.algo != UnionFindAlgorithm::QuickFind {
+            self.size.push(1);
+        }
+        self.nb_objects += 1;
+        self.count += 1;
+        id
+    }
+
+    /// Gives the number of components, i.e. of sets of connected objects.
+    /// # Example
+    /// ```
+    /// use algods::search::{UnionFind, UnionFindAlgorithm};
+    /// let mut uf = UnionFind::with_capacity(4, UnionFindAlgorithm::QuickFind);
+    /// uf.union(0, 1);
+    /// uf.union(1, 0);
+    /// assert_eq!(uf.count(), 3);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
     /// Gets the id of the root of an object in a union find instance.
     /// Depending on the algorithm the root is computed differently and the complexity differs also.
     /// For `QuickFInd`, the value returned is the parent of the object, in a tree representation.
     /// For the others, the value returned is the parent or the great parent or a great great .. parent.
+    /// The instance is only borrowed, so the path to the root is not compressed:
+    /// with `WeightedQuickUnionPathComp`, paths are compressed by `root` and `union`.
+    /// # Panics
+    /// If the object is out of bounds, then it panics.
     /// # Example
     /// ```
     /// use algods::search::{UnionFind, UnionFindAlgorithm};
-    /// let mut uf = UnionFind::with_capacity(2, UnionFindAlgorithm::WeightedQuickUnion);
+    /// let mut uf = UnionFind::with_capacity(3, UnionFindAlgorithm::WeightedQuickUnionPathComp);
     /// uf.union(0, 1);
-    /// assert_eq!(uf.root(0), uf.root(1));
+    /// uf.union(1, 2);
+    /// assert_eq!(uf.find(0), uf.find(2));
     /// ```
     /// # Time complexity
     /// For `QuickFind` it is expected to run in O(1).
     /// For `QuickUnion`, it is expected to run in O(N).
     /// For `WeightedQuickUnion`, it is expected to run in O(log(N)).
-    /// For `WeightedQuickUnionPathComp`, it is expected to run in O(log*(N)) ~ O(1) (almost in constant time).
-    pub fn root(&mut self, mut i: usize) -> usize {
+    /// For `WeightedQuickUnionPathComp`, it is expected to run in O(log(N)),
+    /// and almost in constant time on the paths compressed by `root` and `union`.
+    pub fn find(&self, mut i: usize) -> usize {
         // Finding the root of an object i
         if let UnionFindAlgorithm::QuickFind = self.algo {
             self.ids[i]
-        } else if let UnionFindAlgorithm::WeightedQuickUnionPathComp = self.algo {
+        } else {
             while i != self.ids[i] {
-                // one-pass path compression
-                self.ids[i] = self.ids[self.ids[i]];
                 i = self.ids[i];
             }
             i
-        } else {
+        }
+    }
+
+    /// Gets the id of the root of an object in a union find instance, same as `find`,
+    /// except that with `WeightedQuickUnionPathComp` the path to the root is compressed along the way.
+    /// # Panics
+    /// If the object is out of bounds, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::search::{UnionFind, UnionFindAlgorithm};
+    /// let mut uf = UnionFind::with_capacity(2, UnionFindAlgorithm::WeightedQuickUnionPathComp);
+    /// uf.union(0, 1);
+    /// assert_eq!(uf.root(0), uf.root(1));
+    /// ```
+    /// # Time complexity
+    /// For `QuickFind` it is expected to run in O(1).
+    /// For `QuickUnion`, it is expected to run in O(N).
+    /// For `WeightedQuickUnion`, it is expected to run in O(log(N)).
+    /// For `WeightedQuickUnionPathComp`, it is expected to run in O(log*(N)) ~ O(1) (almost in constant time).
+    pub fn root(&mut self, mut i: usize) -> usize {
+        if let UnionFindAlgorithm::WeightedQuickUnionPathComp = self.algo {
             while i != self.ids[i] {
+                // one-pass path compression
+                self.ids[i] = self.ids[self.ids[i]];
                 i = self.ids[i];
             }
             i
+        } else {
+            self.find(i)
         }
     }
 
@@ -201,17 +272,59 @@ impl UnionFind {
     /// For `QuickUnion`, it is expected to run in O(N).
     /// For `WeightedQuickUnion`, it is expected to run in O(log(N)).
     /// For `WeightedQuickUnionPathComp`, it is expected to run in O(log*(N)) ~ O(1) (almost in constant time).
-    pub fn connected(&mut self, p: usize, q: usize) -> bool {
+    pub fn connected(&self, p: usize, q: usize) -> bool {
+        // complexity: O(1) for QuickFind, O(N) for QuickUnion,
+        // O(log(N)) for the algorithms WeightedQuickUnion*
+        self.find(p) == self.find(q)
+    }
+
+    /// Gives the number of objects connected to an object (itself included).
+    /// # Example
+    /// ```
+    /// use algods::search::{UnionFind, UnionFindAlgorithm};
+    /// let mut uf = UnionFind::with_capacity(4, UnionFindAlgorithm::WeightedQuickUnion);
+    /// uf.union(0, 1);
+    /// uf.union(1, 2);
+    /// assert_eq!(uf.component_size(2), 3);
+    /// assert_eq!(uf.component_size(3), 1);
+    /// ```
+    /// # Time complexity
+    /// For `QuickFind` (which does not keep track of sizes), it is expected to run in O(N).
+    /// Otherwise it runs as `find`.
+    pub fn component_size(&self, p: usize) -> usize {
+        let root = self.find(p);
         if let UnionFindAlgorithm::QuickFind = self.algo {
-            // complexity: O(1)
-            self.ids[p] == self.ids[q]
+            self.ids.iter().filter(|id| **id == root).count()
         } else {
-            // complexity: O(N) for QuickUnion,
-            // O(log(N)) for the algorithms WeightedQuickUnion*
-            self.root(p) == self.root(q)
+            self.size[root]
         }
     }
 
+    /// Lists the components, each one with its objects in increasing order,
+    /// the components being sorted by their smallest object.
+    /// # Example
+    /// ```
+    /// use algods::search::{UnionFind, UnionFindAlgorithm};
+    /// let mut uf = UnionFind::with_capacity(5, UnionFindAlgorithm::QuickUnion);
+    /// uf.union(3, 0);
+    /// uf.union(1, 4);
+    /// assert_eq!(uf.components(), vec![vec![0, 3], vec![1, 4], vec![2]]);
+    /// ```
+    pub fn components(&self) -> Vec<Vec<usize>> {
+        // position of the component of each root in the list
+        let mut positions = vec![usize::MAX; self.nb_objects];
+        let mut components = Vec::<Vec<usize>>::with_capacity(self.count);
+        for p in 0..self.nb_objects {
+            let root = self.find(p);
+            if positions[root] == usize::MAX {
+                positions[root] = components.len();
+                components.push(Vec::new());
+            }
+            components[positions[root]].push(p);
+        }
+        components
+    }
+
     /// Connects two objects of a union find algorithm.
     /// # Example
     /// ```
@@ -228,29 +341,30 @@ impl UnionFind {
     /// For `WeightedQuickUnion`, it is expected to run in O(log(N)).
     /// For `WeightedQuickUnionPathComp`, it is expected to run in O(log*(N)) ~ O(1) (almost in constant time).
     pub fn union(&mut self, p: usize, q: usize) {
+        let i = self.root(p);
+        let j = self.root(q);
+        if i == j {
+            return;
+        }
+        self.count -= 1;
         match self.algo {
             UnionFindAlgorithm::QuickFind => {
                 // complexity: O(N)
-                let pid: usize = self.ids[p];
-                let qid: usize = self.ids[q];
                 // Connect to q all objects connected to p
-                for i in 0..self.ids.len() {
-                    if self.ids[i] == pid {
-                        self.ids[i] = qid;
+                for id in self.ids.iter_mut() {
+                    if *id == i {
+                        *id = j;
                     }
                 }
             }
             UnionFindAlgorithm::QuickUnion => {
                 // complexity: O(N) (tall trees)
-                let i = self.root(p);
-                let j = self.root(q);
                 self.ids[i] = j;
+                self.size[j] += self.size[i];
             }
             _ => {
                 // complexity: given roots (of complexity O(log(N)))
                 // O(log(N)) or O(log*(N)) ~ O(1) with PathComp (path compression)
-                let i = self.root(p);
-                let j = self.root(q);
                 // Put the smallest tree under the tallest one
                 if self.size[i] < self.size[j] {
                     self.ids[i] = j;
@@ -263,3 +377,224 @@ impl UnionFind {
         }
     }
 }
+
+/// Implementation of union-find over keys of any hashable type,
+/// each new key being given the next object id of an inner `UnionFind`.
+/// # Examples
+/// ```
+/// use algods::search::KeyedUnionFind;
+/// let mut uf = KeyedUnionFind::new();
+/// uf.union("alice", "a.smith");
+/// uf.union("bob", "robert");
+/// uf.union("a.smith", "alice.smith");
+/// assert!(uf.connected(&"alice", &"alice.smith"));
+/// assert!(!uf.connected(&"alice", &"bob"));
+/// assert_eq!(uf.count(), 2);
+/// assert_eq!(uf.component_size(&"alice"), Some(3));
+/// ```
+/// The default algorithm is **WeightedQuickUnionPathComp**
+#[derive(Debug)]
+pub struct KeyedUnionFind<K> {
+    // id of each key in the inner union-find
+    ids: HashMap<K, usize>,
+    // key of each id
+    keys: Vec<K>,
+    uf: UnionFind,
+}
+impl<K: Hash + Eq + Clone> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<K: Hash + Eq + Clone> KeyedUnionFind<K> {
+    /// Creates an empty instance, with the `WeightedQuickUnionPathComp` algorithm.
+    /// # Example
+    /// ```
+    /// use algods::search::{KeyedUnionFind, UnionFindAlgorithm};
+    /// let uf = KeyedUnionFind::<String>::new();
+    /// assert!(uf.is_empty());
+    /// assert_eq!(uf.algo(), UnionFindAlgorithm::WeightedQuickUnionPathComp);
+    /// ```
+    pub fn new() -> Self {
+        Self::with_algorithm(UnionFindAlgorithm::WeightedQuickUnionPathComp)
+    }
+    /// Creates an empty instance with a given union-find algorithm.
+    /// # Example
+    /// ```
+    /// use algods::search::{KeyedUnionFind, UnionFindAlgorithm};
+    /// let uf = KeyedUnionFind::<String>::with_algorithm(UnionFindAlgorithm::QuickFind);
+    /// assert_eq!(uf.algo(), UnionFindAlgorithm::QuickFind);
+    /// ```
+    pub fn with_algorithm(algorithm: UnionFindAlgorithm) -> Self {
+        Self {
+            ids: HashMap::new(),
+            keys: Vec::new(),
+            uf: UnionFind::with_capacity(0, algorithm),
+        }
+    }
+    /// Returns the algorithm of the instance.
+    /// # Example
+    /// ```
+    /// use algods::search::{KeyedUnionFind, UnionFindAlgorithm};
+    /// let uf = KeyedUnionFind::<u8>::with_algorithm(UnionFindAlgorithm::QuickUnion);
+    /// assert_eq!(uf.algo(), UnionFindAlgorithm::QuickUnion);
+    /// ```
+    pub fn algo(&self) -> UnionFindAlgorithm {
+        self.uf.algo()
+    }
+    /// Gives the number of keys collected.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// uf.union('a', 'b');
+    /// uf.insert('c');
+    /// assert_eq!(uf.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+    /// Indicates whether or not the instance has no key.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let uf = KeyedUnionFind::<char>::new();
+    /// assert!(uf.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Adds a key in its own component, if it is not already there.
+    /// Returns whether or not the key was added.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// assert!(uf.insert("a"));
+    /// assert!(!uf.insert("a"));
+    /// ```
+    pub fn insert(&mut self, key: K) -> bool {
+        if self.ids.contains_key(&key) {
+            return false;
+        }
+        self.id(key);
+        true
+    }
+    // Gives the id of a key, adding it when new
+    fn id(&mut self, key: K) -> usize {
+        match self.ids.get(&key) {
+            Some(id) => *id,
+            None => {
+                let id = self.uf.add();
+                self.ids.insert(key.clone(), id);
+                self.keys.push(key);
+                id
+            }
+        }
+    }
+    /// Tests whether or not a key has been collected.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// uf.insert("a");
+    /// assert!(uf.contains(&"a"));
+    /// assert!(!uf.contains(&"b"));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.ids.contains_key(key)
+    }
+    /// Gives the number of components, i.e. of sets of connected keys.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// uf.union(1, 2);
+    /// uf.insert(3);
+    /// assert_eq!(uf.count(), 2);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.uf.count()
+    }
+    /// Gives the representative key of the component of a key, if the key has been collected.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// uf.union("a", "b");
+    /// assert_eq!(uf.find(&"a"), uf.find(&"b"));
+    /// assert_eq!(uf.find(&"c"), None);
+    /// ```
+    /// # Time complexity
+    /// It runs as `UnionFind::find` (on average, given the hashing of the key).
+    pub fn find(&self, key: &K) -> Option<&K> {
+        let id = *self.ids.get(key)?;
+        Some(&self.keys[self.uf.find(id)])
+    }
+    /// Indicates whether or not two keys are connected.
+    /// A key that has not been collected is only connected to itself.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// uf.union("a", "b");
+    /// assert!(uf.connected(&"a", &"b"));
+    /// assert!(!uf.connected(&"a", &"c"));
+    /// assert!(uf.connected(&"c", &"c"));
+    /// ```
+    /// # Time complexity
+    /// It runs as `UnionFind::connected` (on average, given the hashing of the keys).
+    pub fn connected(&self, p: &K, q: &K) -> bool {
+        match (self.ids.get(p), self.ids.get(q)) {
+            (Some(p), Some(q)) => self.uf.connected(*p, *q),
+            _ => p == q,
+        }
+    }
+    /// Gives the number of keys connected to a key (itself included), if it has been collected.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// uf.union("a", "b");
+    /// assert_eq!(uf.component_size(&"a"), Some(2));
+    /// assert_eq!(uf.component_size(&"c"), None);
+    /// ```
+    pub fn component_size(&self, key: &K) -> Option<usize> {
+        self.ids.get(key).map(|id| self.uf.component_size(*id))
+    }
+    /// Lists the components, each one with its keys in order of appearance,
+    /// the components being sorted by their first key to appear.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// uf.union("a", "b");
+    /// uf.union("c", "d");
+    /// uf.union("e", "a");
+    /// assert_eq!(
+    ///     uf.components(),
+    ///     vec![vec![&"a", &"b", &"e"], vec![&"c", &"d"]]
+    /// );
+    /// ```
+    pub fn components(&self) -> Vec<Vec<&K>> {
+        self.uf
+            .components()
+            .into_iter()
+            .map(|component| component.into_iter().map(|id| &self.keys[id]).collect())
+            .collect()
+    }
+    /// Connects two keys, collecting them if new.
+    /// # Example
+    /// ```
+    /// use algods::search::KeyedUnionFind;
+    /// let mut uf = KeyedUnionFind::new();
+    /// uf.union("a".to_string(), "b".to_string());
+    /// assert!(uf.connected(&"b".to_string(), &"a".to_string()));
+    /// ```
+    /// # Time complexity
+    /// It runs as `UnionFind::union` (on average, given the hashing of the keys).
+    pub fn union(&mut self, p: K, q: K) {
+        let (p, q) = (self.id(p), self.id(q));
+        self.uf.union(p, q);
+    }
+}
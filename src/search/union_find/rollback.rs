@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod unit_test;
+
+/// Implementation of union-find whose unions can be undone, in the reverse order they were made.
+/// It uses union by size without path compression, so that each union
+/// only changes the parent of one root and can be reverted in O(1).
+/// # Examples
+/// ```
+/// use algods::search::RollbackUnionFind;
+/// let mut uf = RollbackUnionFind::with_capacity(4);
+/// uf.union(0, 1);
+/// let snapshot = uf.snapshot();
+/// uf.union(1, 2);
+/// uf.union(2, 3);
+/// assert!(uf.connected(0, 3));
+/// uf.rollback(snapshot);
+/// assert!(uf.connected(0, 1));
+/// assert!(!uf.connected(0, 2));
+/// assert_eq!(uf.count(), 3);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RollbackUnionFind {
+    // each object j is connected to ids[j]
+    ids: Vec<usize>,
+    // size of the trees, for roots
+    size: Vec<usize>,
+    // number of components
+    count: usize,
+    // unions made so far, as (former root, new root) pairs
+    history: Vec<(usize, usize)>,
+}
+impl RollbackUnionFind {
+    /// Creates an instance with `nb` objects, each one in its own component.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let uf = RollbackUnionFind::with_capacity(3);
+    /// assert_eq!(uf.len(), 3);
+    /// assert_eq!(uf.count(), 3);
+    /// ```
+    pub fn with_capacity(nb: usize) -> Self {
+        Self {
+            ids: (0..nb).collect(),
+            size: vec![1; nb],
+            count: nb,
+            history: Vec::new(),
+        }
+    }
+    /// Gives the number of objects collected.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let uf = RollbackUnionFind::with_capacity(3);
+    /// assert_eq!(uf.len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+    /// Indicates whether or not the instance has no object.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let uf = RollbackUnionFind::with_capacity(0);
+    /// assert!(uf.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Gives the number of components, i.e. of sets of connected objects.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::with_capacity(3);
+    /// uf.union(0, 2);
+    /// assert_eq!(uf.count(), 2);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.count
+    }
+    /// Gets the id of the root of an object.
+    /// # Panics
+    /// If the object is out of bounds, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::with_capacity(2);
+    /// uf.union(0, 1);
+    /// assert_eq!(uf.find(0), uf.find(1));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn find(&self, mut i: usize) -> usize {
+        while i != self.ids[i] {
+            i = self.ids[i];
+        }
+        i
+    }
+    /// Indicates whether or not two objects are connected.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::with_capacity(3);
+    /// uf.union(0, 1);
+    /// assert!(uf.connected(1, 0));
+    /// assert!(!uf.connected(1, 2));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn connected(&self, p: usize, q: usize) -> bool {
+        self.find(p) == self.find(q)
+    }
+    /// Gives the number of objects connected to an object (itself included).
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::with_capacity(3);
+    /// uf.union(0, 1);
+    /// assert_eq!(uf.component_size(1), 2);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn component_size(&self, p: usize) -> usize {
+        self.size[self.find(p)]
+    }
+    /// Connects two objects, returns whether or not they were in different components.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::with_capacity(2);
+    /// assert!(uf.union(0, 1));
+    /// assert!(!uf.union(1, 0));
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(log(N))
+    pub fn union(&mut self, p: usize, q: usize) -> bool {
+        let (mut i, mut j) = (self.find(p), self.find(q));
+        if i == j {
+            return false;
+        }
+        // Put the smallest tree under the tallest one
+        if self.size[i] > self.size[j] {
+            std::mem::swap(&mut i, &mut j);
+        }
+        self.ids[i] = j;
+        self.size[j] += self.size[i];
+        self.count -= 1;
+        self.history.push((i, j));
+        true
+    }
+    /// Gives a snapshot of the current state, that is the number of unions made so far
+    /// (unions of already connected objects aside), to which the instance can be rolled back.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::with_capacity(3);
+    /// assert_eq!(uf.snapshot(), 0);
+    /// uf.union(0, 1);
+    /// uf.union(0, 1);
+    /// assert_eq!(uf.snapshot(), 1);
+    /// ```
+    pub fn snapshot(&self) -> usize {
+        self.history.len()
+    }
+    /// Undoes the unions made since a snapshot.
+    /// # Panics
+    /// If the snapshot is more recent than the current state, then it panics.
+    /// # Example
+    /// ```
+    /// use algods::search::RollbackUnionFind;
+    /// let mut uf = RollbackUnionFind::with_capacity(3);
+    /// uf.union(0, 1);
+    /// uf.union(1, 2);
+    /// uf.rollback(0);
+    /// assert_eq!(uf.count(), 3);
+    /// ```
+    /// # Time complexity
+    /// This is expected to run in O(K), where K is the number of unions undone.
+    pub fn rollback(&mut self, to: usize) {
+        if to > self.history.len() {
+            panic!(
+                "cannot rollback to {to}, only {} unions were made",
+                self.history.len()
+            );
+        }
+        while self.history.len() > to {
+            let (i, j) = self.history.pop().expect("non empty history");
+            self.ids[i] = i;
+            self.size[j] -= self.size[i];
+            self.count += 1;
+        }
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_rollback_restores_states() {
+        let n = 100;
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let mut uf = RollbackUnionFind::with_capacity(n);
+        // states saved along with their snapshot, as root of each object
+        let mut saved: Vec<(usize, Vec<usize>, usize)> = Vec::new();
+        for _ in 0..500 {
+            if rng.gen_bool(0.1) && !saved.is_empty() {
+                let (snapshot, roots, count) = saved.swap_remove(rng.gen_range(0..saved.len()));
+                uf.rollback(snapshot);
+                assert_eq!(uf.count(), count);
+                for (p, root) in roots.iter().enumerate() {
+                    assert_eq!(uf.find(p), *root);
+                }
+                // later states are no longer reachable
+                saved.retain(|(s, _, _)| *s <= snapshot);
+            } else {
+                uf.union(rng.gen_range(0..n), rng.gen_range(0..n));
+                if rng.gen_bool(0.2) {
+                    let roots = (0..n).map(|p| uf.find(p)).collect::<Vec<usize>>();
+                    saved.push((uf.snapshot(), roots, uf.count()));
+                }
+            }
+            let sizes = (0..n).map(|p| uf.component_size(p)).sum::<usize>();
+            let naive = (0..n)
+                .map(|p| (0..n).filter(|q| uf.connected(p, *q)).count())
+                .sum::<usize>();
+            assert_eq!(sizes, naive);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rollback_to_future() {
+        let mut uf = RollbackUnionFind::with_capacity(2);
+        uf.rollback(1);
+    }
+}
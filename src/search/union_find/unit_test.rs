@@ -32,4 +32,125 @@ mod tests {
         assert_eq!(uf.connected(1, 2), true);
         assert_eq!(uf.connected(0, 4), false);
     }
+
+    const ALGORITHMS: [UnionFindAlgorithm; 4] = [
+        UnionFindAlgorithm::QuickFind,
+        UnionFindAlgorithm::QuickUnion,
+        UnionFindAlgorithm::WeightedQuickUnion,
+        UnionFindAlgorithm::WeightedQuickUnionPathComp,
+    ];
+
+    #[test]
+    fn test_components_against_labels() {
+        use rand::{Rng, SeedableRng};
+        let n = 200;
+        for algo in ALGORITHMS {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+            let mut uf = UnionFind::with_capacity(n, algo);
+            // naive component labels
+            let mut labels = (0..n).collect::<Vec<usize>>();
+            for _ in 0..150 {
+                let (p, q) = (rng.gen_range(0..n), rng.gen_range(0..n));
+                uf.union(p, q);
+                let (old, new) = (labels[p], labels[q]);
+                for label in labels.iter_mut() {
+                    if *label == old {
+                        *label = new;
+                    }
+                }
+                let mut distinct = labels.clone();
+                distinct.sort();
+                distinct.dedup();
+                assert_eq!(uf.count(), distinct.len());
+            }
+            for p in 0..n {
+                let size = labels.iter().filter(|l| **l == labels[p]).count();
+                assert_eq!(uf.component_size(p), size);
+                assert_eq!(uf.connected(p, 0), labels[p] == labels[0]);
+            }
+            let components = uf.components();
+            assert_eq!(components.len(), uf.count());
+            for component in components.iter() {
+                assert!(component.iter().all(|p| labels[*p] == labels[component[0]]));
+            }
+            assert_eq!(components.iter().map(|c| c.len()).sum::<usize>(), n);
+        }
+    }
+
+    #[test]
+    fn test_root_compresses_paths() {
+        let mut uf = UnionFind::with_capacity(4, UnionFindAlgorithm::WeightedQuickUnionPathComp);
+        uf.union(0, 1);
+        uf.union(2, 3);
+        uf.union(0, 2);
+        // 3 -> 2 -> 0 before the compression, which find does not do
+        assert_eq!(uf.find(3), 0);
+        assert_eq!(uf.ids[3], 2);
+        let root = uf.root(3);
+        assert_eq!(uf.ids[3], root);
+    }
+
+    #[test]
+    fn test_add() {
+        for algo in ALGORITHMS {
+            let mut uf = UnionFind::with_capacity(0, algo);
+            assert_eq!(uf.add(), 0);
+            assert_eq!(uf.add(), 1);
+            uf.union(0, 1);
+            assert_eq!(uf.add(), 2);
+            assert_eq!(uf.count(), 2);
+            assert_eq!(uf.component_size(1), 2);
+            assert_eq!(uf.components(), vec![vec![0, 1], vec![2]]);
+        }
+    }
+
+    #[test]
+    fn test_keyed_union_find() {
+        for algo in ALGORITHMS {
+            let mut uf = KeyedUnionFind::with_algorithm(algo);
+            let names = ["ann", "anne", "bob", "rob", "robert", "carl"];
+            uf.union(names[0].to_string(), names[1].to_string());
+            uf.union(names[2].to_string(), names[3].to_string());
+            uf.union(names[4].to_string(), names[3].to_string());
+            uf.insert(names[5].to_string());
+            uf.union(names[1].to_string(), names[0].to_string());
+            assert_eq!(uf.len(), 6);
+            assert_eq!(uf.count(), 3);
+            let key = |i: usize| names[i].to_string();
+            assert!(uf.connected(&key(2), &key(4)));
+            assert!(!uf.connected(&key(0), &key(5)));
+            assert_eq!(uf.find(&key(4)), uf.find(&key(2)));
+            assert_eq!(uf.component_size(&key(3)), Some(3));
+            assert_eq!(uf.component_size(&"dan".to_string()), None);
+            assert_eq!(
+                uf.components(),
+                vec![
+                    vec![&key(0), &key(1)],
+                    vec![&key(2), &key(3), &key(4)],
+                    vec![&key(5)]
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_union_find_shared_between_threads() {
+        let mut uf = UnionFind::with_capacity(100, UnionFindAlgorithm::WeightedQuickUnionPathComp);
+        for p in 0..99 {
+            uf.union(p, p + 1);
+        }
+        // queries only borrow the instance, so that it can be shared between threads
+        std::thread::scope(|scope| {
+            for t in 0..4 {
+                let uf = &uf;
+                scope.spawn(move || assert!((t..100).step_by(4).all(|p| uf.connected(p, 0))));
+            }
+        });
+        let mut keyed = KeyedUnionFind::new();
+        keyed.union("a".to_string(), "b".to_string());
+        let keyed = &keyed;
+        std::thread::scope(|scope| {
+            scope.spawn(move || assert!(keyed.connected(&"b".to_string(), &"a".to_string())));
+        });
+    }
 }
@@ -1,3 +1,5 @@
 mod io;
+mod sampling;
 
 pub use io::{read_lines, Reader, Reader2};
+pub use sampling::{knuth_shuffle, reservoir_sampling};
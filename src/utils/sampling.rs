@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod unit_test;
+use rand::Rng;
+
+/// Shuffles a slice in place using the Knuth (Fisher-Yates) algorithm,
+/// so that every permutation of the slice is equally likely.
+/// # Example
+/// ```
+/// use algods::utils::knuth_shuffle;
+/// use rand::{rngs::StdRng, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let mut v = vec![0, 1, 2, 3, 4];
+/// knuth_shuffle(&mut v, &mut rng);
+/// v.sort();
+/// assert_eq!(v, vec![0, 1, 2, 3, 4]);
+/// ```
+/// # Time complexity
+/// This is expected to run in O(N)
+pub fn knuth_shuffle<T, R: Rng + ?Sized>(slice: &mut [T], rng: &mut R) {
+    // at step i, the object at position i is exchanged
+    // with a uniformly chosen object among positions 0..=i
+    for i in 1..slice.len() {
+        let j = rng.gen_range(0..=i);
+        slice.swap(i, j);
+    }
+}
+
+/// Draws `k` objects uniformly at random without replacement from an iterator
+/// of unknown length, by means of the reservoir sampling algorithm.
+/// When the iterator yields less than `k` objects, all of them are returned.
+/// # Example
+/// ```
+/// use algods::utils::reservoir_sampling;
+/// use rand::{rngs::StdRng, SeedableRng};
+/// let mut rng = StdRng::seed_from_u64(42);
+/// let sample = reservoir_sampling(0..100, 5, &mut rng);
+/// assert_eq!(sample.len(), 5);
+/// assert!(sample.iter().all(|e| *e < 100));
+/// ```
+/// # Time complexity
+/// This is expected to run in O(N) where N is the number of objects in the iterator,
+/// using O(k) memory.
+pub fn reservoir_sampling<T, I, R>(iter: I, k: usize, rng: &mut R) -> Vec<T>
+where
+    I: IntoIterator<Item = T>,
+    R: Rng + ?Sized,
+{
+    let iter = iter.into_iter();
+    // k may be much larger than the number of objects
+    let mut reservoir = Vec::with_capacity(k.min(iter.size_hint().0));
+    if k == 0 {
+        return reservoir;
+    }
+    for (n, item) in iter.enumerate() {
+        if n < k {
+            reservoir.push(item);
+        } else {
+            // the (n+1)^th object is kept with probability k/(n+1)
+            let j = rng.gen_range(0..=n);
+            if j < k {
+                reservoir[j] = item;
+            }
+        }
+    }
+    reservoir
+}
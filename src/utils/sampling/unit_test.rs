@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_knuth_shuffle() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut v = (0..100).collect::<Vec<usize>>();
+        knuth_shuffle(&mut v, &mut rng);
+        assert_ne!(v, (0..100).collect::<Vec<usize>>());
+        v.sort();
+        assert_eq!(v, (0..100).collect::<Vec<usize>>());
+        let mut empty = Vec::<usize>::new();
+        knuth_shuffle(&mut empty, &mut rng);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_knuth_shuffle_seeded() {
+        let mut v1 = (0..20).collect::<Vec<usize>>();
+        let mut v2 = v1.clone();
+        knuth_shuffle(&mut v1, &mut StdRng::seed_from_u64(7));
+        knuth_shuffle(&mut v2, &mut StdRng::seed_from_u64(7));
+        assert_eq!(v1, v2);
+    }
+
+    #[test]
+    fn test_knuth_shuffle_uniformity() {
+        // each object should end up at each position with probability 1/3
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut counts = [[0usize; 3]; 3];
+        let trials = 30000;
+        for _ in 0..trials {
+            let mut v = [0, 1, 2];
+            knuth_shuffle(&mut v, &mut rng);
+            for (position, object) in v.iter().enumerate() {
+                counts[*object][position] += 1;
+            }
+        }
+        for row in counts.iter() {
+            for count in row.iter() {
+                assert!((*count as f64 / trials as f64 - 1. / 3.).abs() < 0.02);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reservoir_sampling() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(reservoir_sampling(0..3, 5, &mut rng), vec![0, 1, 2]);
+        assert_eq!(
+            reservoir_sampling(0..3, usize::MAX, &mut rng),
+            vec![0, 1, 2]
+        );
+        assert!(reservoir_sampling(0..3, 0, &mut rng).is_empty());
+        let mut sample = reservoir_sampling(0..1000, 10, &mut rng);
+        assert_eq!(sample.len(), 10);
+        sample.sort();
+        sample.dedup();
+        assert_eq!(sample.len(), 10);
+    }
+
+    #[test]
+    fn test_reservoir_sampling_uniformity() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut counts = [0usize; 10];
+        let trials = 20000;
+        for _ in 0..trials {
+            for item in reservoir_sampling(0..10, 2, &mut rng) {
+                counts[item] += 1;
+            }
+        }
+        for count in counts.iter() {
+            // each object is selected with probability 2/10
+            assert!((*count as f64 / trials as f64 - 0.2).abs() < 0.02);
+        }
+    }
+}